@@ -0,0 +1,120 @@
+//! Step throughput baseline for the orchestration hot loop.
+//!
+//! Run with `cargo bench --bench step_throughput`. Each case builds a
+//! population of `N` cells, primes the signal bus with a fixed number of
+//! sourced signals, and times a single `MorphogeneticApp::step`. A second
+//! group times `SecurityCell::tick` with and without the topic index.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use morphogenetic_security::MorphogeneticApp;
+use morphogenetic_security::cellular::{CellEnvironment, SecurityCell};
+use morphogenetic_security::config::{TopologyConfig, TopologyStrategy};
+use morphogenetic_security::signaling::{Signal, TopicSignals};
+use morphogenetic_security::telemetry::{TelemetryEvent, TelemetrySink};
+use std::hint::black_box;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+const POPULATIONS: [usize; 3] = [100, 500, 1000];
+const SIGNALS_PER_STEP: usize = 100;
+
+/// Sink that drops every event so memory stays flat across iterations.
+struct DiscardSink;
+
+impl TelemetrySink for DiscardSink {
+    fn record(&mut self, _timestamp: SystemTime, _event: TelemetryEvent) {}
+}
+
+fn build_app(cell_count: usize, strategy: TopologyStrategy) -> MorphogeneticApp<DiscardSink> {
+    let cells: Vec<SecurityCell> = (0..cell_count)
+        .map(|idx| SecurityCell::new(format!("seed-{idx}")))
+        .collect();
+
+    // Chain the population so Graph mode has real adjacency lists to walk.
+    let explicit_links = matches!(strategy, TopologyStrategy::Graph).then(|| {
+        (1..cell_count)
+            .map(|idx| vec![format!("seed-{}", idx - 1), format!("seed-{idx}")])
+            .collect()
+    });
+
+    MorphogeneticApp::new(
+        cells,
+        DiscardSink,
+        TopologyConfig {
+            strategy,
+            explicit_links,
+            deliver_self_signals: false,
+            ..TopologyConfig::default()
+        },
+    )
+}
+
+fn prime_signals(app: &mut MorphogeneticApp<DiscardSink>, cell_count: usize) {
+    for idx in 0..SIGNALS_PER_STEP {
+        app.inject_signal(
+            Signal::new("cooperative", 0.01).with_source(format!("seed-{}", idx % cell_count)),
+        );
+    }
+}
+
+fn step_throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("step_throughput");
+    group.sample_size(10);
+
+    for (label, strategy) in [
+        ("global", TopologyStrategy::Global),
+        ("graph", TopologyStrategy::Graph),
+    ] {
+        for &cell_count in &POPULATIONS {
+            let mut app = build_app(cell_count, strategy.clone());
+            let mut step = 0u32;
+            group.bench_with_input(
+                BenchmarkId::new(label, cell_count),
+                &cell_count,
+                |b, &cell_count| {
+                    b.iter(|| {
+                        prime_signals(&mut app, cell_count);
+                        app.step(black_box(step), black_box(0.0));
+                        step = step.wrapping_add(1);
+                    })
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+/// Compare `tick` over a flat many-topic buffer against the pre-indexed view.
+fn tick_categorization(c: &mut Criterion) {
+    let signals: Arc<[Signal]> = (0..1000)
+        .map(|idx| {
+            let topic = if idx % 100 == 0 {
+                "activator".to_string()
+            } else {
+                format!("telemetry:{}", idx % 50)
+            };
+            Signal::new(topic, 0.01).with_source(format!("seed-{}", idx % 10))
+        })
+        .collect();
+    let flat = CellEnvironment {
+        neighbor_signals: Arc::clone(&signals),
+        ..Default::default()
+    };
+    let indexed = CellEnvironment {
+        topic_index: Some(Arc::new(TopicSignals::new(Arc::clone(&signals)))),
+        ..flat.clone()
+    };
+
+    let mut group = c.benchmark_group("tick_categorization");
+    for (label, environment) in [("flat", &flat), ("indexed", &indexed)] {
+        let mut cell = SecurityCell::new("bench");
+        group.bench_function(label, |b| {
+            b.iter(|| black_box(cell.tick(black_box(environment))))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, step_throughput, tick_categorization);
+criterion_main!(benches);
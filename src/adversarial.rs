@@ -12,42 +12,60 @@ use std::collections::{HashMap, VecDeque};
 use std::fs::{self, File};
 use std::io::{self, BufReader, Read};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
 
 use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
 use rand::seq::SliceRandom;
 
 use crate::cellular::PopulationStats;
 use crate::config;
-use crate::config::ConfigError;
-use crate::stimulus::StimulusSchedule;
-use crate::telemetry::TopologyStats;
-use std::collections::{BTreeMap, HashSet};
+use crate::config::{ConfigError, ScenarioConfig, ThreatSpike};
+use crate::orchestration::MorphogeneticApp;
+use crate::signaling::Signal;
+use crate::stimulus::{StimulusBudget, StimulusCommand, StimulusSchedule};
+use crate::telemetry::{
+    StreamingPipeline, TelemetryEvent, TelemetrySink, TelemetrySnapshot, TopologyStats,
+};
+use crate::util::write_atomic;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 /// The strategy used for selecting parents for the next generation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SelectionStrategy {
     /// Select parents using tournament selection with a given size.
-    Tournament { size: usize },
+    ///
+    /// Without replacement (the default) a tournament never repeats an
+    /// outcome, so a `size` above the archive length shrinks to the whole
+    /// archive and always picks its fittest entry. With replacement every
+    /// slot is an independent draw, so `size` entrants are always drawn and
+    /// the same outcome may fill several slots.
+    Tournament {
+        size: usize,
+        #[serde(default)]
+        with_replacement: bool,
+    },
     /// Select parents using roulette wheel selection.
     RouletteWheel,
 }
 
 /// The strategy used for crossover.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CrossoverStrategy {
     /// Each stimulus command is chosen from one of the parents at random.
     Uniform,
 }
 
 /// The strategy used for mutation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MutationStrategy {
     /// A random mutation is chosen from a predefined set.
     Random,
 }
 
 /// Configuration knobs for the evolution harness.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EvolutionConfig {
     /// Number of candidates to evaluate in a single iteration.
     pub batch_size: usize,
@@ -63,6 +81,149 @@ pub struct EvolutionConfig {
     pub crossover_strategy: CrossoverStrategy,
     /// The mutation strategy to use for breeding new candidates.
     pub mutation_strategy: MutationStrategy,
+    /// Ceiling on the running product of stimulus factors applied to a single
+    /// topic along a candidate lineage.
+    #[serde(default = "default_max_cumulative_factor")]
+    pub max_cumulative_factor: f32,
+    /// End a sweep after the generation in which any candidate reaches this
+    /// fitness; `None` always runs the requested generation count.
+    #[serde(default)]
+    pub stop_on_fitness: Option<f32>,
+    /// Thresholds that mark an evaluated run as a breach.
+    #[serde(default)]
+    pub breach_criteria: BreachCriteria,
+    /// Directory structure for per-candidate artifacts.
+    #[serde(default)]
+    pub artifact_layout: ArtifactLayout,
+    /// How fitness components are scaled before weighting.
+    #[serde(default)]
+    pub fitness_normalization: FitnessNormalization,
+    /// What to do when a candidate's stimulus file does not exist.
+    #[serde(default)]
+    pub stimulus_missing_policy: StimulusMissingPolicy,
+    /// Last generation allowed to spawn a recommended follow-up mutant;
+    /// `None` lets mutation chains grow without bound.
+    #[serde(default)]
+    pub max_mutation_depth: Option<u32>,
+    /// Also recombine the parents' scenario configs during crossover instead
+    /// of reusing the first parent's scenario unchanged.
+    #[serde(default)]
+    pub crossover_scenarios: bool,
+    /// Highest-fitness archived candidates requeued unchanged each
+    /// generation alongside the new offspring; 0 disables elitism.
+    #[serde(default)]
+    pub elite_size: usize,
+}
+
+/// Scaling applied to fitness components so scores from different scenarios
+/// can be compared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FitnessNormalization {
+    /// Components against fixed reference levels (threat 1.5, stimulus 1.5
+    /// per step) and whole-run population extremes.
+    #[default]
+    Raw,
+    /// Threat and stimulus relative to the peak threat the candidate's
+    /// scenario schedules, so runs of different lengths and intensities land
+    /// on the same scale; other components match `Raw`. Without a readable
+    /// scenario the `Raw` reference level is used.
+    Scenario,
+}
+
+/// Handling of a candidate whose `stimulus_ref` points at a missing file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StimulusMissingPolicy {
+    /// Fail the candidate with `HarnessError::StimulusLoad`.
+    #[default]
+    Error,
+    /// Run the candidate with no stimulus, logging at debug level.
+    Skip,
+    /// Run the candidate with no stimulus and log a warning.
+    Warn,
+}
+
+/// How per-candidate artifact directories are arranged under an artifact root.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArtifactLayout {
+    /// `<root>/gen{NNN}/<id>/`
+    #[default]
+    Nested,
+    /// `<root>/gen{NNN}-<id>/`
+    Flat,
+}
+
+impl ArtifactLayout {
+    /// Directory holding artifacts for candidate `id` of `generation`. The id
+    /// is passed through [`sanitize_path_component`], so it never escapes the
+    /// root or adds nesting.
+    pub fn candidate_dir(self, artifact_root: &Path, generation: u32, id: &str) -> PathBuf {
+        let id = sanitize_path_component(id);
+        match self {
+            ArtifactLayout::Nested => artifact_root.join(format!("gen{generation:03}")).join(id),
+            ArtifactLayout::Flat => artifact_root.join(format!("gen{generation:03}-{id}")),
+        }
+    }
+}
+
+/// Make `raw` safe as a single path component. Bytes outside
+/// `[A-Za-z0-9._-]` are percent-encoded, as are all-dot names, so distinct
+/// inputs always map to distinct outputs.
+pub fn sanitize_path_component(raw: &str) -> String {
+    if raw.is_empty() {
+        return "%".to_string();
+    }
+    let all_dots = raw.bytes().all(|byte| byte == b'.');
+    let mut sanitized = String::with_capacity(raw.len());
+    for byte in raw.bytes() {
+        let keep = byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.');
+        if keep && !(all_dots && byte == b'.') {
+            sanitized.push(byte as char);
+        } else {
+            sanitized.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    sanitized
+}
+
+/// Thresholds consulted when deciding whether a run breached the defenses.
+/// Any criterion exceeded counts as a breach; `None` disables a criterion.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachCriteria {
+    /// Weighted fitness total.
+    pub fitness: Option<f32>,
+    /// Peak threat observed in any step.
+    pub max_threat: Option<f32>,
+    /// Fitness `cell_loss` component.
+    pub cell_loss: Option<f32>,
+    /// Fitness `lineage` component.
+    pub lineage: Option<f32>,
+}
+
+impl Default for BreachCriteria {
+    fn default() -> Self {
+        Self {
+            fitness: Some(0.65),
+            max_threat: Some(1.1),
+            cell_loss: Some(0.45),
+            lineage: Some(0.8),
+        }
+    }
+}
+
+impl BreachCriteria {
+    /// Whether a run with `stats` and fitness `breakdown` counts as a breach.
+    pub fn is_breach(&self, stats: &RunStatistics, breakdown: &FitnessBreakdown) -> bool {
+        let exceeds = |threshold: Option<f32>, value: f32| threshold.is_some_and(|t| value > t);
+        exceeds(self.fitness, breakdown.total)
+            || exceeds(self.max_threat, stats.max_threat)
+            || exceeds(self.cell_loss, breakdown.cell_loss)
+            || exceeds(self.lineage, breakdown.lineage)
+    }
+}
+
+fn default_max_cumulative_factor() -> f32 {
+    4.0
 }
 
 impl EvolutionConfig {
@@ -73,11 +234,34 @@ impl EvolutionConfig {
             max_generations: 10,
             retain_elite: true,
             crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
+            selection_strategy: SelectionStrategy::Tournament {
+                size: 3,
+                with_replacement: false,
+            },
             crossover_strategy: CrossoverStrategy::Uniform,
             mutation_strategy: MutationStrategy::Random,
+            max_cumulative_factor: default_max_cumulative_factor(),
+            stop_on_fitness: None,
+            breach_criteria: BreachCriteria::default(),
+            artifact_layout: ArtifactLayout::default(),
+            fitness_normalization: FitnessNormalization::default(),
+            stimulus_missing_policy: StimulusMissingPolicy::default(),
+            max_mutation_depth: None,
+            crossover_scenarios: false,
+            elite_size: 0,
         }
     }
+
+    /// [`Self::default_smoke_test`] rendered as pretty JSON, so users can
+    /// discover every knob and its default without reading the source.
+    pub fn print_defaults() -> String {
+        Self::default_smoke_test().to_json_pretty()
+    }
+
+    /// This config as pretty JSON, in the form harness state files store it.
+    pub fn to_json_pretty(&self) -> String {
+        serde_json::to_string_pretty(self).expect("EvolutionConfig serializes to JSON")
+    }
 }
 
 /// A structured mutation to be applied to an attack candidate.
@@ -149,6 +333,69 @@ pub struct AttackCandidate {
     pub parent_id: Option<String>,
     /// Optional mutation that produced this candidate.
     pub mutation: Option<Mutation>,
+    /// Seed scenario this candidate descends from; `None` for seeds themselves.
+    #[serde(default)]
+    pub origin_scenario: Option<String>,
+    /// Running product of stimulus factors applied per topic along this
+    /// lineage, including this candidate's own mutation.
+    #[serde(default)]
+    pub cumulative_factors: BTreeMap<String, f32>,
+}
+
+/// Stimulus topics that raise adversarial pressure, and so the only ones an
+/// increase may be redirected to once its own topic's budget is exhausted.
+const PRESSURE_TOPICS: [&str; 1] = ["activator"];
+
+/// Threat level fitness is measured against under
+/// [`FitnessNormalization::Raw`].
+const REFERENCE_THREAT: f32 = 1.5;
+
+impl AttackCandidate {
+    /// Seed scenario this candidate's lineage started from. Survives the
+    /// `scenario_ref` rewrites performed when mutated files are generated.
+    pub fn origin(&self) -> &str {
+        self.origin_scenario
+            .as_deref()
+            .unwrap_or(&self.scenario_ref)
+    }
+
+    /// Cumulative stimulus factor applied to `topic` so far (1.0 if untouched).
+    pub fn cumulative_factor(&self, topic: &str) -> f32 {
+        self.cumulative_factors.get(topic).copied().unwrap_or(1.0)
+    }
+
+    /// Clamp this candidate's mutation so no topic's cumulative factor exceeds
+    /// `max_cumulative_factor`, then fold it into `cumulative_factors`.
+    ///
+    /// `cumulative_factors` must hold the factors inherited from the parent.
+    /// An increase on an exhausted topic is redirected to a pressure topic
+    /// with headroom, or to a stimulus timing shift when none has any.
+    pub fn with_bounded_mutation(mut self, max_cumulative_factor: f32) -> Self {
+        if let Some(Mutation::IncreaseStimulus { topic, factor }) = &self.mutation {
+            let headroom = |topic: &str| max_cumulative_factor / self.cumulative_factor(topic);
+            let target = std::iter::once(topic.as_str())
+                .chain(PRESSURE_TOPICS.iter().copied().filter(|t| *t != topic))
+                .find(|t| headroom(t) > 1.0 + f32::EPSILON);
+            self.mutation = Some(match target {
+                Some(target) => Mutation::IncreaseStimulus {
+                    topic: target.to_string(),
+                    factor: factor.min(headroom(target)),
+                },
+                None => Mutation::ShiftStimulusTime {
+                    event_index: 0,
+                    time_delta: 1,
+                },
+            });
+        }
+        if let Some(
+            Mutation::IncreaseStimulus { topic, factor }
+            | Mutation::DecreaseStimulus { topic, factor },
+        ) = &self.mutation
+        {
+            *self.cumulative_factors.entry(topic.clone()).or_insert(1.0) *= factor;
+        }
+        self
+    }
 }
 
 /// Recorded outcome after executing a candidate against the runtime.
@@ -167,11 +414,19 @@ pub struct AttackOutcome {
 }
 
 /// Aggregated statistics derived from dashboard-ready telemetry exports.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RunStatistics {
     pub step_count: usize,
     pub avg_threat: f32,
     pub max_threat: f32,
+    /// Nearest-rank percentiles of per-step threat, separating one-off
+    /// spikes from sustained pressure.
+    #[serde(default)]
+    pub p50_threat: f32,
+    #[serde(default)]
+    pub p90_threat: f32,
+    #[serde(default)]
+    pub p99_threat: f32,
     pub avg_cell_count: f32,
     pub min_cell_count: usize,
     pub max_cell_count: usize,
@@ -180,13 +435,75 @@ pub struct RunStatistics {
     pub total_signals: u32,
     pub total_lineage_shifts: u32,
     pub total_stimulus: f32,
-    pub signals_by_topic: HashMap<String, u32>,
-    pub lineage_by_type: HashMap<String, u32>,
-    pub stimuli_by_topic: HashMap<String, f32>,
+    pub signals_by_topic: BTreeMap<String, u32>,
+    pub lineage_by_type: BTreeMap<String, u32>,
+    pub stimuli_by_topic: BTreeMap<String, f32>,
     pub avg_topology_degree: f32,
     pub max_isolation_count: u32,
 }
 
+/// Mean, sample standard deviation, and 95% confidence half-width of a metric.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub mean: f32,
+    pub std_dev: f32,
+    /// Half-width of the normal-approximation 95% confidence interval around `mean`.
+    pub ci95: f32,
+}
+
+/// Statistics aggregated across repeated runs of the same candidate.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AggregatedStatistics {
+    pub run_count: usize,
+    pub avg_threat: MetricSummary,
+    pub max_threat: MetricSummary,
+    #[serde(default)]
+    pub p50_threat: MetricSummary,
+    #[serde(default)]
+    pub p90_threat: MetricSummary,
+    #[serde(default)]
+    pub p99_threat: MetricSummary,
+    pub avg_cell_count: MetricSummary,
+    pub min_cell_count: MetricSummary,
+    pub max_cell_count: MetricSummary,
+    pub total_replications: MetricSummary,
+    pub total_deaths: MetricSummary,
+    pub total_signals: MetricSummary,
+    pub total_lineage_shifts: MetricSummary,
+    pub total_stimulus: MetricSummary,
+    pub avg_topology_degree: MetricSummary,
+    pub max_isolation_count: MetricSummary,
+}
+
+/// Conditions an outcome was produced under, emitted alongside it so a run
+/// can be reproduced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunMetadata {
+    pub scenario_path: String,
+    pub stimulus_path: Option<String>,
+    /// Seed of the scenario's fault RNG; `None` when it isn't known.
+    pub rng_seed: Option<u64>,
+    pub evolution_config: EvolutionConfig,
+    pub crate_version: String,
+}
+
+impl RunMetadata {
+    pub fn new(
+        scenario_path: impl Into<String>,
+        stimulus_path: Option<String>,
+        rng_seed: Option<u64>,
+        evolution_config: &EvolutionConfig,
+    ) -> Self {
+        Self {
+            scenario_path: scenario_path.into(),
+            stimulus_path,
+            rng_seed,
+            evolution_config: evolution_config.clone(),
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
 /// Harness evaluation result combining statistics, fitness, and guidance.
 #[derive(Debug, Clone)]
 pub struct HarnessAnalysis {
@@ -194,10 +511,47 @@ pub struct HarnessAnalysis {
     pub fitness_score: f32,
     pub breach_observed: bool,
     pub recommended_mutation: Option<Mutation>,
+    /// Per-component contributions behind `fitness_score`.
+    pub fitness_breakdown: FitnessBreakdown,
+    /// Which `recommend_mutation` branch produced `recommended_mutation`.
+    pub recommendation_reason: &'static str,
+}
+
+/// Normalised fitness components (each in `[0, 1]`) and the weighted total.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FitnessBreakdown {
+    pub threat: f32,
+    pub suppression: f32,
+    pub cell_loss: f32,
+    pub lineage: f32,
+    pub stimulus: f32,
+    pub topology: f32,
+    pub total: f32,
+}
+
+impl FitnessBreakdown {
+    pub const THREAT_WEIGHT: f32 = 0.3;
+    pub const SUPPRESSION_WEIGHT: f32 = 0.2;
+    pub const CELL_LOSS_WEIGHT: f32 = 0.1;
+    pub const LINEAGE_WEIGHT: f32 = 0.2;
+    pub const STIMULUS_WEIGHT: f32 = 0.1;
+    pub const TOPOLOGY_WEIGHT: f32 = 0.1;
+
+    /// `(name, weight, component)` triples in display order.
+    pub fn components(&self) -> [(&'static str, f32, f32); 6] {
+        [
+            ("threat", Self::THREAT_WEIGHT, self.threat),
+            ("suppression", Self::SUPPRESSION_WEIGHT, self.suppression),
+            ("cell_loss", Self::CELL_LOSS_WEIGHT, self.cell_loss),
+            ("lineage", Self::LINEAGE_WEIGHT, self.lineage),
+            ("stimulus", Self::STIMULUS_WEIGHT, self.stimulus),
+            ("topology", Self::TOPOLOGY_WEIGHT, self.topology),
+        ]
+    }
 }
 
 /// Per-step telemetry summary used to build [`RunStatistics`].
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StepMetrics {
     pub step: u32,
     pub threat_score: f32,
@@ -207,13 +561,25 @@ pub struct StepMetrics {
     pub signals_total: u32,
     pub lineage_shifts_total: u32,
     pub stimulus_total: f32,
-    pub signals_by_topic: HashMap<String, u32>,
-    pub lineage_shifts_by_lineage: HashMap<String, u32>,
-    pub stimulus_by_topic: HashMap<String, f32>,
+    pub signals_by_topic: BTreeMap<String, u32>,
+    pub lineage_shifts_by_lineage: BTreeMap<String, u32>,
+    pub stimulus_by_topic: BTreeMap<String, f32>,
     pub population_stats: Option<PopulationStats>,
     pub topology_stats: Option<TopologyStats>,
 }
 
+impl StepMetrics {
+    /// Derive one row per `StepSummary` from buffered telemetry. Stimulus
+    /// fields stay empty because injected stimulus is not part of telemetry.
+    pub fn from_telemetry(events: &[TelemetrySnapshot]) -> Vec<StepMetrics> {
+        let mut pipeline = StreamingPipeline::default();
+        for snapshot in events {
+            pipeline.record(snapshot.timestamp, snapshot.event.clone());
+        }
+        pipeline.take_step_metrics()
+    }
+}
+
 /// Rolling archive snapshot used for persistence.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarnessState {
@@ -253,6 +619,16 @@ pub enum HarnessError {
     Csv(csv::Error),
     Json(serde_json::Error),
     EmptyDataset,
+    /// Parent selection found nothing to pick from the archive.
+    SelectionFailed(String),
+    ScenarioLoad {
+        path: PathBuf,
+        source: ConfigError,
+    },
+    StimulusLoad {
+        path: PathBuf,
+        source: io::Error,
+    },
     Custom(String),
 }
 
@@ -263,12 +639,70 @@ impl std::fmt::Display for HarnessError {
             HarnessError::Csv(err) => write!(f, "CSV parse error: {err}"),
             HarnessError::Json(err) => write!(f, "JSON parse error: {err}"),
             HarnessError::EmptyDataset => write!(f, "no rows found in telemetry metrics"),
+            HarnessError::SelectionFailed(reason) => write!(f, "Selection failed: {reason}"),
+            HarnessError::ScenarioLoad { path, source } => {
+                write!(f, "Failed to load scenario `{}`: {source}", path.display())
+            }
+            HarnessError::StimulusLoad { path, source } => {
+                write!(f, "Failed to load stimulus `{}`: {source}", path.display())
+            }
             HarnessError::Custom(message) => write!(f, "{message}"),
         }
     }
 }
 
-impl std::error::Error for HarnessError {}
+impl std::error::Error for HarnessError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HarnessError::ScenarioLoad { source, .. } => Some(source),
+            HarnessError::StimulusLoad { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Load a scenario, tagging failures with the offending path.
+pub fn load_scenario<P: AsRef<Path>>(path: P) -> Result<ScenarioConfig, HarnessError> {
+    config::load_from_path(path.as_ref()).map_err(|source| HarnessError::ScenarioLoad {
+        path: path.as_ref().to_path_buf(),
+        source,
+    })
+}
+
+/// Load a stimulus schedule, tagging failures with the offending path.
+pub fn load_stimulus<P: AsRef<Path>>(path: P) -> Result<StimulusSchedule, HarnessError> {
+    StimulusSchedule::load_auto(path.as_ref()).map_err(|source| HarnessError::StimulusLoad {
+        path: path.as_ref().to_path_buf(),
+        source,
+    })
+}
+
+/// Load a candidate's stimulus schedule, treating a missing file as no
+/// stimulus unless `policy` is [`StimulusMissingPolicy::Error`]. Files that
+/// exist but fail to parse are always errors.
+pub fn load_candidate_stimulus<P: AsRef<Path>>(
+    path: P,
+    policy: StimulusMissingPolicy,
+) -> Result<Option<StimulusSchedule>, HarnessError> {
+    match load_stimulus(path.as_ref()) {
+        Ok(schedule) => Ok(Some(schedule)),
+        Err(HarnessError::StimulusLoad { path, source })
+            if source.kind() == io::ErrorKind::NotFound
+                && policy != StimulusMissingPolicy::Error =>
+        {
+            if policy == StimulusMissingPolicy::Warn {
+                log::warn!(
+                    "Stimulus `{}` not found; running without stimulus",
+                    path.display()
+                );
+            } else {
+                log::debug!("Skipping missing stimulus `{}`", path.display());
+            }
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
 
 impl From<io::Error> for HarnessError {
     fn from(value: io::Error) -> Self {
@@ -313,15 +747,34 @@ impl AdversarialHarness {
     }
 
     /// Reconstruct a harness from persisted state.
+    ///
+    /// The archive is put in [`Self::sort_archive_by_generation`] order, so
+    /// selection and export do not depend on how the state was written.
     pub fn from_state(state: HarnessState) -> Self {
-        Self {
+        let mut harness = Self {
             config: state.config,
             backlog: state.backlog,
             archive: state.archive,
-        }
+        };
+        harness.sort_archive_by_generation();
+        harness
+    }
+
+    /// Order the archive by candidate generation, breaking ties by candidate
+    /// id. The sort is stable, so outcomes sharing both keep their order.
+    pub fn sort_archive_by_generation(&mut self) {
+        self.archive.sort_by(|a, b| {
+            a.candidate
+                .generation
+                .cmp(&b.candidate.generation)
+                .then_with(|| a.candidate.id.cmp(&b.candidate.id))
+        });
     }
 
     /// Persist the current harness snapshot to disk.
+    ///
+    /// The snapshot is written atomically, so a crash mid-write never
+    /// clobbers the previous state.
     pub fn save_state<P: AsRef<Path>>(&self, path: P) -> Result<(), HarnessError> {
         let path = path.as_ref();
         if let Some(parent) = path.parent()
@@ -329,9 +782,10 @@ impl AdversarialHarness {
         {
             fs::create_dir_all(parent)?;
         }
-        let file = File::create(path)?;
-        serde_json::to_writer_pretty(file, &self.snapshot_state())?;
-        Ok(())
+        write_atomic(path, |writer| -> Result<(), HarnessError> {
+            serde_json::to_writer_pretty(writer, &self.snapshot_state())?;
+            Ok(())
+        })
     }
 
     /// Load a persisted harness state from disk.
@@ -342,6 +796,82 @@ impl AdversarialHarness {
         Ok(Self::from_state(state))
     }
 
+    /// Load persisted state, salvaging what it can from a truncated file.
+    ///
+    /// When the JSON does not parse, the `config` plus every complete
+    /// `backlog` and `archive` entry are recovered. The returned count is the
+    /// number of sections or entries that had to be dropped; it is zero for
+    /// an intact file. Fails only if not even the config survives.
+    pub fn load_state_lenient<P: AsRef<Path>>(path: P) -> Result<(Self, usize), HarnessError> {
+        let text = fs::read_to_string(path)?;
+        match serde_json::from_str::<HarnessState>(&text) {
+            Ok(state) => Ok((Self::from_state(state), 0)),
+            Err(err) => recover_state(&text)
+                .map(|(state, warnings)| (Self::from_state(state), warnings))
+                .ok_or(HarnessError::Json(err)),
+        }
+    }
+
+    /// Persist the harness snapshot in compact bincode form.
+    #[cfg(feature = "bincode")]
+    pub fn save_state_bincode<P: AsRef<Path>>(&self, path: P) -> Result<(), HarnessError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.exists()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        write_atomic(path, |writer| {
+            bincode::serialize_into(writer, &self.snapshot_state())
+                .map_err(|err| HarnessError::Custom(format!("bincode encode error: {err}")))
+        })
+    }
+
+    /// Load a harness snapshot written by [`Self::save_state_bincode`].
+    #[cfg(feature = "bincode")]
+    pub fn load_state_bincode<P: AsRef<Path>>(path: P) -> Result<Self, HarnessError> {
+        let reader = BufReader::new(File::open(path)?);
+        let state: HarnessState = bincode::deserialize_from(reader)
+            .map_err(|err| HarnessError::Custom(format!("bincode decode error: {err}")))?;
+        Ok(Self::from_state(state))
+    }
+
+    /// Persist state as bincode for `.bin` paths and as JSON otherwise.
+    pub fn save_state_auto<P: AsRef<Path>>(&self, path: P) -> Result<(), HarnessError> {
+        let path = path.as_ref();
+        if !is_bincode_path(path) {
+            return self.save_state(path);
+        }
+        #[cfg(feature = "bincode")]
+        return self.save_state_bincode(path);
+        #[cfg(not(feature = "bincode"))]
+        Err(bincode_disabled(path))
+    }
+
+    /// Counterpart of [`Self::save_state_auto`], selecting the format by extension.
+    pub fn load_state_auto<P: AsRef<Path>>(path: P) -> Result<Self, HarnessError> {
+        let path = path.as_ref();
+        if !is_bincode_path(path) {
+            return Self::load_state(path);
+        }
+        #[cfg(feature = "bincode")]
+        return Self::load_state_bincode(path);
+        #[cfg(not(feature = "bincode"))]
+        Err(bincode_disabled(path))
+    }
+
+    /// Like [`Self::load_state_auto`], but JSON states are loaded with
+    /// [`Self::load_state_lenient`]. Bincode states are all-or-nothing, so
+    /// their dropped count is always zero.
+    pub fn load_state_lenient_auto<P: AsRef<Path>>(path: P) -> Result<(Self, usize), HarnessError> {
+        let path = path.as_ref();
+        if is_bincode_path(path) {
+            Self::load_state_auto(path).map(|harness| (harness, 0))
+        } else {
+            Self::load_state_lenient(path)
+        }
+    }
+
     /// Current harness configuration.
     pub fn config(&self) -> &EvolutionConfig {
         &self.config
@@ -352,6 +882,22 @@ impl AdversarialHarness {
         self.backlog.len()
     }
 
+    /// Pending candidates in execution order.
+    pub fn backlog(&self) -> &VecDeque<AttackCandidate> {
+        &self.backlog
+    }
+
+    /// Archived outcomes, oldest first. A loaded harness lists them in
+    /// [`Self::sort_archive_by_generation`] order.
+    pub fn archive(&self) -> &[AttackOutcome] {
+        &self.archive
+    }
+
+    /// The candidate the next batch will start with, left in the backlog.
+    pub fn peek_next(&self) -> Option<&AttackCandidate> {
+        self.backlog.front()
+    }
+
     /// Queue a new candidate for evaluation.
     pub fn enqueue(&mut self, candidate: AttackCandidate) {
         self.backlog.push_back(candidate);
@@ -404,7 +950,8 @@ impl AdversarialHarness {
         steps: Vec<StepMetrics>,
     ) -> Result<(AttackOutcome, Option<AttackCandidate>, HarnessAnalysis), HarnessError> {
         let stats = build_statistics_from_steps(&steps)?;
-        let analysis = analyze_run_statistics(stats);
+        let envelope = self.scenario_envelope(&candidate);
+        let analysis = analyze_run_statistics(stats, &self.config, envelope);
         Ok(self.finalize_evaluation(candidate, analysis))
     }
 
@@ -413,25 +960,92 @@ impl AdversarialHarness {
     /// The executor is responsible for running the morphogenetic runtime and
     /// returning per-step metrics alongside any persisted artifacts.
     pub fn run_generations<F>(
+        &mut self,
+        generations: usize,
+        artifact_root: &Path,
+        executor: F,
+    ) -> Result<Vec<EvaluatedCandidate>, HarnessError>
+    where
+        F: FnMut(&AttackCandidate) -> Result<ExecutionReport, HarnessError>,
+    {
+        self.run_generations_until(generations, artifact_root, executor, |_| false)
+    }
+
+    /// Like [`Self::run_generations`], but consults `should_stop` with the
+    /// number of completed generations at every generation boundary and
+    /// returns early, with the harness in a consistent state, once it
+    /// answers `true`.
+    pub fn run_generations_until<F, S>(
+        &mut self,
+        generations: usize,
+        artifact_root: &Path,
+        executor: F,
+        should_stop: S,
+    ) -> Result<Vec<EvaluatedCandidate>, HarnessError>
+    where
+        F: FnMut(&AttackCandidate) -> Result<ExecutionReport, HarnessError>,
+        S: FnMut(usize) -> bool,
+    {
+        let never_cancelled = AtomicBool::new(false);
+        self.drive_generations(
+            generations,
+            artifact_root,
+            executor,
+            should_stop,
+            &never_cancelled,
+        )
+    }
+
+    /// Like [`Self::run_generations`], but stops once `cancel` is set (e.g.
+    /// from a Ctrl-C handler on another thread).
+    ///
+    /// The flag is checked at every generation boundary and before each
+    /// candidate. Evaluations completed so far are returned; candidates not
+    /// yet executed stay at the front of the backlog.
+    pub fn run_generations_cancellable<F>(
+        &mut self,
+        generations: usize,
+        artifact_root: &Path,
+        executor: F,
+        cancel: &AtomicBool,
+    ) -> Result<Vec<EvaluatedCandidate>, HarnessError>
+    where
+        F: FnMut(&AttackCandidate) -> Result<ExecutionReport, HarnessError>,
+    {
+        self.drive_generations(generations, artifact_root, executor, |_| false, cancel)
+    }
+
+    fn drive_generations<F, S>(
         &mut self,
         generations: usize,
         artifact_root: &Path,
         mut executor: F,
+        mut should_stop: S,
+        cancel: &AtomicBool,
     ) -> Result<Vec<EvaluatedCandidate>, HarnessError>
     where
         F: FnMut(&AttackCandidate) -> Result<ExecutionReport, HarnessError>,
+        S: FnMut(usize) -> bool,
     {
         let mut all_evaluations = Vec::new();
 
         for gen_idx in 0..generations {
-            println!("[info] Starting generation {}/{}", gen_idx + 1, generations);
+            if cancel.load(Ordering::SeqCst) {
+                log::info!("Cancelled after {gen_idx} generation(s).");
+                break;
+            }
+            if gen_idx > 0 && should_stop(gen_idx) {
+                log::info!("Stopping after {gen_idx} generation(s) on request.");
+                break;
+            }
+            log::info!("Starting generation {}/{}", gen_idx + 1, generations);
 
             // 1. Process all candidates currently in the backlog
             let mut current_generation_evaluations = Vec::new();
             let backlog_size = self.backlog.len();
 
             if backlog_size == 0 && gen_idx > 0 {
-                println!("[warn] Backlog empty, no candidates to evaluate for this generation.");
+                log::warn!("Backlog empty, no candidates to evaluate for this generation.");
                 break;
             }
 
@@ -440,16 +1054,41 @@ impl AdversarialHarness {
                 .collect();
 
             if candidates_to_process.is_empty() && gen_idx == 0 {
-                println!("[warn] No seed candidates in backlog. Exiting.");
+                log::warn!("No seed candidates in backlog. Exiting.");
                 break;
             }
 
-            for candidate in candidates_to_process {
+            let mut pending = candidates_to_process.into_iter();
+            while let Some(candidate) = pending.next() {
+                if cancel.load(Ordering::SeqCst) {
+                    // Hand unexecuted candidates back in their original order.
+                    let unexecuted: Vec<AttackCandidate> =
+                        std::iter::once(candidate).chain(pending).collect();
+                    log::info!(
+                        "Cancelled mid-generation; {} candidate(s) returned to the backlog.",
+                        unexecuted.len()
+                    );
+                    for candidate in unexecuted.into_iter().rev() {
+                        self.backlog.push_front(candidate);
+                    }
+                    all_evaluations.extend(current_generation_evaluations);
+                    return Ok(all_evaluations);
+                }
                 let candidate_snapshot = candidate.clone();
-                let report = executor(&candidate_snapshot)?;
+                let rerun = self
+                    .archive
+                    .iter()
+                    .any(|outcome| outcome.candidate.id == candidate.id);
+                let report = executor(&self.execution_view(&candidate_snapshot, rerun, gen_idx))?;
                 let stats = build_statistics_from_steps(&report.steps)?;
-                let analysis = analyze_run_statistics(stats);
-                let (outcome, follow_up, analysis) = self.finalize_evaluation(candidate, analysis);
+                let envelope = self.scenario_envelope(&candidate);
+                let analysis = analyze_run_statistics(stats, &self.config, envelope);
+                let (outcome, follow_up, analysis) = if rerun {
+                    // Already archived, and its follow-up already queued.
+                    (outcome_for_analysis(candidate, &analysis), None, analysis)
+                } else {
+                    self.finalize_evaluation(candidate, analysis)
+                };
                 let backlog_len_after = self.backlog.len(); // This backlog length is for immediate follow-ups
                 current_generation_evaluations.push(EvaluatedCandidate {
                     candidate: candidate_snapshot,
@@ -460,11 +1099,23 @@ impl AdversarialHarness {
                     backlog_len_after,
                 });
             }
+            let fitness_trigger = self.config.stop_on_fitness.and_then(|threshold| {
+                current_generation_evaluations
+                    .iter()
+                    .find(|evaluation| evaluation.outcome.fitness_score >= threshold)
+                    .map(|evaluation| {
+                        (
+                            threshold,
+                            evaluation.candidate.id.clone(),
+                            evaluation.outcome.fitness_score,
+                        )
+                    })
+            });
             all_evaluations.extend(current_generation_evaluations);
 
             // 2. Select parents and generate new candidates for the next generation
             if self.archive.is_empty() {
-                println!("[warn] Archive empty, cannot select parents for next generation.");
+                log::warn!("Archive empty, cannot select parents for next generation.");
                 continue;
             }
 
@@ -475,28 +1126,26 @@ impl AdversarialHarness {
                 let new_candidate = if rng.gen_range(0.0..1.0) < self.config.crossover_rate {
                     // Perform crossover
                     let parent1 = match self.config.selection_strategy {
-                        SelectionStrategy::Tournament { size } => {
-                            tournament_selection(&self.archive, size, &mut rng)
-                        }
+                        SelectionStrategy::Tournament {
+                            size,
+                            with_replacement,
+                        } => tournament_selection(&self.archive, size, with_replacement, &mut rng),
                         SelectionStrategy::RouletteWheel => {
                             roulette_wheel_selection(&self.archive, &mut rng)
                         }
                     }
-                    .map_err(|e| {
-                        HarnessError::Custom(format!("Selection failed for parent 1: {}", e))
-                    })?;
+                    .map_err(HarnessError::SelectionFailed)?;
 
                     let parent2 = match self.config.selection_strategy {
-                        SelectionStrategy::Tournament { size } => {
-                            tournament_selection(&self.archive, size, &mut rng)
-                        }
+                        SelectionStrategy::Tournament {
+                            size,
+                            with_replacement,
+                        } => tournament_selection(&self.archive, size, with_replacement, &mut rng),
                         SelectionStrategy::RouletteWheel => {
                             roulette_wheel_selection(&self.archive, &mut rng)
                         }
                     }
-                    .map_err(|e| {
-                        HarnessError::Custom(format!("Selection failed for parent 2: {}", e))
-                    })?;
+                    .map_err(HarnessError::SelectionFailed)?;
 
                     perform_crossover(
                         parent1,
@@ -504,18 +1153,21 @@ impl AdversarialHarness {
                         &mut rng,
                         artifact_root,
                         &self.config.crossover_strategy,
+                        self.config.artifact_layout,
+                        self.config.crossover_scenarios,
                     )?
                 } else {
                     // Perform mutation
                     let parent_outcome = match self.config.selection_strategy {
-                        SelectionStrategy::Tournament { size } => {
-                            tournament_selection(&self.archive, size, &mut rng)
-                        }
+                        SelectionStrategy::Tournament {
+                            size,
+                            with_replacement,
+                        } => tournament_selection(&self.archive, size, with_replacement, &mut rng),
                         SelectionStrategy::RouletteWheel => {
                             roulette_wheel_selection(&self.archive, &mut rng)
                         }
                     }
-                    .map_err(|e| HarnessError::Custom(format!("Selection failed: {}", e)))?;
+                    .map_err(HarnessError::SelectionFailed)?;
 
                     // Use a hash of parent ID to keep the child ID length manageable
                     use std::collections::hash_map::DefaultHasher;
@@ -541,20 +1193,86 @@ impl AdversarialHarness {
                         stimulus_ref: parent_outcome.candidate.stimulus_ref.clone(),
                         generation: gen_idx as u32 + 1,
                         parent_id: Some(parent_outcome.candidate.id.clone()),
+                        origin_scenario: Some(parent_outcome.candidate.origin().to_string()),
                         mutation,
+                        cumulative_factors: parent_outcome.candidate.cumulative_factors.clone(),
                     }
                 };
-                self.enqueue(new_candidate);
+                self.enqueue(
+                    new_candidate.with_bounded_mutation(self.config.max_cumulative_factor),
+                );
+            }
+            let elites = self.elite_candidates();
+            if !elites.is_empty() {
+                log::info!(
+                    "Carrying {} elite candidate(s) into the next generation.",
+                    elites.len()
+                );
             }
-            println!(
-                "[info] Enqueued {} new candidates for next generation.",
+            for elite in elites {
+                self.enqueue(elite);
+            }
+            log::info!(
+                "Enqueued {} new candidates for next generation.",
                 num_new_candidates
             );
+
+            if let Some((threshold, candidate_id, fitness)) = fitness_trigger {
+                log::info!(
+                    "Candidate `{candidate_id}` reached fitness {fitness:.3} (threshold {threshold:.3}); stopping after generation {} with {} candidate(s) left in the backlog.",
+                    gen_idx + 1,
+                    self.backlog.len()
+                );
+                break;
+            }
         }
 
         Ok(all_evaluations)
     }
 
+    /// Re-run the archived candidate `candidate_id` through `executor` and
+    /// score it afresh. The archive and backlog are left untouched, so a
+    /// replay never skews later generations.
+    pub fn replay<F>(
+        &self,
+        candidate_id: &str,
+        executor: F,
+    ) -> Result<EvaluatedCandidate, HarnessError>
+    where
+        F: FnOnce(&AttackCandidate) -> Result<ExecutionReport, HarnessError>,
+    {
+        let archived = self
+            .archive
+            .iter()
+            .rev()
+            .find(|outcome| outcome.candidate.id == candidate_id)
+            .ok_or_else(|| {
+                HarnessError::Custom(format!(
+                    "candidate `{candidate_id}` is not in the harness archive"
+                ))
+            })?;
+        let candidate = archived.candidate.clone();
+        let report = executor(&candidate)?;
+        let stats = build_statistics_from_steps(&report.steps)?;
+        let analysis =
+            analyze_run_statistics(stats, &self.config, self.scenario_envelope(&candidate));
+        let outcome = AttackOutcome {
+            candidate: candidate.clone(),
+            fitness_score: analysis.fitness_score,
+            breach_observed: analysis.breach_observed,
+            notes: Some(outcome_note_for_analysis(&analysis)),
+            statistics: analysis.statistics.clone(),
+        };
+        Ok(EvaluatedCandidate {
+            candidate,
+            outcome,
+            analysis,
+            follow_up: None,
+            report,
+            backlog_len_after: self.backlog_len(),
+        })
+    }
+
     /// Most recent outcomes, truncated to the configured generation history.
     pub fn recent_outcomes(&self) -> Vec<&AttackOutcome> {
         let limit = self.config.max_generations as usize;
@@ -565,33 +1283,169 @@ impl AdversarialHarness {
             .collect::<Vec<&AttackOutcome>>()
     }
 
-    /// Requeue a candidate for additional mutations when elite retention is enabled.
-    pub fn maybe_requeue(&mut self, candidate: AttackCandidate) {
-        if self.config.retain_elite {
-            self.backlog.push_back(candidate);
+    /// Archived outcomes whose lineage started from `scenario_ref`.
+    pub fn outcomes_for_scenario(&self, scenario_ref: &str) -> Vec<&AttackOutcome> {
+        self.archive
+            .iter()
+            .filter(|outcome| outcome.candidate.origin() == scenario_ref)
+            .collect()
+    }
+
+    /// Highest-fitness archived outcome for each origin scenario.
+    pub fn best_outcome_per_scenario(&self) -> HashMap<String, &AttackOutcome> {
+        let mut best: HashMap<String, &AttackOutcome> = HashMap::new();
+        for outcome in &self.archive {
+            let origin = outcome.candidate.origin();
+            match best.get(origin) {
+                Some(current) if current.fitness_score >= outcome.fitness_score => {}
+                _ => {
+                    best.insert(origin.to_string(), outcome);
+                }
+            }
         }
+        best
     }
 
-    fn finalize_evaluation(
-        &mut self,
-        candidate: AttackCandidate,
-        analysis: HarnessAnalysis,
-    ) -> (AttackOutcome, Option<AttackCandidate>, HarnessAnalysis) {
-        let outcome_candidate = candidate.clone();
-        let note = outcome_note_for_analysis(&analysis);
+    /// Distribution of archived fitness scores over `[0, 1]` in `bins` equal
+    /// buckets, as `(bin_start, count)` pairs. Empty when nothing is archived.
+    pub fn fitness_histogram(&self, bins: usize) -> Vec<(f32, usize)> {
+        if bins == 0 || self.archive.is_empty() {
+            return Vec::new();
+        }
+        let width = 1.0 / bins as f32;
+        let mut counts = vec![0usize; bins];
+        for outcome in &self.archive {
+            let score = outcome.fitness_score.clamp(0.0, 1.0);
+            let index = ((score * bins as f32) as usize).min(bins - 1);
+            counts[index] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| (index as f32 * width, count))
+            .collect()
+    }
 
-        let outcome = AttackOutcome {
-            candidate: outcome_candidate.clone(),
-            fitness_score: analysis.fitness_score,
-            breach_observed: analysis.breach_observed,
-            notes: Some(note),
-            statistics: analysis.statistics.clone(),
-        };
-        self.record_outcome(outcome.clone());
+    /// Write [`Self::fitness_histogram`] as `bin_start,bin_end,count` rows.
+    pub fn write_fitness_histogram_csv<P: AsRef<Path>>(
+        &self,
+        path: P,
+        bins: usize,
+    ) -> Result<(), HarnessError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.exists()
+        {
+            fs::create_dir_all(parent)?;
+        }
 
-        let recommended_mutation = analysis.recommended_mutation.clone();
-        let next_candidate = recommended_mutation.map(|mutation| {
-            let next_generation = candidate.generation + 1;
+        let width = if bins == 0 { 0.0 } else { 1.0 / bins as f32 };
+        write_atomic(path, |file| -> Result<(), HarnessError> {
+            let mut writer = WriterBuilder::new().has_headers(true).from_writer(file);
+            writer.write_record(["bin_start", "bin_end", "count"])?;
+            for (start, count) in self.fitness_histogram(bins) {
+                writer.write_record([
+                    format!("{start:.4}"),
+                    format!("{:.4}", start + width),
+                    count.to_string(),
+                ])?;
+            }
+            writer.flush()?;
+            Ok(())
+        })
+    }
+
+    /// The `elite_size` highest-fitness archived candidates, best first. A
+    /// candidate archived more than once counts once, at its best score.
+    fn elite_candidates(&self) -> Vec<AttackCandidate> {
+        if self.config.elite_size == 0 {
+            return Vec::new();
+        }
+        let mut ranked: Vec<&AttackOutcome> = self.archive.iter().collect();
+        ranked.sort_by(|a, b| b.fitness_score.total_cmp(&a.fitness_score));
+        let mut seen = HashSet::new();
+        ranked
+            .into_iter()
+            .filter(|outcome| seen.insert(outcome.candidate.id.as_str()))
+            .take(self.config.elite_size)
+            .map(|outcome| outcome.candidate.clone())
+            .collect()
+    }
+
+    /// Peak threat `candidate`'s scenario schedules once its mutation is
+    /// applied. Only [`FitnessNormalization::Scenario`] needs it; `None` when
+    /// the scenario cannot be read.
+    fn scenario_envelope(&self, candidate: &AttackCandidate) -> Option<f32> {
+        if self.config.fitness_normalization != FitnessNormalization::Scenario {
+            return None;
+        }
+        match load_scenario(&candidate.scenario_ref) {
+            Ok(mut scenario) => {
+                if let Some(mutation) = &candidate.mutation {
+                    scenario.apply_mutation(mutation);
+                }
+                Some(scenario.peak_threat_level())
+            }
+            Err(err) => {
+                log::warn!(
+                    "Scoring `{}` against the reference threat: {err}",
+                    candidate.id
+                );
+                None
+            }
+        }
+    }
+
+    /// The candidate handed to the executor. A re-run of an archived
+    /// candidate keeps its id in the backlog, but runs as `<id>-rerunN`, `N`
+    /// being the generation index, so its artifacts land beside, not over,
+    /// those of its earlier runs.
+    fn execution_view(
+        &self,
+        candidate: &AttackCandidate,
+        rerun: bool,
+        gen_idx: usize,
+    ) -> AttackCandidate {
+        if !rerun {
+            return candidate.clone();
+        }
+        AttackCandidate {
+            id: format!("{}-rerun{gen_idx}", candidate.id),
+            ..candidate.clone()
+        }
+    }
+
+    /// Requeue a candidate for additional mutations when elite retention is enabled.
+    pub fn maybe_requeue(&mut self, candidate: AttackCandidate) {
+        if self.config.retain_elite {
+            self.backlog.push_back(candidate);
+        }
+    }
+
+    fn finalize_evaluation(
+        &mut self,
+        candidate: AttackCandidate,
+        analysis: HarnessAnalysis,
+    ) -> (AttackOutcome, Option<AttackCandidate>, HarnessAnalysis) {
+        let outcome = outcome_for_analysis(candidate.clone(), &analysis);
+        self.record_outcome(outcome.clone());
+
+        let within_depth = self
+            .config
+            .max_mutation_depth
+            .is_none_or(|depth| candidate.generation < depth);
+        if !within_depth && analysis.recommended_mutation.is_some() {
+            log::debug!(
+                "Candidate `{}` reached max_mutation_depth; no follow-up queued",
+                candidate.id
+            );
+        }
+        let recommended_mutation = analysis
+            .recommended_mutation
+            .clone()
+            .filter(|_| within_depth);
+        let next_candidate = recommended_mutation.map(|mutation| {
+            let next_generation = candidate.generation + 1;
 
             use std::collections::hash_map::DefaultHasher;
             use std::hash::{Hash, Hasher};
@@ -607,8 +1461,11 @@ impl AdversarialHarness {
                 stimulus_ref: candidate.stimulus_ref.clone(),
                 generation: next_generation,
                 parent_id: Some(candidate.id.clone()),
+                origin_scenario: Some(candidate.origin().to_string()),
                 mutation: Some(mutation),
+                cumulative_factors: candidate.cumulative_factors.clone(),
             }
+            .with_bounded_mutation(self.config.max_cumulative_factor)
         });
 
         if let Some(mutant) = &next_candidate {
@@ -627,56 +1484,249 @@ impl AdversarialHarness {
     }
 }
 
+/// Rebuild a [`HarnessState`] from a partially written JSON document.
+fn recover_state(text: &str) -> Option<(HarnessState, usize)> {
+    let fields = top_level_fields(text);
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(key, _, _)| key == name)
+            .map(|(_, value, complete)| (*value, *complete))
+    };
+
+    let (config_text, config_complete) = field("config")?;
+    if !config_complete {
+        return None;
+    }
+    let config: EvolutionConfig = serde_json::from_str(config_text).ok()?;
+
+    let mut warnings = 0;
+    let backlog = recover_entries(field("backlog"), &mut warnings);
+    let archive = recover_entries(field("archive"), &mut warnings);
+    Some((
+        HarnessState {
+            config,
+            backlog,
+            archive,
+        },
+        warnings,
+    ))
+}
+
+/// Parse every complete element of a (possibly truncated) JSON array,
+/// counting missing sections, truncation, and unparseable entries.
+fn recover_entries<T, C>(field: Option<(&str, bool)>, warnings: &mut usize) -> C
+where
+    T: serde::de::DeserializeOwned,
+    C: FromIterator<T>,
+{
+    let Some((text, _)) = field else {
+        *warnings += 1;
+        return std::iter::empty().collect();
+    };
+    let (elements, complete) = json_array_elements(text);
+    if !complete {
+        *warnings += 1;
+    }
+    elements
+        .into_iter()
+        .filter_map(|element| match serde_json::from_str(element) {
+            Ok(entry) => Some(entry),
+            Err(_) => {
+                *warnings += 1;
+                None
+            }
+        })
+        .collect()
+}
+
+/// Split the top-level object into `(key, raw value, complete)` triples,
+/// stopping at the first value that runs past the end of the input.
+fn top_level_fields(text: &str) -> Vec<(String, &str, bool)> {
+    let bytes = text.as_bytes();
+    let mut fields = Vec::new();
+    let mut idx = skip_json_whitespace(bytes, 0);
+    if bytes.get(idx) != Some(&b'{') {
+        return fields;
+    }
+    idx += 1;
+    loop {
+        idx = skip_json_whitespace(bytes, idx);
+        if bytes.get(idx) != Some(&b'"') {
+            break;
+        }
+        let Some(key_end) = json_value_end(bytes, idx) else {
+            break;
+        };
+        let Ok(key) = serde_json::from_str::<String>(&text[idx..key_end]) else {
+            break;
+        };
+        idx = skip_json_whitespace(bytes, key_end);
+        if bytes.get(idx) != Some(&b':') {
+            break;
+        }
+        idx = skip_json_whitespace(bytes, idx + 1);
+        if idx >= bytes.len() {
+            break;
+        }
+        let Some(end) = json_value_end(bytes, idx) else {
+            fields.push((key, &text[idx..], false));
+            break;
+        };
+        fields.push((key, &text[idx..end], true));
+        idx = skip_json_whitespace(bytes, end);
+        if bytes.get(idx) != Some(&b',') {
+            break;
+        }
+        idx += 1;
+    }
+    fields
+}
+
+/// Raw text of each complete array element, plus whether the array closed.
+fn json_array_elements(text: &str) -> (Vec<&str>, bool) {
+    let bytes = text.as_bytes();
+    let mut elements = Vec::new();
+    let mut idx = skip_json_whitespace(bytes, 0);
+    if bytes.get(idx) != Some(&b'[') {
+        return (elements, false);
+    }
+    idx += 1;
+    loop {
+        idx = skip_json_whitespace(bytes, idx);
+        match bytes.get(idx) {
+            Some(b']') => return (elements, true),
+            Some(_) => {}
+            None => return (elements, false),
+        }
+        let Some(end) = json_value_end(bytes, idx) else {
+            return (elements, false);
+        };
+        elements.push(&text[idx..end]);
+        idx = skip_json_whitespace(bytes, end);
+        match bytes.get(idx) {
+            Some(b',') => idx += 1,
+            Some(b']') => return (elements, true),
+            _ => return (elements, false),
+        }
+    }
+}
+
+/// End offset of the JSON value starting at `start`, or `None` if it is cut off.
+fn json_value_end(bytes: &[u8], start: usize) -> Option<usize> {
+    match bytes.get(start)? {
+        b'"' => {
+            let mut idx = start + 1;
+            while idx < bytes.len() {
+                match bytes[idx] {
+                    b'\\' => idx += 2,
+                    b'"' => return Some(idx + 1),
+                    _ => idx += 1,
+                }
+            }
+            None
+        }
+        b'{' | b'[' => {
+            let mut depth = 0usize;
+            let mut in_string = false;
+            let mut escaped = false;
+            for (idx, &byte) in bytes.iter().enumerate().skip(start) {
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if byte == b'\\' {
+                        escaped = true;
+                    } else if byte == b'"' {
+                        in_string = false;
+                    }
+                    continue;
+                }
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(idx + 1);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            None
+        }
+        // Scalars are only known to be complete once a delimiter follows.
+        _ => bytes[start..]
+            .iter()
+            .position(|byte| matches!(byte, b',' | b'}' | b']') || byte.is_ascii_whitespace())
+            .map(|offset| start + offset),
+    }
+}
+
+fn skip_json_whitespace(bytes: &[u8], mut idx: usize) -> usize {
+    while bytes.get(idx).is_some_and(u8::is_ascii_whitespace) {
+        idx += 1;
+    }
+    idx
+}
+
+fn is_bincode_path(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "bin")
+}
+
+#[cfg(not(feature = "bincode"))]
+fn bincode_disabled(path: &Path) -> HarnessError {
+    HarnessError::Custom(format!(
+        "`{}` needs the bincode state format; rebuild with `--features bincode`",
+        path.display()
+    ))
+}
+
 /// Applies a candidate's mutation to its scenario and stimulus (if present),
 /// writing the modified definitions to new files within the specified artifact
 /// root directory.
 ///
-/// Returns the paths to the mutated scenario file and the mutated stimulus file (if any).
+/// Returns the paths to the mutated scenario file and the mutated stimulus file
+/// (if any). A missing stimulus file is handled according to `stimulus_missing`.
 pub fn apply_mutation_and_generate_files(
     candidate: &AttackCandidate,
     artifact_root: &Path,
+    layout: ArtifactLayout,
+    stimulus_missing: StimulusMissingPolicy,
 ) -> Result<(PathBuf, Option<PathBuf>), HarnessError> {
     // Determine the directory for this candidate's artifacts
-    let candidate_dir = artifact_root
-        .join(format!("gen{:03}", candidate.generation))
-        .join(&candidate.id);
+    let candidate_dir = layout.candidate_dir(artifact_root, candidate.generation, &candidate.id);
+    let file_stem = sanitize_path_component(&candidate.id);
     fs::create_dir_all(&candidate_dir)?;
 
     // Load and mutate scenario
     let original_scenario_path = PathBuf::from(&candidate.scenario_ref);
-    let mut scenario_config = config::load_from_path(&original_scenario_path).map_err(|e| {
-        HarnessError::Custom(format!(
-            "Failed to load scenario from {}: {}",
-            original_scenario_path.display(),
-            e
-        ))
-    })?;
+    let mut scenario_config = load_scenario(&original_scenario_path)?;
 
     if let Some(mutation) = &candidate.mutation {
         scenario_config.apply_mutation(mutation);
     }
 
-    let mutated_scenario_path = candidate_dir.join(format!("{}.yaml", candidate.id));
+    let mutated_scenario_path = candidate_dir.join(format!("{file_stem}.yaml"));
     scenario_config.save_to_path(&mutated_scenario_path)?;
 
     // Load and mutate stimulus, if present
     let mut mutated_stimulus_path: Option<PathBuf> = None;
-    if let Some(stimulus_ref) = &candidate.stimulus_ref {
-        let original_stimulus_path = PathBuf::from(stimulus_ref);
-        let mut stimulus_schedule =
-            StimulusSchedule::load(&original_stimulus_path).map_err(|e| {
-                HarnessError::Custom(format!(
-                    "Failed to load stimulus from {}: {}",
-                    original_stimulus_path.display(),
-                    e
-                ))
-            })?;
-
-        if let Some(mutation) = &candidate.mutation {
-            stimulus_schedule.apply_mutation(mutation);
+    if let Some(stimulus_ref) = &candidate.stimulus_ref
+        && let Some(mut stimulus_schedule) =
+            load_candidate_stimulus(stimulus_ref, stimulus_missing)?
+    {
+        if let Some(mutation) = &candidate.mutation
+            && !stimulus_schedule
+                .apply_mutation_within(mutation, Some(scenario_config.simulation_steps))
+        {
+            log::warn!(
+                "Mutation {mutation:?} for candidate `{}` names an event missing from `{stimulus_ref}`; running its stimulus unmutated",
+                candidate.id
+            );
         }
 
-        let current_mutated_stimulus_path = candidate_dir.join(format!("{}.jsonl", candidate.id));
+        let current_mutated_stimulus_path = candidate_dir.join(format!("{file_stem}.jsonl"));
         stimulus_schedule.save_to_path(&current_mutated_stimulus_path)?;
         mutated_stimulus_path = Some(current_mutated_stimulus_path);
     }
@@ -688,12 +1738,15 @@ pub fn apply_mutation_and_generate_files(
 ///
 /// `population`: The pool of [`AttackOutcome`]s to select from.
 /// `tournament_size`: The number of candidates to randomly pick for the tournament.
+/// `with_replacement`: Whether one outcome may be picked more than once; see
+/// [`SelectionStrategy::Tournament`] for how this interacts with the size.
 /// `rng`: A mutable reference to a random number generator.
 ///
 /// Returns the selected [`AttackOutcome`] (the fittest in the tournament).
 pub fn tournament_selection<'a, R: Rng>(
     population: &'a [AttackOutcome],
     tournament_size: usize,
+    with_replacement: bool,
     rng: &mut R,
 ) -> Result<&'a AttackOutcome, String> {
     if population.is_empty() {
@@ -703,12 +1756,7 @@ pub fn tournament_selection<'a, R: Rng>(
         return Err("Tournament size cannot be zero".to_string());
     }
 
-    let actual_tournament_size = std::cmp::min(tournament_size, population.len());
-    let selected_candidates: Vec<&AttackOutcome> = population
-        .choose_multiple(rng, actual_tournament_size)
-        .collect();
-
-    selected_candidates
+    tournament_sample(population, tournament_size, with_replacement, rng)
         .into_iter()
         .max_by(|a, b| {
             a.fitness_score
@@ -718,6 +1766,26 @@ pub fn tournament_selection<'a, R: Rng>(
         .ok_or_else(|| "Failed to select candidate from tournament".to_string())
 }
 
+/// Draw the entrants of one tournament. Without replacement the sample is
+/// capped at the population size.
+fn tournament_sample<'a, R: Rng>(
+    population: &'a [AttackOutcome],
+    tournament_size: usize,
+    with_replacement: bool,
+    rng: &mut R,
+) -> Vec<&'a AttackOutcome> {
+    if with_replacement {
+        (0..tournament_size)
+            .filter_map(|_| population.choose(rng))
+            .collect()
+    } else {
+        let actual_tournament_size = std::cmp::min(tournament_size, population.len());
+        population
+            .choose_multiple(rng, actual_tournament_size)
+            .collect()
+    }
+}
+
 /// Selects a parent [`AttackOutcome`] using roulette wheel selection.
 ///
 /// `population`: The pool of [`AttackOutcome`]s to select from.
@@ -802,8 +1870,9 @@ pub fn perform_crossover<R: Rng>(
     rng: &mut R,
     artifact_root: &Path,
     crossover_strategy: &CrossoverStrategy,
+    layout: ArtifactLayout,
+    crossover_scenarios: bool,
 ) -> Result<AttackCandidate, HarnessError> {
-    let child_scenario_ref = parent1.candidate.scenario_ref.clone();
     let child_generation =
         std::cmp::max(parent1.candidate.generation, parent2.candidate.generation) + 1;
 
@@ -818,13 +1887,29 @@ pub fn perform_crossover<R: Rng>(
 
     let child_id = format!("xover-gen{}-{:08x}", child_generation, hash);
 
+    let child_scenario_ref = if crossover_scenarios {
+        let child_scenario = crossover_scenario_configs(
+            &load_scenario(&parent1.candidate.scenario_ref)?,
+            &load_scenario(&parent2.candidate.scenario_ref)?,
+        );
+        let child_scenario_path = layout
+            .candidate_dir(artifact_root, child_generation, &child_id)
+            .join("scenario.yaml");
+        child_scenario.validate()?;
+        fs::create_dir_all(child_scenario_path.parent().unwrap())?;
+        child_scenario.save_to_path(&child_scenario_path)?;
+        child_scenario_path.to_string_lossy().to_string()
+    } else {
+        parent1.candidate.scenario_ref.clone()
+    };
+
     let child_stimulus_ref = match (
         &parent1.candidate.stimulus_ref,
         &parent2.candidate.stimulus_ref,
     ) {
         (Some(s1), Some(s2)) => {
-            let p1_schedule = StimulusSchedule::load(s1)?;
-            let p2_schedule = StimulusSchedule::load(s2)?;
+            let p1_schedule = load_stimulus(s1)?;
+            let p2_schedule = load_stimulus(s2)?;
 
             let child_schedule = match crossover_strategy {
                 CrossoverStrategy::Uniform => {
@@ -832,9 +1917,8 @@ pub fn perform_crossover<R: Rng>(
                 }
             };
 
-            let child_stimulus_path = artifact_root
-                .join(format!("gen{:03}", child_generation))
-                .join(&child_id)
+            let child_stimulus_path = layout
+                .candidate_dir(artifact_root, child_generation, &child_id)
                 .join("stimulus.jsonl");
             fs::create_dir_all(child_stimulus_path.parent().unwrap())?;
             child_schedule.save_to_path(&child_stimulus_path)?;
@@ -867,10 +1951,208 @@ pub fn perform_crossover<R: Rng>(
         stimulus_ref: child_stimulus_ref,
         generation: child_generation,
         parent_id: Some(format!("{},{}", parent1.candidate.id, parent2.candidate.id)),
+        origin_scenario: Some(parent1.candidate.origin().to_string()),
+        mutation,
+        cumulative_factors: parent1.candidate.cumulative_factors.clone(),
+    })
+}
+
+/// Recombine two parent scenarios. Numeric parameters (population, step
+/// counts, threat profile, reproduction rate) are averaged; every other field
+/// follows `parent1`. Averaging keeps `warmup_steps` below `simulation_steps`
+/// when both parents satisfy that, and the step cap is the larger of the two.
+///
+/// Both parents' spikes are merged by step: spikes sharing a step become one
+/// with the mean intensity and the longer duration, and spikes at or past the
+/// child's `simulation_steps` are dropped.
+pub fn crossover_scenario_configs(
+    parent1: &ScenarioConfig,
+    parent2: &ScenarioConfig,
+) -> ScenarioConfig {
+    let mean_f32 = |a: f32, b: f32| (a + b) / 2.0;
+    let mut child = parent1.clone();
+    child.initial_cell_count = (parent1.initial_cell_count + parent2.initial_cell_count) / 2;
+    child.simulation_steps =
+        ((u64::from(parent1.simulation_steps) + u64::from(parent2.simulation_steps)) / 2) as u32;
+    child.warmup_steps =
+        ((u64::from(parent1.warmup_steps) + u64::from(parent2.warmup_steps)) / 2) as u32;
+    child.max_simulation_steps = parent1
+        .max_simulation_steps
+        .max(parent2.max_simulation_steps);
+    child.threat_profile.background_threat = mean_f32(
+        parent1.threat_profile.background_threat,
+        parent2.threat_profile.background_threat,
+    );
+    child.threat_profile.spike_threshold = mean_f32(
+        parent1.threat_profile.spike_threshold,
+        parent2.threat_profile.spike_threshold,
+    );
+    child.cell_reproduction_rate = mean_f32(
+        parent1.cell_reproduction_rate,
+        parent2.cell_reproduction_rate,
+    );
+    let mut spikes_by_step: BTreeMap<u32, Vec<&ThreatSpike>> = BTreeMap::new();
+    for spike in parent1.spikes.iter().chain(&parent2.spikes) {
+        if spike.step < child.simulation_steps {
+            spikes_by_step.entry(spike.step).or_default().push(spike);
+        }
+    }
+    child.spikes = spikes_by_step
+        .into_iter()
+        .map(|(step, spikes)| ThreatSpike {
+            step,
+            intensity: spikes.iter().map(|spike| spike.intensity).sum::<f32>()
+                / spikes.len() as f32,
+            duration: spikes.iter().map(|spike| spike.duration).max().unwrap_or(1),
+        })
+        .collect();
+    child
+}
+
+/// Blends N weighted parents into one child candidate.
+///
+/// Each stimulus step takes its commands from a single parent, sampled by
+/// `weights` among the parents that schedule that step. The mutation is
+/// inherited the same way; scenario and cumulative factors follow the first
+/// parent, as in [`perform_crossover`] without scenario crossover.
+pub fn perform_multi_crossover<R: Rng>(
+    parents: &[&AttackOutcome],
+    weights: &[f32],
+    rng: &mut R,
+    artifact_root: &Path,
+    crossover_strategy: &CrossoverStrategy,
+    layout: ArtifactLayout,
+) -> Result<AttackCandidate, HarnessError> {
+    if parents.is_empty() {
+        return Err(HarnessError::Custom(
+            "multi-parent crossover needs at least one parent".to_string(),
+        ));
+    }
+    if parents.len() != weights.len() {
+        return Err(HarnessError::Custom(format!(
+            "multi-parent crossover got {} parents but {} weights",
+            parents.len(),
+            weights.len()
+        )));
+    }
+    if weights
+        .iter()
+        .any(|weight| !weight.is_finite() || *weight < 0.0)
+        || weights.iter().sum::<f32>() <= 0.0
+    {
+        return Err(HarnessError::Custom(
+            "crossover weights must be non-negative with a positive total".to_string(),
+        ));
+    }
+
+    let first = &parents[0].candidate;
+    let child_generation = parents
+        .iter()
+        .map(|parent| parent.candidate.generation)
+        .max()
+        .unwrap_or_default()
+        + 1;
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let mut hasher = DefaultHasher::new();
+    for parent in parents {
+        parent.candidate.id.hash(&mut hasher);
+    }
+    rng.r#gen::<u64>().hash(&mut hasher);
+    let child_id = format!("xover-gen{}-{:08x}", child_generation, hasher.finish());
+
+    let mut schedules = Vec::new();
+    for (parent, weight) in parents.iter().zip(weights) {
+        if let Some(stimulus_ref) = &parent.candidate.stimulus_ref {
+            schedules.push((stimulus_ref, load_stimulus(stimulus_ref)?, *weight));
+        }
+    }
+    let child_stimulus_ref = match schedules.as_slice() {
+        [] => None,
+        [(stimulus_ref, _, _)] => Some((*stimulus_ref).clone()),
+        _ => {
+            let child_schedule = match crossover_strategy {
+                CrossoverStrategy::Uniform => weighted_crossover_stimulus(&schedules, rng),
+            };
+            let child_stimulus_path = layout
+                .candidate_dir(artifact_root, child_generation, &child_id)
+                .join("stimulus.jsonl");
+            fs::create_dir_all(child_stimulus_path.parent().unwrap())?;
+            child_schedule.save_to_path(&child_stimulus_path)?;
+            Some(child_stimulus_path.to_string_lossy().to_string())
+        }
+    };
+
+    let mutation_donor = weighted_pick(weights, rng).unwrap_or(0);
+    let mutation = parents[mutation_donor]
+        .candidate
+        .mutation
+        .clone()
+        .or_else(|| {
+            let topics = ["activator", "inhibitor", "reproducer"];
+            let topic = topics.choose(rng).unwrap_or(&"activator").to_string();
+            Some(Mutation::IncreaseStimulus {
+                topic,
+                factor: rng.gen_range(1.1..=1.5),
+            })
+        });
+
+    let parent_ids: Vec<&str> = parents
+        .iter()
+        .map(|parent| parent.candidate.id.as_str())
+        .collect();
+    Ok(AttackCandidate {
+        id: child_id,
+        scenario_ref: first.scenario_ref.clone(),
+        stimulus_ref: child_stimulus_ref,
+        generation: child_generation,
+        parent_id: Some(parent_ids.join(",")),
+        origin_scenario: Some(first.origin().to_string()),
         mutation,
+        cumulative_factors: first.cumulative_factors.clone(),
     })
 }
 
+/// Per step, copy the commands of one parent sampled by weight among the
+/// parents that schedule that step.
+fn weighted_crossover_stimulus<R: Rng>(
+    parents: &[(&String, StimulusSchedule, f32)],
+    rng: &mut R,
+) -> StimulusSchedule {
+    let all_steps: BTreeSet<u32> = parents
+        .iter()
+        .flat_map(|(_, schedule, _)| schedule.commands.keys().copied())
+        .collect();
+
+    let mut child_commands = BTreeMap::new();
+    for step in all_steps {
+        let donors: Vec<(&Vec<StimulusCommand>, f32)> = parents
+            .iter()
+            .filter_map(|(_, schedule, weight)| {
+                schedule
+                    .commands
+                    .get(&step)
+                    .map(|commands| (commands, *weight))
+            })
+            .collect();
+        let donor_weights: Vec<f32> = donors.iter().map(|(_, weight)| *weight).collect();
+        // Zero-weight parents still donate steps nobody else schedules.
+        let index =
+            weighted_pick(&donor_weights, rng).unwrap_or_else(|| rng.gen_range(0..donors.len()));
+        child_commands.insert(step, donors[index].0.clone());
+    }
+
+    StimulusSchedule::new(child_commands, None)
+}
+
+/// Index sampled proportionally to `weights`; `None` when no weight is positive.
+fn weighted_pick<R: Rng>(weights: &[f32], rng: &mut R) -> Option<usize> {
+    WeightedIndex::new(weights)
+        .ok()
+        .map(|distribution| distribution.sample(rng))
+}
+
 fn perform_mutation<R: Rng>(
     mutation_strategy: &MutationStrategy,
     stats: &RunStatistics,
@@ -891,13 +2173,106 @@ fn perform_mutation<R: Rng>(
     }
 }
 
-/// Load metrics produced by `scripts/prepare_telemetry_dashboard.py` and derive harness guidance.
-pub fn analyze_metrics_csv<P: AsRef<Path>>(path: P) -> Result<HarnessAnalysis, HarnessError> {
+/// Drive `app` through `scenario` in-process: inject scheduled spikes and the
+/// active `schedule` commands within the scenario's stimulus budget, step the
+/// runtime, and return per-step metrics with warmup steps left out.
+pub fn run_scenario(
+    app: &mut MorphogeneticApp<StreamingPipeline>,
+    scenario: &ScenarioConfig,
+    mut schedule: Option<StimulusSchedule>,
+) -> Result<Vec<StepMetrics>, HarnessError> {
+    let steps = std::cmp::max(1, scenario.simulation_steps);
+    let mut per_step: Vec<StepMetrics> = Vec::with_capacity(steps as usize);
+    let mut active_stimuli: Vec<StimulusCommand> = Vec::new();
+    let mut budget = StimulusBudget::new(scenario.stimulus_budget.clone());
+    let mut fault_rng = scenario.fault_rng();
+    for signal in scenario.initial_bus_signals() {
+        app.inject_signal(signal);
+    }
+
+    for step in 0..steps {
+        let threat = scenario.threat_level_for_step(step);
+        for fault in scenario.faults_at(step) {
+            app.inject_fault(step, fault.kill_fraction, &mut fault_rng);
+        }
+        for signal in scenario.scheduled_signals(step) {
+            // Attribute automatic threat/defense spikes to this step's stimulus totals
+            inject_within_budget(app, &mut budget, signal);
+        }
+
+        if let Some(schedule) = schedule.as_mut() {
+            active_stimuli.extend(schedule.take_for_step(step));
+        }
+
+        // Inject all active stimuli and attribute them to this step
+        for command in &active_stimuli {
+            let signal = scenario.stimulus_signal(command);
+            inject_within_budget(app, &mut budget, signal);
+        }
+
+        // Keep if not expired. Duration is 1-based (1 means only the start step).
+        active_stimuli.retain(|command| step < command.step + command.duration - 1);
+
+        app.step(step, threat);
+        let completed = app.telemetry_mut().take_step_metrics();
+        if completed.is_empty() {
+            return Err(HarnessError::Custom(format!(
+                "Step summary missing for scenario `{}` step {}",
+                scenario.scenario_name, step
+            )));
+        }
+        // Warmup steps still drive the simulation but are left out of fitness.
+        if step >= scenario.warmup_steps {
+            per_step.extend(completed);
+        }
+    }
+    Ok(per_step)
+}
+
+/// Inject `signal` after clamping it to the topic's remaining stimulus budget,
+/// recording a `StimulusBudgetExceeded` event whenever it had to be cut.
+fn inject_within_budget(
+    app: &mut MorphogeneticApp<StreamingPipeline>,
+    budget: &mut StimulusBudget,
+    mut signal: Signal,
+) {
+    let requested = signal.value;
+    let injected = budget.admit(&signal.topic, requested);
+    if injected < requested {
+        let limit = budget.limit(&signal.topic).unwrap_or_default();
+        app.telemetry_mut().record(
+            SystemTime::now(),
+            TelemetryEvent::StimulusBudgetExceeded {
+                topic: signal.topic.clone(),
+                requested,
+                injected,
+                budget: limit,
+            },
+        );
+        // Nothing is left of an exhausted budget; zero-valued signals on
+        // other topics still reach the bus.
+        if injected == 0.0 {
+            return;
+        }
+    }
+    signal.value = injected;
+    app.telemetry_mut()
+        .record_stimulus(&signal.topic, signal.value);
+    app.inject_signal(signal);
+}
+
+/// Load metrics produced by `scripts/prepare_telemetry_dashboard.py` and
+/// score them under `config`. No scenario is known, so
+/// [`FitnessNormalization::Scenario`] falls back to the reference threat.
+pub fn analyze_metrics_csv<P: AsRef<Path>>(
+    path: P,
+    config: &EvolutionConfig,
+) -> Result<HarnessAnalysis, HarnessError> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
     let steps = load_step_metrics_from_csv(reader)?;
     let stats = build_statistics_from_steps(&steps)?;
-    Ok(analyze_run_statistics(stats))
+    Ok(analyze_run_statistics(stats, config, None))
 }
 
 /// Persist per-step metrics as a CSV compatible with the analytics tooling.
@@ -912,64 +2287,86 @@ pub fn write_step_metrics_csv<P: AsRef<Path>>(
         fs::create_dir_all(parent)?;
     }
 
-    let mut writer = WriterBuilder::new().has_headers(true).from_path(path)?;
-
-    writer.write_record([
-        "step",
-        "threat_score",
-        "cell_count",
-        "replications",
-        "deaths",
-        "signals_total",
-        "lineage_shifts_total",
-        "stimulus_total",
-        "top_signal_topic",
-        "top_signal_count",
-        "top_lineage",
-        "top_lineage_count",
-        "signals_by_topic",
-        "lineage_shifts_by_lineage",
-        "stimulus_by_topic",
-        "population_stats",
-        "topology_stats",
-    ])?;
-
-    for step in steps {
-        let signals_json = serde_json::to_string(&step.signals_by_topic)?;
-        let lineage_json = serde_json::to_string(&step.lineage_shifts_by_lineage)?;
-        let stimulus_json = serde_json::to_string(&step.stimulus_by_topic)?;
-        let population_stats_json = serde_json::to_string(&step.population_stats)?;
-        let topology_stats_json = serde_json::to_string(&step.topology_stats)?;
-        let (top_signal_topic, top_signal_count) = top_u32(&step.signals_by_topic);
-        let (top_lineage, top_lineage_count) = top_u32(&step.lineage_shifts_by_lineage);
+    write_atomic(path, |file| -> Result<(), HarnessError> {
+        let mut writer = WriterBuilder::new().has_headers(true).from_writer(file);
 
         writer.write_record([
-            step.step.to_string(),
-            format!("{:.6}", step.threat_score),
-            step.cell_count.to_string(),
-            step.replications.to_string(),
-            step.deaths.to_string(),
-            step.signals_total.to_string(),
-            step.lineage_shifts_total.to_string(),
-            format!("{:.6}", step.stimulus_total),
-            top_signal_topic,
-            top_signal_count.to_string(),
-            top_lineage,
-            top_lineage_count.to_string(),
-            signals_json,
-            lineage_json,
-            stimulus_json,
-            population_stats_json,
-            topology_stats_json,
+            "step",
+            "threat_score",
+            "cell_count",
+            "replications",
+            "deaths",
+            "signals_total",
+            "lineage_shifts_total",
+            "stimulus_total",
+            "top_signal_topic",
+            "top_signal_count",
+            "top_lineage",
+            "top_lineage_count",
+            "signals_by_topic",
+            "lineage_shifts_by_lineage",
+            "stimulus_by_topic",
+            "population_stats",
+            "topology_stats",
         ])?;
-    }
 
-    writer.flush()?;
-    Ok(())
+        for step in steps {
+            let signals_json = serde_json::to_string(&step.signals_by_topic)?;
+            let lineage_json = serde_json::to_string(&step.lineage_shifts_by_lineage)?;
+            let stimulus_json = serde_json::to_string(&step.stimulus_by_topic)?;
+            let population_stats_json = serde_json::to_string(&step.population_stats)?;
+            let topology_stats_json = serde_json::to_string(&step.topology_stats)?;
+            let (top_signal_topic, top_signal_count) = top_u32(&step.signals_by_topic);
+            let (top_lineage, top_lineage_count) = top_u32(&step.lineage_shifts_by_lineage);
+
+            writer.write_record([
+                step.step.to_string(),
+                format!("{:.6}", step.threat_score),
+                step.cell_count.to_string(),
+                step.replications.to_string(),
+                step.deaths.to_string(),
+                step.signals_total.to_string(),
+                step.lineage_shifts_total.to_string(),
+                format!("{:.6}", step.stimulus_total),
+                top_signal_topic,
+                top_signal_count.to_string(),
+                top_lineage,
+                top_lineage_count.to_string(),
+                signals_json,
+                lineage_json,
+                stimulus_json,
+                population_stats_json,
+                topology_stats_json,
+            ])?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    })
 }
 
+/// Columns a metrics CSV must carry; everything else defaults when absent.
+const REQUIRED_METRICS_COLUMNS: [&str; 7] = [
+    "step",
+    "threat_score",
+    "cell_count",
+    "replications",
+    "signals_total",
+    "lineage_shifts_total",
+    "stimulus_total",
+];
+
 fn load_step_metrics_from_csv<R: Read>(reader: R) -> Result<Vec<StepMetrics>, HarnessError> {
     let mut csv_reader = Reader::from_reader(reader);
+    let headers = csv_reader.headers()?.clone();
+    if let Some(missing) = REQUIRED_METRICS_COLUMNS
+        .iter()
+        .find(|column| !headers.iter().any(|header| header.trim() == **column))
+    {
+        return Err(HarnessError::Custom(format!(
+            "Metrics CSV is missing required column `{missing}`"
+        )));
+    }
     let mut steps = Vec::new();
 
     for record in csv_reader.deserialize::<RawMetricsRow>() {
@@ -1008,6 +2405,8 @@ fn load_step_metrics_from_csv<R: Read>(reader: R) -> Result<Vec<StepMetrics>, Ha
     Ok(steps)
 }
 
+/// Columns are matched by header name, so order is irrelevant; derived
+/// columns such as `top_signal_topic` are ignored and re-computed on demand.
 #[derive(Debug, Deserialize)]
 struct RawMetricsRow {
     step: u32,
@@ -1019,16 +2418,11 @@ struct RawMetricsRow {
     signals_total: u32,
     lineage_shifts_total: u32,
     stimulus_total: f32,
-    #[serde(rename = "top_signal_topic")]
-    _top_signal_topic: String,
-    #[serde(rename = "top_signal_count")]
-    _top_signal_count: u32,
-    #[serde(rename = "top_lineage")]
-    _top_lineage: String,
-    #[serde(rename = "top_lineage_count")]
-    _top_lineage_count: u32,
+    #[serde(default)]
     signals_by_topic: String,
+    #[serde(default)]
     lineage_shifts_by_lineage: String,
+    #[serde(default)]
     stimulus_by_topic: String,
     #[serde(default)]
     population_stats: String,
@@ -1049,6 +2443,7 @@ struct StatsAccumulator {
     step_count: usize,
     threat_sum: f32,
     max_threat: f32,
+    threats: Vec<f32>,
     cell_sum: f32,
     min_cell: Option<u32>,
     max_cell: u32,
@@ -1057,9 +2452,9 @@ struct StatsAccumulator {
     total_signals: u32,
     total_lineage_shifts: u32,
     total_stimulus: f32,
-    signals_by_topic: HashMap<String, u32>,
-    lineage_by_type: HashMap<String, u32>,
-    stimuli_by_topic: HashMap<String, f32>,
+    signals_by_topic: BTreeMap<String, u32>,
+    lineage_by_type: BTreeMap<String, u32>,
+    stimuli_by_topic: BTreeMap<String, f32>,
     total_degree_sum: f32,
     max_isolation: u32,
 }
@@ -1068,6 +2463,7 @@ impl StatsAccumulator {
     fn add_step(&mut self, step: &StepMetrics) {
         self.step_count += 1;
         self.threat_sum += step.threat_score;
+        self.threats.push(step.threat_score);
         self.max_threat = if self.step_count == 1 {
             step.threat_score
         } else {
@@ -1095,10 +2491,11 @@ impl StatsAccumulator {
         }
     }
 
-    fn finish(self) -> Result<RunStatistics, HarnessError> {
+    fn finish(mut self) -> Result<RunStatistics, HarnessError> {
         if self.step_count == 0 {
             return Err(HarnessError::EmptyDataset);
         }
+        self.threats.sort_by(f32::total_cmp);
 
         let min_cell = self.min_cell.unwrap_or(0) as usize;
         let max_cell = self.max_cell as usize;
@@ -1107,6 +2504,9 @@ impl StatsAccumulator {
             step_count: self.step_count,
             avg_threat: self.threat_sum / self.step_count as f32,
             max_threat: self.max_threat,
+            p50_threat: nearest_rank(&self.threats, 50.0),
+            p90_threat: nearest_rank(&self.threats, 90.0),
+            p99_threat: nearest_rank(&self.threats, 99.0),
             avg_cell_count: self.cell_sum / self.step_count as f32,
             min_cell_count: min_cell,
             max_cell_count: max_cell,
@@ -1124,44 +2524,116 @@ impl StatsAccumulator {
     }
 }
 
-fn analyze_run_statistics(stats: RunStatistics) -> HarnessAnalysis {
-    let (fitness_score, breach_observed) = compute_fitness(&stats);
-    let recommended_mutation = recommend_mutation(&stats, fitness_score, breach_observed);
+/// Nearest-rank percentile of an ascending, non-empty slice.
+fn nearest_rank(sorted: &[f32], percentile: f32) -> f32 {
+    let rank = (percentile / 100.0 * sorted.len() as f32).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl MetricSummary {
+    fn from_samples(samples: &[f32]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let count = samples.len() as f32;
+        let mean = samples.iter().sum::<f32>() / count;
+        let std_dev = if samples.len() > 1 {
+            let variance = samples
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f32>()
+                / (count - 1.0);
+            variance.sqrt()
+        } else {
+            0.0
+        };
+        Self {
+            mean,
+            std_dev,
+            ci95: 1.96 * std_dev / count.sqrt(),
+        }
+    }
+}
+
+impl RunStatistics {
+    /// Aggregate repeated runs of a candidate so lucky seeds can be told apart
+    /// from consistently strong attacks.
+    pub fn from_repeated(runs: &[RunStatistics]) -> AggregatedStatistics {
+        let summarise = |extract: fn(&RunStatistics) -> f32| {
+            let samples: Vec<f32> = runs.iter().map(extract).collect();
+            MetricSummary::from_samples(&samples)
+        };
+
+        AggregatedStatistics {
+            run_count: runs.len(),
+            avg_threat: summarise(|run| run.avg_threat),
+            max_threat: summarise(|run| run.max_threat),
+            p50_threat: summarise(|run| run.p50_threat),
+            p90_threat: summarise(|run| run.p90_threat),
+            p99_threat: summarise(|run| run.p99_threat),
+            avg_cell_count: summarise(|run| run.avg_cell_count),
+            min_cell_count: summarise(|run| run.min_cell_count as f32),
+            max_cell_count: summarise(|run| run.max_cell_count as f32),
+            total_replications: summarise(|run| run.total_replications as f32),
+            total_deaths: summarise(|run| run.total_deaths as f32),
+            total_signals: summarise(|run| run.total_signals as f32),
+            total_lineage_shifts: summarise(|run| run.total_lineage_shifts as f32),
+            total_stimulus: summarise(|run| run.total_stimulus),
+            avg_topology_degree: summarise(|run| run.avg_topology_degree),
+            max_isolation_count: summarise(|run| run.max_isolation_count as f32),
+        }
+    }
+}
+
+/// Score `stats` under `config`'s normalization and breach criteria.
+/// `scenario_envelope` is the scenario's peak scheduled threat, when known.
+fn analyze_run_statistics(
+    stats: RunStatistics,
+    config: &EvolutionConfig,
+    scenario_envelope: Option<f32>,
+) -> HarnessAnalysis {
+    let fitness_breakdown =
+        normalized_fitness_breakdown(&stats, config.fitness_normalization, scenario_envelope);
+    let (fitness_score, breach_observed) = compute_fitness(&stats, config, scenario_envelope);
+    let (recommended_mutation, recommendation_reason) =
+        explain_recommendation(&stats, fitness_score, breach_observed);
     HarnessAnalysis {
         statistics: stats,
         fitness_score,
         breach_observed,
         recommended_mutation,
+        fitness_breakdown,
+        recommendation_reason,
     }
 }
 
-fn merge_u32_map(target: &mut HashMap<String, u32>, source: &HashMap<String, u32>) {
+fn merge_u32_map(target: &mut BTreeMap<String, u32>, source: &BTreeMap<String, u32>) {
     for (key, value) in source {
         *target.entry(key.clone()).or_insert(0) += *value;
     }
 }
 
-fn merge_f32_map(target: &mut HashMap<String, f32>, source: &HashMap<String, f32>) {
+fn merge_f32_map(target: &mut BTreeMap<String, f32>, source: &BTreeMap<String, f32>) {
     for (key, value) in source {
         *target.entry(key.clone()).or_insert(0.0) += *value;
     }
 }
 
-fn top_u32(map: &HashMap<String, u32>) -> (String, u32) {
+fn top_u32(map: &BTreeMap<String, u32>) -> (String, u32) {
     map.iter()
         .max_by_key(|entry| entry.1)
         .map(|(key, value)| (key.clone(), *value))
         .unwrap_or_else(|| (String::new(), 0))
 }
 
-fn parse_u32_map(raw_json: &str) -> Result<HashMap<String, u32>, HarnessError> {
+fn parse_u32_map(raw_json: &str) -> Result<BTreeMap<String, u32>, HarnessError> {
     if raw_json.trim().is_empty() {
-        return Ok(HashMap::new());
+        return Ok(BTreeMap::new());
     }
     let value: Value = serde_json::from_str(raw_json)?;
     match value {
         Value::Object(map) => {
-            let mut result = HashMap::new();
+            let mut result = BTreeMap::new();
             for (key, val) in map {
                 let count = match val {
                     Value::Number(number) => number.as_u64().unwrap_or(0) as u32,
@@ -1174,18 +2646,18 @@ fn parse_u32_map(raw_json: &str) -> Result<HashMap<String, u32>, HarnessError> {
             }
             Ok(result)
         }
-        _ => Ok(HashMap::new()),
+        _ => Ok(BTreeMap::new()),
     }
 }
 
-fn parse_f32_map(raw_json: &str) -> Result<HashMap<String, f32>, HarnessError> {
+fn parse_f32_map(raw_json: &str) -> Result<BTreeMap<String, f32>, HarnessError> {
     if raw_json.trim().is_empty() {
-        return Ok(HashMap::new());
+        return Ok(BTreeMap::new());
     }
     let value: Value = serde_json::from_str(raw_json)?;
     match value {
         Value::Object(map) => {
-            let mut result = HashMap::new();
+            let mut result = BTreeMap::new();
             for (key, val) in map {
                 let magnitude = match val {
                     Value::Number(number) => number.as_f64().unwrap_or(0.0) as f32,
@@ -1198,12 +2670,30 @@ fn parse_f32_map(raw_json: &str) -> Result<HashMap<String, f32>, HarnessError> {
             }
             Ok(result)
         }
-        _ => Ok(HashMap::new()),
+        _ => Ok(BTreeMap::new()),
     }
 }
 
-fn compute_fitness(stats: &RunStatistics) -> (f32, bool) {
-    let threat_component = (stats.avg_threat / 1.5).clamp(0.0, 1.0);
+/// Decompose the fitness score into its weighted components.
+pub fn fitness_breakdown(stats: &RunStatistics) -> FitnessBreakdown {
+    normalized_fitness_breakdown(stats, FitnessNormalization::Raw, None)
+}
+
+/// [`fitness_breakdown`] under an explicit [`FitnessNormalization`].
+/// `scenario_envelope` is the peak threat the scenario schedules; `Raw`
+/// ignores it.
+pub fn normalized_fitness_breakdown(
+    stats: &RunStatistics,
+    normalization: FitnessNormalization,
+    scenario_envelope: Option<f32>,
+) -> FitnessBreakdown {
+    let threat_envelope = match normalization {
+        FitnessNormalization::Raw => REFERENCE_THREAT,
+        FitnessNormalization::Scenario => scenario_envelope
+            .unwrap_or(REFERENCE_THREAT)
+            .max(f32::EPSILON),
+    };
+    let threat_component = (stats.avg_threat / threat_envelope).clamp(0.0, 1.0);
     let reproduction_rate =
         stats.total_replications as f32 / (stats.step_count as f32 + f32::EPSILON);
     let suppression_component = (1.0 - reproduction_rate.min(1.0)).max(0.0);
@@ -1214,24 +2704,42 @@ fn compute_fitness(stats: &RunStatistics) -> (f32, bool) {
     } else {
         0.0
     };
-    let stimulus_component =
-        (stats.total_stimulus / ((stats.step_count as f32).max(1.0) * 1.5)).clamp(0.0, 1.0);
+    let stimulus_component = (stats.total_stimulus
+        / ((stats.step_count as f32).max(1.0) * threat_envelope))
+        .clamp(0.0, 1.0);
     let lineage_component = compute_lineage_component(stats);
     let topology_component = (stats.max_isolation_count as f32
         / (stats.max_cell_count as f32 + f32::EPSILON))
         .clamp(0.0, 1.0);
 
-    let fitness = 0.3 * threat_component
-        + 0.2 * suppression_component
-        + 0.1 * cell_loss_component
-        + 0.2 * lineage_component
-        + 0.1 * stimulus_component
-        + 0.1 * topology_component;
-    let breach_observed = fitness > 0.65
-        || stats.max_threat > 1.1
-        || cell_loss_component > 0.45
-        || lineage_component > 0.8;
-    (fitness, breach_observed)
+    let mut breakdown = FitnessBreakdown {
+        threat: threat_component,
+        suppression: suppression_component,
+        cell_loss: cell_loss_component,
+        lineage: lineage_component,
+        stimulus: stimulus_component,
+        topology: topology_component,
+        total: 0.0,
+    };
+    breakdown.total = breakdown
+        .components()
+        .iter()
+        .map(|(_, weight, value)| weight * value)
+        .sum();
+    breakdown
+}
+
+fn compute_fitness(
+    stats: &RunStatistics,
+    config: &EvolutionConfig,
+    scenario_envelope: Option<f32>,
+) -> (f32, bool) {
+    let breakdown =
+        normalized_fitness_breakdown(stats, config.fitness_normalization, scenario_envelope);
+    (
+        breakdown.total,
+        config.breach_criteria.is_breach(stats, &breakdown),
+    )
 }
 
 fn compute_lineage_component(stats: &RunStatistics) -> f32 {
@@ -1257,6 +2765,15 @@ fn recommend_mutation(
     fitness_score: f32,
     breach_observed: bool,
 ) -> Option<Mutation> {
+    explain_recommendation(stats, fitness_score, breach_observed).0
+}
+
+/// Recommend a mutation and name the decision branch that produced it.
+pub fn explain_recommendation(
+    stats: &RunStatistics,
+    fitness_score: f32,
+    breach_observed: bool,
+) -> (Option<Mutation>, &'static str) {
     let activator = stats
         .stimuli_by_topic
         .get("activator")
@@ -1282,55 +2799,84 @@ fn recommend_mutation(
     };
 
     if fitness_score < 0.35 {
-        if activator <= inhibitor {
-            Some(Mutation::IncreaseStimulus {
-                topic: "activator".to_string(),
-                factor: 1.2,
-            })
+        let topic = if activator <= inhibitor {
+            "activator"
         } else {
+            "inhibitor"
+        };
+        (
             Some(Mutation::IncreaseStimulus {
-                topic: "inhibitor".to_string(),
+                topic: topic.to_string(),
                 factor: 1.2,
-            })
-        }
+            }),
+            "low fitness: boost the weaker of activator/inhibitor stimulus",
+        )
     } else if breach_observed {
         if stats.total_signals < stats.step_count as u32 {
-            Some(Mutation::AddSpike {
-                step: stats.step_count as u32 / 2,
-                intensity: 0.5,
-            })
+            (
+                Some(Mutation::AddSpike {
+                    step: stats.step_count as u32 / 2,
+                    intensity: 0.5,
+                }),
+                "breach with sparse signalling: add a mid-run spike",
+            )
         } else {
-            Some(Mutation::DecreaseStimulus {
-                topic: "inhibitor".to_string(),
-                factor: 0.8,
-            })
+            (
+                Some(Mutation::DecreaseStimulus {
+                    topic: "inhibitor".to_string(),
+                    factor: 0.8,
+                }),
+                "breach with active signalling: weaken inhibitor stimulus",
+            )
         }
     } else if lineage_pressure < 0.2 {
-        Some(Mutation::IncreaseStimulus {
-            topic: "activator".to_string(),
-            factor: 1.5,
-        })
-    } else if dominant_ratio < 0.5 && stats.total_lineage_shifts > 3 {
-        if let Some((lineage, _)) = dominant_lineage_entry {
+        (
             Some(Mutation::IncreaseStimulus {
-                topic: lineage.clone(),
+                topic: "activator".to_string(),
                 factor: 1.5,
-            })
-        } else {
-            None
+            }),
+            "low lineage pressure: amplify activator stimulus",
+        )
+    } else if dominant_ratio < 0.5 && stats.total_lineage_shifts > 3 {
+        let reason = "diffuse lineage churn: focus stimulus on the dominant lineage";
+        match dominant_lineage_entry {
+            Some((lineage, _)) => (
+                Some(Mutation::IncreaseStimulus {
+                    topic: lineage.clone(),
+                    factor: 1.5,
+                }),
+                reason,
+            ),
+            None => (None, reason),
         }
     } else if reproduction_rate > 0.6 {
-        Some(Mutation::IncreaseStimulus {
-            topic: "inhibitor".to_string(),
-            factor: 1.5,
-        })
+        (
+            Some(Mutation::IncreaseStimulus {
+                topic: "inhibitor".to_string(),
+                factor: 1.5,
+            }),
+            "runaway replication: amplify inhibitor stimulus",
+        )
     } else if inhibitor > activator && activator > 0.0 {
-        Some(Mutation::IncreaseStimulus {
-            topic: "activator".to_string(),
-            factor: 1.2,
-        })
+        (
+            Some(Mutation::IncreaseStimulus {
+                topic: "activator".to_string(),
+                factor: 1.2,
+            }),
+            "inhibitor outweighs activator: rebalance towards activator",
+        )
     } else {
-        None
+        (None, "no rule matched: retain candidate unchanged")
+    }
+}
+
+fn outcome_for_analysis(candidate: AttackCandidate, analysis: &HarnessAnalysis) -> AttackOutcome {
+    AttackOutcome {
+        candidate,
+        fitness_score: analysis.fitness_score,
+        breach_observed: analysis.breach_observed,
+        notes: Some(outcome_note_for_analysis(analysis)),
+        statistics: analysis.statistics.clone(),
     }
 }
 
@@ -1353,19 +2899,48 @@ mod tests {
     use serde::Serialize;
     use serde_json::json;
     use std::io::Cursor;
+    use std::sync::Arc;
     use tempfile::NamedTempFile;
     use tempfile::tempdir;
 
+    /// Single-candidate batches without elitism; tests override what they exercise.
+    fn test_config() -> EvolutionConfig {
+        EvolutionConfig {
+            batch_size: 1,
+            retain_elite: false,
+            ..EvolutionConfig::default_smoke_test()
+        }
+    }
+
+    /// A quiet step with four live cells and no activity besides `threat_score`.
+    fn step_metrics(step: u32, threat_score: f32) -> StepMetrics {
+        StepMetrics {
+            step,
+            threat_score,
+            cell_count: 4,
+            ..Default::default()
+        }
+    }
+
+    /// Statistics of a one-step, one-cell run.
+    fn run_stats() -> RunStatistics {
+        RunStatistics {
+            step_count: 1,
+            avg_threat: 0.1,
+            max_threat: 0.2,
+            avg_cell_count: 1.0,
+            min_cell_count: 1,
+            max_cell_count: 1,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn retain_elite_requeues_elite_candidates() {
         let mut harness = AdversarialHarness::new(EvolutionConfig {
-            batch_size: 1,
             max_generations: 5,
             retain_elite: true,
-            crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
-            crossover_strategy: CrossoverStrategy::Uniform,
-            mutation_strategy: MutationStrategy::Random,
+            ..test_config()
         });
 
         harness.enqueue(AttackCandidate {
@@ -1374,6 +2949,8 @@ mod tests {
             stimulus_ref: None,
             generation: 0,
             parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: None,
         });
 
@@ -1381,20 +2958,12 @@ mod tests {
         let evaluations = harness
             .run_generations(1, artifact_dir.path(), |_candidate| {
                 // Simulate an elite candidate (high fitness, no mutation recommended)
+                // Step 1 with one lineage shift puts lineage_pressure >= 0.2, and
+                // full threat pushes fitness_score past 0.4.
                 let steps = vec![StepMetrics {
-                    step: 1,           // Changed step to 1 to influence lineage_pressure calculation
-                    threat_score: 1.0, // Increased threat to make fitness_score > 0.4
                     cell_count: 10,
-                    replications: 0,
-                    deaths: 0,
-                    signals_total: 0,
-                    lineage_shifts_total: 1, // Set lineage_shifts_total to 1 to make lineage_pressure >= 0.2
-                    stimulus_total: 0.0,
-                    signals_by_topic: HashMap::new(),
-                    lineage_shifts_by_lineage: HashMap::new(),
-                    stimulus_by_topic: HashMap::new(), // Make stimulus_by_topic empty
-                    population_stats: None,
-                    topology_stats: None,
+                    lineage_shifts_total: 1,
+                    ..step_metrics(1, 1.0)
                 }];
                 Ok(ExecutionReport {
                     steps,
@@ -1416,11 +2985,7 @@ mod tests {
         let mut harness = AdversarialHarness::new(EvolutionConfig {
             batch_size: 2,
             max_generations: 5,
-            retain_elite: false,
-            crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
-            crossover_strategy: CrossoverStrategy::Uniform,
-            mutation_strategy: MutationStrategy::Random,
+            ..test_config()
         });
 
         harness.enqueue(AttackCandidate {
@@ -1429,6 +2994,8 @@ mod tests {
             stimulus_ref: None,
             generation: 0,
             parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: None,
         });
 
@@ -1438,6 +3005,8 @@ mod tests {
             stimulus_ref: None,
             generation: 1,
             parent_id: Some("seed-1".into()),
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: Some(Mutation::IncreaseStimulus {
                 topic: "inhibitor".to_string(),
                 factor: 1.5,
@@ -1450,6 +3019,8 @@ mod tests {
             stimulus_ref: None,
             generation: 1,
             parent_id: Some("seed-1".into()),
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: Some(Mutation::AddSpike {
                 step: 10,
                 intensity: 0.8,
@@ -1465,6 +3036,72 @@ mod tests {
         assert_eq!(harness.backlog_len(), 0);
     }
 
+    #[test]
+    fn backlog_is_fifo_and_peek_next_does_not_consume() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+        assert!(harness.peek_next().is_none());
+        for id in ["first", "second", "third"] {
+            harness.enqueue(AttackCandidate {
+                id: id.into(),
+                scenario_ref: "docs/examples/baseline-growth.yaml".into(),
+                stimulus_ref: None,
+                generation: 0,
+                parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
+                mutation: None,
+            });
+        }
+
+        let queued: Vec<&str> = harness.backlog().iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(queued, vec!["first", "second", "third"]);
+        assert_eq!(harness.peek_next().map(|c| c.id.as_str()), Some("first"));
+        assert_eq!(harness.peek_next().map(|c| c.id.as_str()), Some("first"));
+        assert_eq!(harness.backlog_len(), 3);
+
+        // The default batch drains all three, in the order peeked above.
+        let batch: Vec<String> = harness.next_batch().into_iter().map(|c| c.id).collect();
+        assert_eq!(batch, vec!["first", "second", "third"]);
+        assert!(harness.peek_next().is_none());
+    }
+
+    #[test]
+    fn artifact_layouts_keep_awkward_ids_distinct_and_contained() {
+        let root = Path::new("artifacts");
+        let ids = ["a/b", "a_b", "a%2Fb", "..", ".", "", "../escape", "seed a"];
+        for layout in [ArtifactLayout::Nested, ArtifactLayout::Flat] {
+            let dirs: Vec<PathBuf> = ids
+                .iter()
+                .map(|id| layout.candidate_dir(root, 7, id))
+                .collect();
+            for dir in &dirs {
+                let relative = dir.strip_prefix(root).expect("stays under the root");
+                let expected_depth = match layout {
+                    ArtifactLayout::Nested => 2,
+                    ArtifactLayout::Flat => 1,
+                };
+                assert_eq!(relative.components().count(), expected_depth, "{dir:?}");
+                assert!(
+                    relative
+                        .components()
+                        .all(|part| matches!(part, std::path::Component::Normal(_))),
+                    "{dir:?}"
+                );
+            }
+            let unique: HashSet<&PathBuf> = dirs.iter().collect();
+            assert_eq!(unique.len(), ids.len(), "{layout:?} collided: {dirs:?}");
+        }
+
+        assert_eq!(
+            ArtifactLayout::Nested.candidate_dir(root, 3, "seed-a"),
+            root.join("gen003").join("seed-a")
+        );
+        assert_eq!(
+            ArtifactLayout::Flat.candidate_dir(root, 3, "seed-a"),
+            root.join("gen003-seed-a")
+        );
+    }
+
     #[test]
     fn analyze_metrics_from_csv_stream() {
         let rows = vec![
@@ -1532,13 +3169,8 @@ mod tests {
         let csv = serialize_rows(rows);
 
         let mut harness = AdversarialHarness::new(EvolutionConfig {
-            batch_size: 1,
             max_generations: 3,
-            retain_elite: false,
-            crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
-            crossover_strategy: CrossoverStrategy::Uniform,
-            mutation_strategy: MutationStrategy::Random,
+            ..test_config()
         });
 
         let temp_csv = NamedTempFile::new().expect("temp file");
@@ -1550,6 +3182,8 @@ mod tests {
             stimulus_ref: Some("docs/examples/ci-stimulus.jsonl".into()),
             generation: 0,
             parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: None,
         };
 
@@ -1576,13 +3210,9 @@ mod tests {
     #[test]
     fn harness_state_roundtrip_persists_archive() {
         let mut harness = AdversarialHarness::new(EvolutionConfig {
-            batch_size: 1,
             max_generations: 4,
             retain_elite: true,
-            crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
-            crossover_strategy: CrossoverStrategy::Uniform,
-            mutation_strategy: MutationStrategy::Random,
+            ..test_config()
         });
 
         let candidate = AttackCandidate {
@@ -1591,6 +3221,8 @@ mod tests {
             stimulus_ref: None,
             generation: 0,
             parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: Some(Mutation::AddSpike {
                 step: 0,
                 intensity: 0.0,
@@ -1598,19 +3230,12 @@ mod tests {
         };
 
         let steps = vec![StepMetrics {
-            step: 0,
-            threat_score: 0.5,
-            cell_count: 4,
             replications: 1,
-            deaths: 0,
             signals_total: 1,
-            lineage_shifts_total: 0,
             stimulus_total: 0.4,
-            signals_by_topic: HashMap::from([("activator".into(), 1)]),
-            lineage_shifts_by_lineage: HashMap::new(),
-            stimulus_by_topic: HashMap::from([("activator".into(), 0.4)]),
-            population_stats: None,
-            topology_stats: None,
+            signals_by_topic: BTreeMap::from([("activator".into(), 1)]),
+            stimulus_by_topic: BTreeMap::from([("activator".into(), 0.4)]),
+            ..step_metrics(0, 0.5)
         }];
 
         harness
@@ -1630,13 +3255,8 @@ mod tests {
     #[test]
     fn run_generations_executes_batches() {
         let mut harness = AdversarialHarness::new(EvolutionConfig {
-            batch_size: 1,
             max_generations: 5,
-            retain_elite: false,
-            crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
-            crossover_strategy: CrossoverStrategy::Uniform,
-            mutation_strategy: MutationStrategy::Random,
+            ..test_config()
         });
 
         harness.enqueue(AttackCandidate {
@@ -1645,6 +3265,8 @@ mod tests {
             stimulus_ref: None,
             generation: 0,
             parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: None,
         });
         harness.enqueue(AttackCandidate {
@@ -1653,6 +3275,8 @@ mod tests {
             stimulus_ref: None,
             generation: 0,
             parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
             mutation: None,
         });
 
@@ -1661,19 +3285,8 @@ mod tests {
             .run_generations(2, artifact_dir.path(), |candidate: &AttackCandidate| {
                 let base_threat = 0.3 + candidate.generation as f32 * 0.1;
                 let steps = vec![StepMetrics {
-                    step: candidate.generation,
-                    threat_score: base_threat,
-                    cell_count: 4,
                     replications: 1,
-                    deaths: 0,
-                    signals_total: 0,
-                    lineage_shifts_total: 0,
-                    stimulus_total: 0.0,
-                    signals_by_topic: HashMap::new(),
-                    lineage_shifts_by_lineage: HashMap::new(),
-                    stimulus_by_topic: HashMap::new(),
-                    population_stats: None,
-                    topology_stats: None,
+                    ..step_metrics(candidate.generation, base_threat)
                 }];
                 Ok(ExecutionReport {
                     steps,
@@ -1689,36 +3302,346 @@ mod tests {
         assert_eq!(harness.backlog_len(), 4); // New candidates for gen 3
     }
 
+    #[test]
+    fn elite_size_requeues_top_candidates_verbatim() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig {
+            crossover_rate: 0.0,
+            elite_size: 2,
+            ..test_config()
+        });
+        let threats = [("weak", 0.1), ("strong", 0.9), ("middling", 0.5)];
+        for (id, threat) in threats {
+            harness.enqueue(AttackCandidate {
+                id: id.to_string(),
+                scenario_ref: "scenario.yaml".to_string(),
+                stimulus_ref: None,
+                generation: 0,
+                parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
+                mutation: Some(Mutation::AddSpike {
+                    step: 1,
+                    intensity: threat,
+                }),
+            });
+        }
+
+        let artifact_dir = tempdir().expect("tempdir");
+        harness
+            .run_generations(1, artifact_dir.path(), |candidate: &AttackCandidate| {
+                let (_, threat) = threats
+                    .iter()
+                    .find(|(id, _)| *id == candidate.id)
+                    .expect("seeded candidate");
+                let steps = (0..3).map(|step| step_metrics(step, *threat)).collect();
+                Ok(ExecutionReport {
+                    steps,
+                    telemetry_path: None,
+                    metrics_path: None,
+                    stimulus_path: None,
+                })
+            })
+            .expect("generation runs");
+
+        let mut ranked: Vec<&AttackOutcome> = harness.archive().iter().collect();
+        ranked.sort_by(|a, b| b.fitness_score.total_cmp(&a.fitness_score));
+        assert!(ranked[1].fitness_score > ranked[2].fitness_score);
+
+        let queued: Vec<serde_json::Value> = harness
+            .backlog()
+            .iter()
+            .map(|candidate| serde_json::to_value(candidate).unwrap())
+            .collect();
+        for elite in &ranked[..2] {
+            let expected = serde_json::to_value(&elite.candidate).unwrap();
+            assert!(
+                queued.contains(&expected),
+                "elite `{}` missing from backlog",
+                elite.candidate.id
+            );
+        }
+        assert!(
+            !harness
+                .backlog()
+                .iter()
+                .any(|candidate| candidate.id == ranked[2].candidate.id)
+        );
+    }
+
+    #[test]
+    fn elite_reruns_write_to_their_own_artifact_directory() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig {
+            crossover_rate: 0.0,
+            elite_size: 1,
+            ..test_config()
+        });
+        harness.enqueue(AttackCandidate {
+            id: "champion".to_string(),
+            scenario_ref: "scenario.yaml".to_string(),
+            stimulus_ref: None,
+            generation: 0,
+            parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+            mutation: None,
+        });
+
+        let artifact_dir = tempdir().expect("tempdir");
+        let layout = harness.config().artifact_layout;
+        let mut run_dirs = Vec::new();
+        harness
+            .run_generations(3, artifact_dir.path(), |candidate: &AttackCandidate| {
+                run_dirs.push(layout.candidate_dir(
+                    artifact_dir.path(),
+                    candidate.generation,
+                    &candidate.id,
+                ));
+                let threat = if candidate.id.starts_with("champion") {
+                    0.9
+                } else {
+                    0.1
+                };
+                let steps = (0..3).map(|step| step_metrics(step, threat)).collect();
+                Ok(ExecutionReport {
+                    steps,
+                    telemetry_path: None,
+                    metrics_path: None,
+                    stimulus_path: None,
+                })
+            })
+            .expect("generation runs");
+
+        // Re-runs are not archived again.
+        let champion_runs = harness
+            .archive()
+            .iter()
+            .filter(|outcome| outcome.candidate.id == "champion")
+            .count();
+        assert_eq!(champion_runs, 1);
+        let champion_dirs = run_dirs
+            .iter()
+            .filter(|dir| dir.to_string_lossy().contains("champion"))
+            .count();
+        assert_eq!(champion_dirs, 3);
+        let unique: HashSet<&PathBuf> = run_dirs.iter().collect();
+        assert_eq!(unique.len(), run_dirs.len(), "reused dirs: {run_dirs:?}");
+    }
+
+    struct CapturingLogger;
+
+    static CAPTURED_LOGS: std::sync::Mutex<Vec<(log::Level, String)>> =
+        std::sync::Mutex::new(Vec::new());
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Info
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                CAPTURED_LOGS
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURING_LOGGER: CapturingLogger = CapturingLogger;
+
+    #[test]
+    fn generation_start_is_logged_at_info_level() {
+        // `set_logger` only succeeds once per process; a repeat call is harmless.
+        let _ = log::set_logger(&CAPTURING_LOGGER);
+        log::set_max_level(log::LevelFilter::Info);
+
+        let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+        harness.enqueue(AttackCandidate {
+            id: "logged-seed".into(),
+            scenario_ref: "docs/examples/a.yaml".into(),
+            stimulus_ref: None,
+            generation: 0,
+            parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+            mutation: None,
+        });
+        let artifact_dir = tempdir().expect("failed to create temp dir");
+        harness
+            .run_generations(1, artifact_dir.path(), |_| {
+                Ok(ExecutionReport {
+                    steps: vec![step_metrics(0, 0.4)],
+                    telemetry_path: None,
+                    metrics_path: None,
+                    stimulus_path: None,
+                })
+            })
+            .expect("loop execution");
+
+        let logs = CAPTURED_LOGS.lock().unwrap();
+        assert!(
+            logs.iter()
+                .any(|(level, message)| *level == log::Level::Info
+                    && message == "Starting generation 1/1"),
+            "{logs:?}"
+        );
+    }
+
+    #[test]
+    fn cancellation_stops_further_generations_and_keeps_backlog() {
+        let config = EvolutionConfig {
+            batch_size: 2,
+            crossover_rate: 0.0,
+            selection_strategy: SelectionStrategy::Tournament {
+                size: 2,
+                with_replacement: false,
+            },
+            ..test_config()
+        };
+        let seed = |id: &str| AttackCandidate {
+            id: id.into(),
+            scenario_ref: "docs/examples/a.yaml".into(),
+            stimulus_ref: None,
+            generation: 0,
+            parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+            mutation: None,
+        };
+        let report = || ExecutionReport {
+            steps: vec![step_metrics(0, 0.4)],
+            telemetry_path: None,
+            metrics_path: None,
+            stimulus_path: None,
+        };
+        let artifact_dir = tempdir().expect("failed to create temp dir");
+
+        // Flag raised once the first generation's two seeds have run.
+        let mut harness = AdversarialHarness::new(config.clone());
+        harness.enqueue(seed("seed-a"));
+        harness.enqueue(seed("seed-b"));
+        let cancel = Arc::new(AtomicBool::new(false));
+        let mut calls = 0;
+        let evaluations = harness
+            .run_generations_cancellable(
+                5,
+                artifact_dir.path(),
+                |_| {
+                    calls += 1;
+                    if calls == 2 {
+                        cancel.store(true, Ordering::SeqCst);
+                    }
+                    Ok(report())
+                },
+                &cancel,
+            )
+            .expect("cancellable run");
+        assert_eq!(calls, 2);
+        assert_eq!(evaluations.len(), 2);
+        assert_eq!(harness.archive.len(), 2);
+        assert!(harness.backlog_len() > 0, "bred candidates are kept");
+
+        // Flag raised mid-generation: the unexecuted seed stays queued first.
+        let mut harness = AdversarialHarness::new(config);
+        harness.enqueue(seed("seed-a"));
+        harness.enqueue(seed("seed-b"));
+        let cancel = AtomicBool::new(false);
+        let evaluations = harness
+            .run_generations_cancellable(
+                5,
+                artifact_dir.path(),
+                |_| {
+                    cancel.store(true, Ordering::SeqCst);
+                    Ok(report())
+                },
+                &cancel,
+            )
+            .expect("cancellable run");
+        assert_eq!(evaluations.len(), 1);
+        assert_eq!(harness.archive.len(), 1);
+        assert_eq!(harness.next_batch()[0].id, "seed-b");
+    }
+
+    #[test]
+    fn stop_on_fitness_ends_sweep_after_triggering_generation() {
+        let report = |threat_score: f32, stimulus_total: f32| ExecutionReport {
+            steps: vec![StepMetrics {
+                stimulus_total,
+                ..step_metrics(0, threat_score)
+            }],
+            telemetry_path: None,
+            metrics_path: None,
+            stimulus_path: None,
+        };
+        let fitness_of = |report: &ExecutionReport| {
+            analyze_run_statistics(
+                build_statistics_from_steps(&report.steps).unwrap(),
+                &test_config(),
+                None,
+            )
+            .fitness_score
+        };
+        let hot_fitness = fitness_of(&report(1.5, 3.0));
+        assert!(fitness_of(&report(0.1, 0.0)) < hot_fitness);
+
+        let mut harness = AdversarialHarness::new(EvolutionConfig {
+            batch_size: 2,
+            crossover_rate: 0.0,
+            selection_strategy: SelectionStrategy::Tournament {
+                size: 2,
+                with_replacement: false,
+            },
+            stop_on_fitness: Some(hot_fitness),
+            ..test_config()
+        });
+        for id in ["seed-a", "seed-b"] {
+            harness.enqueue(AttackCandidate {
+                id: id.into(),
+                scenario_ref: "docs/examples/a.yaml".into(),
+                stimulus_ref: None,
+                generation: 0,
+                parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
+                mutation: None,
+            });
+        }
+
+        // Seeds are weak; every bred candidate from generation 1 on is strong.
+        let artifact_dir = tempdir().expect("failed to create temp dir");
+        let evaluations = harness
+            .run_generations(5, artifact_dir.path(), |candidate| {
+                Ok(if candidate.generation == 0 {
+                    report(0.1, 0.0)
+                } else {
+                    report(1.5, 3.0)
+                })
+            })
+            .expect("run succeeds");
+
+        let last_generation = evaluations
+            .iter()
+            .map(|evaluation| evaluation.candidate.generation)
+            .max();
+        assert_eq!(last_generation, Some(1));
+        assert!(
+            evaluations
+                .iter()
+                .any(|evaluation| evaluation.outcome.fitness_score >= hot_fitness)
+        );
+        assert!(harness.backlog_len() > 0, "remaining backlog is kept");
+    }
+
     #[test]
     fn archive_prunes_to_configured_limit() {
         let mut harness = AdversarialHarness::new(EvolutionConfig {
-            batch_size: 1,
             max_generations: 2,
-            retain_elite: false,
-            crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
-            crossover_strategy: CrossoverStrategy::Uniform,
-            mutation_strategy: MutationStrategy::Random,
+            ..test_config()
         });
 
-        let template_stats = RunStatistics {
-            step_count: 1,
-            avg_threat: 0.1,
-            max_threat: 0.2,
-            avg_cell_count: 1.0,
-            min_cell_count: 1,
-            max_cell_count: 1,
-            total_replications: 0,
-            total_deaths: 0,
-            total_signals: 0,
-            total_lineage_shifts: 0,
-            total_stimulus: 0.0,
-            signals_by_topic: HashMap::new(),
-            lineage_by_type: HashMap::new(),
-            stimuli_by_topic: HashMap::new(),
-            avg_topology_degree: 0.0,
-            max_isolation_count: 0,
-        };
+        let template_stats = run_stats();
 
         for idx in 0..3 {
             let outcome = AttackOutcome {
@@ -1728,6 +3651,8 @@ mod tests {
                     stimulus_ref: None,
                     generation: idx,
                     parent_id: None,
+                    origin_scenario: None,
+                    cumulative_factors: BTreeMap::new(),
                     mutation: None,
                 },
                 fitness_score: idx as f32,
@@ -1744,36 +3669,406 @@ mod tests {
     }
 
     #[test]
-    fn archive_clears_when_limit_zero() {
-        let mut harness = AdversarialHarness::new(EvolutionConfig {
-            batch_size: 1,
-            max_generations: 0,
-            retain_elite: false,
-            crossover_rate: 0.7,
-            selection_strategy: SelectionStrategy::Tournament { size: 3 },
-            crossover_strategy: CrossoverStrategy::Uniform,
-            mutation_strategy: MutationStrategy::Random,
-        });
+    fn outcomes_filter_by_origin_scenario_across_lineages() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+        let stats = run_stats();
+        let outcome =
+            |id: &str, scenario_ref: &str, origin: Option<&str>, fitness: f32| AttackOutcome {
+                candidate: AttackCandidate {
+                    id: id.into(),
+                    scenario_ref: scenario_ref.into(),
+                    stimulus_ref: None,
+                    generation: u32::from(origin.is_some()),
+                    parent_id: None,
+                    origin_scenario: origin.map(str::to_string),
+                    cumulative_factors: BTreeMap::new(),
+                    mutation: None,
+                },
+                fitness_score: fitness,
+                breach_observed: false,
+                notes: None,
+                statistics: stats.clone(),
+            };
+
+        harness.record_outcome(outcome("alpha", "seeds/alpha.yaml", None, 0.2));
+        harness.record_outcome(outcome("beta", "seeds/beta.yaml", None, 0.4));
+        // Mutants point at rewritten artifacts but keep their seed origin.
+        harness.record_outcome(outcome(
+            "alpha-mut",
+            "artifacts/gen001/alpha-mut/alpha-mut.yaml",
+            Some("seeds/alpha.yaml"),
+            0.9,
+        ));
+        harness.record_outcome(outcome(
+            "beta-mut",
+            "artifacts/gen001/beta-mut/beta-mut.yaml",
+            Some("seeds/beta.yaml"),
+            0.1,
+        ));
+
+        let alpha: Vec<&str> = harness
+            .outcomes_for_scenario("seeds/alpha.yaml")
+            .iter()
+            .map(|outcome| outcome.candidate.id.as_str())
+            .collect();
+        assert_eq!(alpha, vec!["alpha", "alpha-mut"]);
+        assert!(harness.outcomes_for_scenario("seeds/gamma.yaml").is_empty());
+
+        let best = harness.best_outcome_per_scenario();
+        assert_eq!(best.len(), 2);
+        assert_eq!(best["seeds/alpha.yaml"].candidate.id, "alpha-mut");
+        assert_eq!(best["seeds/beta.yaml"].candidate.id, "beta");
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_state_round_trip_matches_json() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+        let steps = vec![StepMetrics {
+            replications: 1,
+            signals_total: 3,
+            lineage_shifts_total: 1,
+            stimulus_total: 1.0,
+            signals_by_topic: BTreeMap::from([("activator".into(), 3)]),
+            lineage_shifts_by_lineage: BTreeMap::from([("Firewall".into(), 1)]),
+            stimulus_by_topic: BTreeMap::from([("activator".into(), 1.0)]),
+            ..step_metrics(0, 0.8)
+        }];
+        let mut candidate = AttackCandidate {
+            id: "seed".to_string(),
+            scenario_ref: "scenario.yaml".to_string(),
+            stimulus_ref: Some("stimulus.jsonl".to_string()),
+            generation: 0,
+            parent_id: None,
+            mutation: Some(Mutation::AddSpike {
+                step: 2,
+                intensity: 0.4,
+            }),
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+        };
+        candidate
+            .cumulative_factors
+            .insert("activator".to_string(), 1.5);
+        harness
+            .evaluate_steps(candidate, steps)
+            .expect("evaluation succeeds");
+
+        let dir = tempdir().expect("tempdir");
+        let json_path = dir.path().join("state.json");
+        let bin_path = dir.path().join("state.bin");
+        harness.save_state_auto(&json_path).expect("json save");
+        harness.save_state_auto(&bin_path).expect("bincode save");
+
+        let from_json = AdversarialHarness::load_state_auto(&json_path).expect("json load");
+        let from_bin = AdversarialHarness::load_state_auto(&bin_path).expect("bincode load");
+        assert_eq!(
+            serde_json::to_value(from_bin.snapshot_state()).unwrap(),
+            serde_json::to_value(from_json.snapshot_state()).unwrap()
+        );
+        assert!(fs::metadata(&bin_path).unwrap().len() < fs::metadata(&json_path).unwrap().len());
+    }
+
+    #[test]
+    fn lenient_load_recovers_intact_entries_from_truncated_state() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+        for idx in 0..3 {
+            harness.record_outcome(AttackOutcome {
+                candidate: AttackCandidate {
+                    id: format!("cand-{idx}"),
+                    scenario_ref: "scenario.yaml".to_string(),
+                    stimulus_ref: None,
+                    generation: 0,
+                    parent_id: None,
+                    mutation: None,
+                    origin_scenario: None,
+                    cumulative_factors: BTreeMap::new(),
+                },
+                fitness_score: 0.1 * idx as f32,
+                breach_observed: false,
+                notes: Some("note with \"quotes\" and {braces}".to_string()),
+                statistics: RunStatistics::default(),
+            });
+        }
+
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("state.json");
+        harness.save_state(&path).expect("save state");
+        assert!(!dir.path().join("state.json.tmp").exists());
+
+        let (intact, warnings) =
+            AdversarialHarness::load_state_lenient(&path).expect("intact load");
+        assert_eq!(warnings, 0);
+        assert_eq!(intact.archive.len(), 3);
+
+        // Cut the file off partway through the last archive entry.
+        let full = fs::read_to_string(&path).unwrap();
+        let last_entry = full.rfind("cand-2").unwrap();
+        fs::write(&path, &full[..last_entry]).unwrap();
+
+        assert!(AdversarialHarness::load_state(&path).is_err());
+        let (recovered, warnings) =
+            AdversarialHarness::load_state_lenient(&path).expect("lenient load");
+        assert_eq!(warnings, 1);
+        assert_eq!(recovered.config().batch_size, harness.config().batch_size);
+        let ids: Vec<_> = recovered
+            .archive
+            .iter()
+            .map(|outcome| outcome.candidate.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["cand-0", "cand-1"]);
+
+        fs::write(&path, &full[..20]).unwrap();
+        assert!(AdversarialHarness::load_state_lenient(&path).is_err());
+    }
+
+    #[test]
+    fn printed_defaults_round_trip_to_the_default_config() {
+        let printed = EvolutionConfig::print_defaults();
+        let parsed: EvolutionConfig = serde_json::from_str(&printed).expect("printed JSON parses");
+        assert_eq!(parsed, EvolutionConfig::default_smoke_test());
+        assert!(printed.contains("\"batch_size\": 3"));
+    }
+
+    #[test]
+    fn shuffled_archive_is_ordered_by_generation_after_load() {
+        let outcome = |id: &str, generation: u32| AttackOutcome {
+            candidate: AttackCandidate {
+                id: id.to_string(),
+                scenario_ref: "scenario.yaml".to_string(),
+                stimulus_ref: None,
+                generation,
+                parent_id: None,
+                mutation: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
+            },
+            fitness_score: 0.5,
+            breach_observed: false,
+            notes: None,
+            statistics: RunStatistics::default(),
+        };
+        let dir = tempdir().expect("tempdir");
+        let write_shuffled = |name: &str, order: &[(&str, u32)]| {
+            let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+            for &(id, generation) in order {
+                harness.record_outcome(outcome(id, generation));
+            }
+            let path = dir.path().join(name);
+            harness.save_state(&path).expect("save state");
+            path
+        };
+        let first = write_shuffled(
+            "first.json",
+            &[("c", 2), ("a", 0), ("d", 1), ("b", 1), ("e", 0)],
+        );
+        let second = write_shuffled(
+            "second.json",
+            &[("b", 1), ("e", 0), ("c", 2), ("d", 1), ("a", 0)],
+        );
+
+        let ids = |path: &Path| -> Vec<String> {
+            AdversarialHarness::load_state(path)
+                .expect("load state")
+                .archive()
+                .iter()
+                .map(|outcome| outcome.candidate.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&first), vec!["a", "e", "b", "d", "c"]);
+        assert_eq!(ids(&first), ids(&second));
+    }
+
+    #[test]
+    fn fitness_histogram_bins_archived_scores() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+        assert!(harness.fitness_histogram(4).is_empty());
+
+        let outcome = |fitness_score: f32| AttackOutcome {
+            candidate: AttackCandidate {
+                id: format!("cand-{fitness_score}"),
+                scenario_ref: "scenario.yaml".to_string(),
+                stimulus_ref: None,
+                generation: 0,
+                parent_id: None,
+                mutation: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
+            },
+            fitness_score,
+            breach_observed: false,
+            notes: None,
+            statistics: RunStatistics::default(),
+        };
+
+        harness.record_outcome(outcome(0.3));
+        let single = harness.fitness_histogram(4);
+        assert_eq!(single.iter().map(|(_, count)| count).sum::<usize>(), 1);
+        assert_eq!(single[1], (0.25, 1));
+
+        for score in [0.05, 0.1, 0.6, 0.99, 1.0] {
+            harness.record_outcome(outcome(score));
+        }
+        let counts: Vec<usize> = harness
+            .fitness_histogram(4)
+            .into_iter()
+            .map(|(_, count)| count)
+            .collect();
+        assert_eq!(counts, vec![2, 1, 1, 2]);
+
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("histogram.csv");
+        harness
+            .write_fitness_histogram_csv(&path, 4)
+            .expect("histogram csv written");
+        let written = fs::read_to_string(&path).expect("histogram csv readable");
+        assert_eq!(written.lines().count(), 5);
+        assert!(written.starts_with("bin_start,bin_end,count"));
+    }
 
+    #[test]
+    fn repeated_increases_stop_scaling_at_cumulative_cap() {
+        let cap = 3.0;
+        let mut parent = AttackCandidate {
+            id: "seed".to_string(),
+            scenario_ref: "scenario.yaml".to_string(),
+            stimulus_ref: None,
+            generation: 0,
+            parent_id: None,
+            mutation: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+        };
+
+        let mut redirected = false;
+        for generation in 1..=10 {
+            let child = AttackCandidate {
+                id: format!("child-{generation}"),
+                generation,
+                parent_id: Some(parent.id.clone()),
+                mutation: Some(Mutation::IncreaseStimulus {
+                    topic: "activator".to_string(),
+                    factor: 1.5,
+                }),
+                ..parent.clone()
+            }
+            .with_bounded_mutation(cap);
+
+            assert!(child.cumulative_factor("activator") <= cap + 1e-4);
+            if parent.cumulative_factor("activator") >= cap - 1e-4 {
+                assert!(!matches!(
+                    &child.mutation,
+                    Some(Mutation::IncreaseStimulus { topic, .. }) if topic == "activator"
+                ));
+                redirected = true;
+            }
+            parent = child;
+        }
+
+        assert!(
+            redirected,
+            "cap should have been reached within ten generations"
+        );
+        assert!((parent.cumulative_factor("activator") - cap).abs() < 1e-4);
+        assert!(matches!(
+            parent.mutation,
+            Some(Mutation::ShiftStimulusTime { .. })
+        ));
+    }
+
+    #[test]
+    fn capped_activator_increase_never_turns_into_inhibitor_increase() {
+        let capped = AttackCandidate {
+            id: "capped".to_string(),
+            scenario_ref: "scenario.yaml".to_string(),
+            stimulus_ref: None,
+            generation: 3,
+            parent_id: None,
+            mutation: Some(Mutation::IncreaseStimulus {
+                topic: "activator".to_string(),
+                factor: 1.5,
+            }),
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::from([("activator".to_string(), 3.0)]),
+        }
+        .with_bounded_mutation(3.0);
+
+        assert!(matches!(
+            capped.mutation,
+            Some(Mutation::ShiftStimulusTime { .. })
+        ));
+        assert_eq!(capped.cumulative_factor("inhibitor"), 1.0);
+    }
+
+    #[test]
+    fn run_metadata_round_trips_with_seed() {
+        let config = EvolutionConfig::default_smoke_test();
+        let metadata = RunMetadata::new(
+            "scenario.yaml",
+            Some("stimulus.jsonl".to_string()),
+            Some(42),
+            &config,
+        );
+        let json = serde_json::to_value(&metadata).expect("serialize metadata");
+        assert_eq!(json["rng_seed"], 42);
+        assert_eq!(json["crate_version"], env!("CARGO_PKG_VERSION"));
+
+        let restored: RunMetadata = serde_json::from_value(json).expect("deserialize metadata");
+        assert_eq!(restored.rng_seed, Some(42));
+        assert_eq!(restored.scenario_path, "scenario.yaml");
+        assert_eq!(restored.stimulus_path.as_deref(), Some("stimulus.jsonl"));
+        assert_eq!(restored.evolution_config.batch_size, config.batch_size);
+    }
+
+    #[test]
+    fn fitness_breakdown_weighted_components_sum_to_total() {
+        let mut lineage_by_type = BTreeMap::new();
+        lineage_by_type.insert("IntrusionDetection".to_string(), 3);
+        lineage_by_type.insert("Healer".to_string(), 1);
         let stats = RunStatistics {
-            step_count: 1,
-            avg_threat: 0.1,
-            max_threat: 0.2,
-            avg_cell_count: 1.0,
-            min_cell_count: 1,
-            max_cell_count: 1,
-            total_replications: 0,
-            total_deaths: 0,
-            total_signals: 0,
-            total_lineage_shifts: 0,
-            total_stimulus: 0.0,
-            signals_by_topic: HashMap::new(),
-            lineage_by_type: HashMap::new(),
-            stimuli_by_topic: HashMap::new(),
-            avg_topology_degree: 0.0,
-            max_isolation_count: 0,
+            step_count: 20,
+            avg_threat: 0.9,
+            max_threat: 1.2,
+            avg_cell_count: 6.0,
+            min_cell_count: 3,
+            max_cell_count: 8,
+            total_replications: 5,
+            total_deaths: 2,
+            total_signals: 30,
+            total_lineage_shifts: 4,
+            total_stimulus: 12.0,
+            lineage_by_type,
+            avg_topology_degree: 1.5,
+            max_isolation_count: 2,
+            ..run_stats()
         };
 
+        let breakdown = fitness_breakdown(&stats);
+        let (fitness, _) = compute_fitness(&stats, &test_config(), None);
+        let weighted: f32 = breakdown
+            .components()
+            .iter()
+            .map(|(_, weight, value)| weight * value)
+            .sum();
+        assert!((weighted - breakdown.total).abs() < 1e-6);
+        assert!((breakdown.total - fitness).abs() < 1e-6);
+        let weight_sum: f32 = breakdown.components().iter().map(|(_, w, _)| w).sum();
+        assert!((weight_sum - 1.0).abs() < 1e-6);
+
+        let analysis = analyze_run_statistics(stats, &test_config(), None);
+        assert_eq!(analysis.fitness_breakdown, breakdown);
+        assert!(!analysis.recommendation_reason.is_empty());
+    }
+
+    #[test]
+    fn archive_clears_when_limit_zero() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig {
+            max_generations: 0,
+            ..test_config()
+        });
+
+        let stats = run_stats();
+
         let outcome = AttackOutcome {
             candidate: AttackCandidate {
                 id: "zero-limit".into(),
@@ -1781,6 +4076,8 @@ mod tests {
                 stimulus_ref: None,
                 generation: 0,
                 parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
                 mutation: None,
             },
             fitness_score: 0.5,
@@ -1803,17 +4100,14 @@ mod tests {
             min_cell_count: 9,
             max_cell_count: 12,
             total_replications: 8,
-            total_deaths: 0,
             total_signals: 24,
-            total_lineage_shifts: 0,
             total_stimulus: 0.6,
-            signals_by_topic: HashMap::from([("activator".into(), 20)]),
-            lineage_by_type: HashMap::new(),
-            stimuli_by_topic: HashMap::from([("activator".into(), 0.6)]),
-            avg_topology_degree: 0.0,
-            max_isolation_count: 0,
+            signals_by_topic: BTreeMap::from([("activator".into(), 20)]),
+            stimuli_by_topic: BTreeMap::from([("activator".into(), 0.6)]),
+            ..run_stats()
         };
-        let (baseline_fitness, baseline_breach) = compute_fitness(&base_stats);
+        let (baseline_fitness, baseline_breach) =
+            compute_fitness(&base_stats, &test_config(), None);
         assert!(baseline_fitness > 0.0);
         assert!(!baseline_breach);
 
@@ -1826,7 +4120,8 @@ mod tests {
             .lineage_by_type
             .insert("IntrusionDetection".into(), 10);
 
-        let (elevated_fitness, elevated_breach) = compute_fitness(&elevated_stats);
+        let (elevated_fitness, elevated_breach) =
+            compute_fitness(&elevated_stats, &test_config(), None);
         assert!(
             elevated_fitness > baseline_fitness + 0.1,
             "expected {elevated_fitness} to significantly exceed {baseline_fitness}"
@@ -1844,18 +4139,13 @@ mod tests {
             min_cell_count: 8,
             max_cell_count: 12,
             total_replications: 9,
-            total_deaths: 0,
             total_signals: 12,
             total_lineage_shifts: 2,
-            total_stimulus: 0.0,
-            signals_by_topic: HashMap::new(),
-            lineage_by_type: HashMap::from([("IntrusionDetection".into(), 2)]),
-            stimuli_by_topic: HashMap::new(),
-            avg_topology_degree: 0.0,
-            max_isolation_count: 0,
+            lineage_by_type: BTreeMap::from([("IntrusionDetection".into(), 2)]),
+            ..run_stats()
         };
 
-        let (fitness, breach) = compute_fitness(&stats);
+        let (fitness, breach) = compute_fitness(&stats, &test_config(), None);
         assert!(fitness >= 0.35);
         assert!(!breach);
 
@@ -1870,6 +4160,147 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scenario_normalization_makes_fitness_length_independent() {
+        // A 10-step threat cycle that loses cells and regrows them, repeated
+        // for each length.
+        let run = |length: u32| {
+            let steps: Vec<StepMetrics> = (0..length)
+                .map(|step| StepMetrics {
+                    cell_count: 20 - step % 10,
+                    replications: 1,
+                    signals_total: 2,
+                    stimulus_total: 0.4,
+                    ..step_metrics(step, if step % 10 < 3 { 1.2 } else { 0.3 })
+                })
+                .collect();
+            let stats = build_statistics_from_steps(&steps).unwrap();
+            let score = |fitness_normalization| {
+                let config = EvolutionConfig {
+                    fitness_normalization,
+                    ..test_config()
+                };
+                // The cycle's spikes peak at 1.2.
+                analyze_run_statistics(stats.clone(), &config, Some(1.2))
+            };
+            (
+                score(FitnessNormalization::Raw),
+                score(FitnessNormalization::Scenario),
+            )
+        };
+
+        let (raw_short, normalized_short) = run(50);
+        let (_, normalized_long) = run(500);
+        let normalized_gap = (normalized_short.fitness_score - normalized_long.fitness_score).abs();
+        assert!(normalized_gap < 0.005, "normalized gap {normalized_gap}");
+        // Cell loss is already a ratio of population extremes and is left as is.
+        let cell_loss = normalized_short.fitness_breakdown.cell_loss;
+        assert!((cell_loss - 0.45).abs() < 1e-6, "cell loss {cell_loss}");
+        assert_eq!(cell_loss, raw_short.fitness_breakdown.cell_loss);
+        assert_eq!(cell_loss, normalized_long.fitness_breakdown.cell_loss);
+    }
+
+    #[test]
+    fn scenario_normalization_keeps_flat_run_intensity() {
+        let config = EvolutionConfig {
+            fitness_normalization: FitnessNormalization::Scenario,
+            ..test_config()
+        };
+        let flat_run = |threat: f32| {
+            let steps: Vec<StepMetrics> = (0..20).map(|step| step_metrics(step, threat)).collect();
+            let stats = build_statistics_from_steps(&steps).unwrap();
+            analyze_run_statistics(stats, &config, Some(1.0))
+        };
+
+        let mild = flat_run(0.4);
+        let intense = flat_run(0.8);
+        assert!((mild.fitness_breakdown.threat - 0.4).abs() < 1e-6);
+        assert!((intense.fitness_breakdown.threat - 0.8).abs() < 1e-6);
+        assert!(intense.fitness_score > mild.fitness_score);
+    }
+
+    #[test]
+    fn scenario_normalization_measures_against_the_scheduled_peak() {
+        let dir = tempdir().expect("tempdir");
+        let scenario_path = dir.path().join("spiky.yaml");
+        std::fs::write(
+            &scenario_path,
+            "simulation_steps: 10\nthreat_profile:\n  background_threat: 0.1\nspikes:\n  - step: 2\n    intensity: 0.9\n",
+        )
+        .expect("write scenario");
+        let mut harness = AdversarialHarness::new(EvolutionConfig {
+            fitness_normalization: FitnessNormalization::Scenario,
+            ..test_config()
+        });
+        let mut threat_component = |scenario_ref: &Path| {
+            let candidate = AttackCandidate {
+                id: "flat".to_string(),
+                scenario_ref: scenario_ref.display().to_string(),
+                stimulus_ref: None,
+                generation: 0,
+                parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
+                mutation: None,
+            };
+            let steps = (0..10).map(|step| step_metrics(step, 0.5)).collect();
+            let (_, _, analysis) = harness.evaluate_steps(candidate, steps).expect("scored");
+            analysis.fitness_breakdown.threat
+        };
+
+        assert!((threat_component(&scenario_path) - 0.5).abs() < 1e-6);
+        let unreadable = threat_component(&dir.path().join("missing.yaml"));
+        assert!((unreadable - 0.5 / REFERENCE_THREAT).abs() < 1e-6);
+    }
+
+    #[test]
+    fn tightened_max_threat_criterion_flips_breach() {
+        let stats = RunStatistics {
+            step_count: 20,
+            avg_threat: 0.4,
+            max_threat: 1.0,
+            avg_cell_count: 10.0,
+            min_cell_count: 10,
+            max_cell_count: 10,
+            total_replications: 10,
+            total_signals: 10,
+            ..run_stats()
+        };
+        let with_criteria = |breach_criteria| EvolutionConfig {
+            breach_criteria,
+            ..test_config()
+        };
+        assert!(!analyze_run_statistics(stats.clone(), &test_config(), None).breach_observed);
+
+        let strict = BreachCriteria {
+            max_threat: Some(0.9),
+            ..BreachCriteria::default()
+        };
+        assert!(
+            analyze_run_statistics(stats.clone(), &with_criteria(strict), None).breach_observed
+        );
+
+        let disabled = BreachCriteria {
+            fitness: None,
+            max_threat: None,
+            cell_loss: None,
+            lineage: None,
+        };
+        let runaway = RunStatistics {
+            max_threat: 50.0,
+            ..stats
+        };
+        assert!(analyze_run_statistics(runaway.clone(), &test_config(), None).breach_observed);
+        assert!(!analyze_run_statistics(runaway, &with_criteria(disabled), None).breach_observed);
+
+        // Omitted fields keep their defaults; `null` switches a criterion off.
+        let parsed: BreachCriteria =
+            serde_json::from_str(r#"{"max_threat": 0.9, "lineage": null}"#).unwrap();
+        assert_eq!(parsed.max_threat, Some(0.9));
+        assert_eq!(parsed.fitness, Some(0.65));
+        assert_eq!(parsed.lineage, None);
+    }
+
     #[test]
     fn recommendation_focuses_dominant_lineage_when_diffuse() {
         let stats = RunStatistics {
@@ -1880,22 +4311,18 @@ mod tests {
             min_cell_count: 9,
             max_cell_count: 14,
             total_replications: 6,
-            total_deaths: 0,
             total_signals: 18,
             total_lineage_shifts: 8,
             total_stimulus: 0.5,
-            signals_by_topic: HashMap::new(),
-            lineage_by_type: HashMap::from([
+            lineage_by_type: BTreeMap::from([
                 ("IntrusionDetection".into(), 3),
                 ("AdaptiveProbe".into(), 3),
                 ("Recon".into(), 2),
             ]),
-            stimuli_by_topic: HashMap::new(),
-            avg_topology_degree: 0.0,
-            max_isolation_count: 0,
+            ..run_stats()
         };
 
-        let (fitness, breach) = compute_fitness(&stats);
+        let (fitness, breach) = compute_fitness(&stats, &test_config(), None);
         assert!(fitness > 0.35);
         assert!(!breach);
 
@@ -1929,6 +4356,8 @@ mod tests {
                 stimulus_ref: Some(parent1_stimulus_path),
                 generation: 1,
                 parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
                 mutation: Some(Mutation::AddSpike {
                     step: 10,
                     intensity: 0.5,
@@ -1937,24 +4366,7 @@ mod tests {
             fitness_score: 0.7,
             breach_observed: true,
             notes: None,
-            statistics: RunStatistics {
-                step_count: 1,
-                avg_threat: 0.1,
-                max_threat: 0.2,
-                avg_cell_count: 1.0,
-                min_cell_count: 1,
-                max_cell_count: 1,
-                total_replications: 0,
-                total_deaths: 0,
-                total_signals: 0,
-                total_lineage_shifts: 0,
-                total_stimulus: 0.0,
-                signals_by_topic: HashMap::new(),
-                lineage_by_type: HashMap::new(),
-                stimuli_by_topic: HashMap::new(),
-                avg_topology_degree: 0.0,
-                max_isolation_count: 0,
-            },
+            statistics: run_stats(),
         };
 
         let parent2_outcome = AttackOutcome {
@@ -1964,6 +4376,8 @@ mod tests {
                 stimulus_ref: Some(parent2_stimulus_path),
                 generation: 2,
                 parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: BTreeMap::new(),
                 mutation: Some(Mutation::IncreaseStimulus {
                     topic: "activator".to_string(),
                     factor: 1.2,
@@ -1972,24 +4386,7 @@ mod tests {
             fitness_score: 0.8,
             breach_observed: true,
             notes: None,
-            statistics: RunStatistics {
-                step_count: 1,
-                avg_threat: 0.1,
-                max_threat: 0.2,
-                avg_cell_count: 1.0,
-                min_cell_count: 1,
-                max_cell_count: 1,
-                total_replications: 0,
-                total_deaths: 0,
-                total_signals: 0,
-                total_lineage_shifts: 0,
-                total_stimulus: 0.0,
-                signals_by_topic: HashMap::new(),
-                lineage_by_type: HashMap::new(),
-                stimuli_by_topic: HashMap::new(),
-                avg_topology_degree: 0.0,
-                max_isolation_count: 0,
-            },
+            statistics: run_stats(),
         };
 
         let mut rng = rand::thread_rng();
@@ -2000,6 +4397,8 @@ mod tests {
             &mut rng,
             artifact_dir.path(),
             &CrossoverStrategy::Uniform,
+            ArtifactLayout::Nested,
+            false,
         )
         .expect("crossover failed");
 
@@ -2014,6 +4413,163 @@ mod tests {
         assert!(child.mutation.is_some());
     }
 
+    #[test]
+    fn scenario_crossover_writes_child_scenario_between_parents() {
+        let dir = tempdir().expect("tempdir");
+        let parent = |id: &str, yaml: &str| {
+            let path = dir.path().join(format!("{id}.yaml"));
+            fs::write(&path, yaml).expect("write scenario");
+            AttackOutcome {
+                candidate: AttackCandidate {
+                    id: id.to_string(),
+                    scenario_ref: path.to_string_lossy().to_string(),
+                    stimulus_ref: None,
+                    generation: 0,
+                    parent_id: None,
+                    origin_scenario: None,
+                    cumulative_factors: BTreeMap::new(),
+                    mutation: None,
+                },
+                fitness_score: 0.5,
+                breach_observed: false,
+                notes: None,
+                statistics: RunStatistics::default(),
+            }
+        };
+        let low = parent(
+            "low",
+            "simulation_steps: 10\nthreat_profile:\n  background_threat: 0.1\nspikes:\n  - step: 8\n    intensity: 0.5\n",
+        );
+        let high = parent(
+            "high",
+            "simulation_steps: 30\nthreat_profile:\n  background_threat: 0.5\nspikes:\n  - step: 2\n    intensity: 0.9\n",
+        );
+
+        let mut rng = rand::thread_rng();
+        let child = perform_crossover(
+            &low,
+            &high,
+            &mut rng,
+            dir.path(),
+            &CrossoverStrategy::Uniform,
+            ArtifactLayout::Nested,
+            true,
+        )
+        .expect("crossover succeeds");
+
+        assert_ne!(child.scenario_ref, low.candidate.scenario_ref);
+        let scenario = load_scenario(&child.scenario_ref).expect("child scenario written");
+        assert!(
+            (10..=30).contains(&scenario.simulation_steps),
+            "{}",
+            scenario.simulation_steps
+        );
+        assert!((scenario.threat_profile.background_threat - 0.3).abs() < 1e-6);
+        let spike_steps: Vec<u32> = scenario.spikes.iter().map(|spike| spike.step).collect();
+        assert_eq!(spike_steps, vec![2, 8]);
+    }
+
+    #[test]
+    fn scenario_crossover_merges_spikes_by_step_within_the_child_run() {
+        let scenario = |yaml: &str| config::load_from_reader(yaml.as_bytes()).expect("parses");
+        let short = scenario(
+            "simulation_steps: 10\nspikes:\n  - step: 2\n    intensity: 0.4\n  - step: 9\n    intensity: 0.8\n",
+        );
+        let long = scenario(
+            "simulation_steps: 20\nspikes:\n  - step: 2\n    intensity: 0.8\n    duration: 3\n  - step: 18\n    intensity: 1.0\n",
+        );
+
+        let child = crossover_scenario_configs(&short, &long);
+        assert_eq!(child.simulation_steps, 15);
+        let spikes: Vec<(u32, f32, u32)> = child
+            .spikes
+            .iter()
+            .map(|spike| (spike.step, spike.intensity, spike.duration))
+            .collect();
+        assert_eq!(spikes.len(), 2, "{spikes:?}");
+        assert_eq!((spikes[0].0, spikes[0].2), (2, 3));
+        assert!((spikes[0].1 - 0.6).abs() < 1e-6);
+        assert_eq!(spikes[1].0, 9);
+        assert!(child.validate().is_ok());
+    }
+
+    #[test]
+    fn multi_crossover_draws_steps_from_every_weighted_parent() {
+        use rand::SeedableRng;
+
+        let dir = tempdir().expect("tempdir");
+        let parents: Vec<AttackOutcome> = [0.1f32, 0.2, 0.3]
+            .iter()
+            .enumerate()
+            .map(|(index, marker)| {
+                // Every parent schedules the same steps; `value` identifies the donor.
+                let path = dir.path().join(format!("parent{index}.jsonl"));
+                let lines: String = (0..8)
+                    .map(|step| {
+                        format!("{{\"step\":{step},\"topic\":\"activator\",\"value\":{marker}}}\n")
+                    })
+                    .collect();
+                fs::write(&path, lines).expect("write parent stimulus");
+                AttackOutcome {
+                    candidate: AttackCandidate {
+                        id: format!("parent{index}"),
+                        scenario_ref: "scenario.yaml".into(),
+                        stimulus_ref: Some(path.to_string_lossy().to_string()),
+                        generation: index as u32 * 2,
+                        parent_id: None,
+                        origin_scenario: None,
+                        cumulative_factors: BTreeMap::new(),
+                        mutation: None,
+                    },
+                    fitness_score: 0.5,
+                    breach_observed: false,
+                    notes: None,
+                    statistics: RunStatistics::default(),
+                }
+            })
+            .collect();
+        let parent_refs: Vec<&AttackOutcome> = parents.iter().collect();
+        let weights = [0.5, 0.3, 0.2];
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let mut drawn = [0usize; 3];
+        for _ in 0..30 {
+            let child = perform_multi_crossover(
+                &parent_refs,
+                &weights,
+                &mut rng,
+                dir.path(),
+                &CrossoverStrategy::Uniform,
+                ArtifactLayout::Flat,
+            )
+            .expect("crossover succeeds");
+            assert_eq!(child.generation, 5);
+            assert_eq!(child.parent_id.as_deref(), Some("parent0,parent1,parent2"));
+
+            let schedule =
+                StimulusSchedule::load(child.stimulus_ref.expect("blended stimulus")).unwrap();
+            assert_eq!(schedule.commands.len(), 8);
+            for commands in schedule.commands.values() {
+                let donor = (commands[0].value * 10.0).round() as usize - 1;
+                drawn[donor] += 1;
+            }
+        }
+        assert!(drawn.iter().all(|count| *count > 0), "draws {drawn:?}");
+        assert!(drawn[0] > drawn[2], "weights ignored: {drawn:?}");
+
+        let mismatch = perform_multi_crossover(
+            &parent_refs,
+            &[1.0, 1.0],
+            &mut rng,
+            dir.path(),
+            &CrossoverStrategy::Uniform,
+            ArtifactLayout::Nested,
+        );
+        assert!(
+            matches!(mismatch, Err(HarnessError::Custom(reason)) if reason.contains("weights"))
+        );
+    }
+
     #[derive(Serialize)]
     struct TestRow {
         step: u32,
@@ -2088,34 +4644,133 @@ mod tests {
         writer.into_inner().expect("extract writer")
     }
 
+    #[test]
+    fn metrics_csv_tolerates_reordered_columns() {
+        let csv = "\
+stimulus_total,cell_count,step,signals_by_topic,threat_score,lineage_shifts_total,replications,signals_total
+0.5,4,0,\"{\"\"activator\"\":2}\",0.3,1,1,2
+0.0,5,1,{},0.6,0,0,0
+";
+        let steps = load_step_metrics_from_csv(Cursor::new(csv)).expect("reordered csv loads");
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].step, 0);
+        assert_eq!(steps[0].cell_count, 4);
+        assert_eq!(steps[0].signals_by_topic.get("activator"), Some(&2));
+        assert!((steps[1].threat_score - 0.6).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn metrics_csv_defaults_missing_optional_columns() {
+        let csv = "\
+step,threat_score,cell_count,replications,signals_total,lineage_shifts_total,stimulus_total
+0,0.2,3,0,1,0,0.0
+";
+        let steps = load_step_metrics_from_csv(Cursor::new(csv)).expect("minimal csv loads");
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].deaths, 0);
+        assert!(steps[0].signals_by_topic.is_empty());
+        assert!(steps[0].lineage_shifts_by_lineage.is_empty());
+        assert!(steps[0].stimulus_by_topic.is_empty());
+        assert!(steps[0].population_stats.is_none());
+        assert!(steps[0].topology_stats.is_none());
+    }
+
+    #[test]
+    fn metrics_csv_names_missing_required_column() {
+        let csv =
+            "step,threat_score,replications,signals_total,lineage_shifts_total,stimulus_total\n";
+        match load_step_metrics_from_csv(Cursor::new(csv)) {
+            Err(HarnessError::Custom(message)) => assert!(message.contains("`cell_count`")),
+            other => panic!("expected missing column error, got {other:?}"),
+        }
+    }
+
     fn analyze_metrics_csv_from_reader<R: Read>(
         reader: R,
     ) -> Result<HarnessAnalysis, HarnessError> {
         let steps = load_step_metrics_from_csv(reader)?;
         let stats = build_statistics_from_steps(&steps)?;
-        Ok(analyze_run_statistics(stats))
+        Ok(analyze_run_statistics(stats, &test_config(), None))
     }
 
     #[test]
-    fn test_tournament_selection_edge_cases() {
-        let stats = RunStatistics {
-            step_count: 1,
-            avg_threat: 0.1,
-            max_threat: 0.2,
-            avg_cell_count: 1.0,
-            min_cell_count: 1,
-            max_cell_count: 1,
-            total_replications: 0,
-            total_deaths: 0,
-            total_signals: 0,
-            total_lineage_shifts: 0,
-            total_stimulus: 0.0,
-            signals_by_topic: HashMap::new(),
-            lineage_by_type: HashMap::new(),
-            stimuli_by_topic: HashMap::new(),
-            avg_topology_degree: 0.0,
-            max_isolation_count: 0,
+    fn threat_percentiles_use_nearest_rank_and_survive_csv() {
+        // Threats 0.1..=1.0 in shuffled order, plus one spike.
+        let threats = [0.7, 0.2, 1.0, 0.4, 0.9, 0.1, 0.6, 0.3, 0.8, 0.5, 5.0];
+        let steps: Vec<StepMetrics> = threats
+            .iter()
+            .enumerate()
+            .map(|(step, threat)| StepMetrics {
+                cell_count: 3,
+                ..step_metrics(step as u32, *threat)
+            })
+            .collect();
+
+        let stats = build_statistics_from_steps(&steps).expect("stats");
+        assert_eq!(stats.p50_threat, 0.6);
+        assert_eq!(stats.p90_threat, 1.0);
+        assert_eq!(stats.p99_threat, 5.0);
+        assert_eq!(stats.max_threat, 5.0);
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("metrics.csv");
+        write_step_metrics_csv(&path, &steps).expect("csv written");
+        let reloaded = analyze_metrics_csv(&path, &test_config())
+            .expect("csv analysed")
+            .statistics;
+        assert_eq!(reloaded.p50_threat, stats.p50_threat);
+        assert_eq!(reloaded.p90_threat, stats.p90_threat);
+        assert_eq!(reloaded.p99_threat, stats.p99_threat);
+
+        let encoded = serde_json::to_value(&stats).expect("serialize");
+        let mut legacy = encoded.as_object().unwrap().clone();
+        legacy.remove("p90_threat");
+        let decoded: RunStatistics =
+            serde_json::from_value(legacy.into()).expect("pre-percentile stats load");
+        assert_eq!(decoded.p90_threat, 0.0);
+        assert_eq!(decoded.p50_threat, 0.6);
+    }
+
+    #[test]
+    fn from_repeated_reports_mean_and_spread() {
+        let template = RunStatistics {
+            step_count: 10,
+            avg_threat: 0.4,
+            max_threat: 0.9,
+            avg_cell_count: 5.0,
+            min_cell_count: 4,
+            max_cell_count: 6,
+            total_replications: 3,
+            total_deaths: 1,
+            total_signals: 12,
+            total_lineage_shifts: 2,
+            total_stimulus: 1.5,
+            ..run_stats()
         };
+        let runs: Vec<RunStatistics> = [0.2, 0.4, 0.6]
+            .iter()
+            .map(|threat| {
+                let mut run = template.clone();
+                run.avg_threat = *threat;
+                run
+            })
+            .collect();
+
+        let aggregated = RunStatistics::from_repeated(&runs);
+        assert_eq!(aggregated.run_count, 3);
+        assert!((aggregated.avg_threat.mean - 0.4).abs() < 1e-6);
+        assert!((aggregated.avg_threat.std_dev - 0.2).abs() < 1e-6);
+        assert!(aggregated.avg_threat.ci95 > 0.0);
+        assert!((aggregated.total_signals.mean - 12.0).abs() < 1e-6);
+        assert_eq!(aggregated.total_signals.std_dev, 0.0);
+
+        let encoded = serde_json::to_value(&aggregated).expect("serialize aggregate");
+        assert!(encoded["avg_threat"]["std_dev"].as_f64().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_tournament_selection_edge_cases() {
+        let stats = run_stats();
         let outcomes: Vec<AttackOutcome> = (0..3)
             .map(|i| AttackOutcome {
                 candidate: AttackCandidate {
@@ -2124,6 +4779,8 @@ mod tests {
                     stimulus_ref: None,
                     generation: 0,
                     parent_id: None,
+                    origin_scenario: None,
+                    cumulative_factors: BTreeMap::new(),
                     mutation: None,
                 },
                 fitness_score: i as f32 * 0.1,
@@ -2136,18 +4793,57 @@ mod tests {
         let mut rng = rand::thread_rng();
 
         // Size 1 should succeed
-        assert!(tournament_selection(&outcomes, 1, &mut rng).is_ok());
+        assert!(tournament_selection(&outcomes, 1, false, &mut rng).is_ok());
 
         // Size larger than population should cap at max fitness
-        let selected = tournament_selection(&outcomes, 10, &mut rng).expect("selection failed");
+        let selected =
+            tournament_selection(&outcomes, 10, false, &mut rng).expect("selection failed");
         assert_eq!(selected.fitness_score, 0.2);
 
         // Empty population should error
         let empty: Vec<AttackOutcome> = vec![];
-        assert!(tournament_selection(&empty, 3, &mut rng).is_err());
+        assert!(tournament_selection(&empty, 3, false, &mut rng).is_err());
+        assert!(tournament_selection(&empty, 3, true, &mut rng).is_err());
 
         // Size 0 should error
-        assert!(tournament_selection(&outcomes, 0, &mut rng).is_err());
+        assert!(tournament_selection(&outcomes, 0, false, &mut rng).is_err());
+    }
+
+    #[test]
+    fn tournament_with_replacement_can_draw_an_outcome_twice() {
+        let outcomes: Vec<AttackOutcome> = (0..2)
+            .map(|i| AttackOutcome {
+                candidate: AttackCandidate {
+                    id: format!("cand-{i}"),
+                    scenario_ref: "s".into(),
+                    stimulus_ref: None,
+                    generation: 0,
+                    parent_id: None,
+                    origin_scenario: None,
+                    cumulative_factors: BTreeMap::new(),
+                    mutation: None,
+                },
+                fitness_score: i as f32,
+                breach_observed: false,
+                notes: None,
+                statistics: RunStatistics::default(),
+            })
+            .collect();
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+
+        let without = tournament_sample(&outcomes, 5, false, &mut rng);
+        assert_eq!(without.len(), 2);
+
+        // Five draws from two outcomes must repeat at least one of them.
+        let with = tournament_sample(&outcomes, 5, true, &mut rng);
+        assert_eq!(with.len(), 5);
+        let distinct: HashSet<&str> = with
+            .iter()
+            .map(|outcome| outcome.candidate.id.as_str())
+            .collect();
+        assert!(distinct.len() < with.len());
+        assert!(tournament_selection(&outcomes, 5, true, &mut rng).is_ok());
     }
 
     #[test]
@@ -2159,24 +4855,7 @@ mod tests {
         assert!(roulette_wheel_selection(&empty, &mut rng).is_err());
 
         // All zero fitness should select randomly but succeed
-        let stats = RunStatistics {
-            step_count: 1,
-            avg_threat: 0.1,
-            max_threat: 0.2,
-            avg_cell_count: 1.0,
-            min_cell_count: 1,
-            max_cell_count: 1,
-            total_replications: 0,
-            total_deaths: 0,
-            total_signals: 0,
-            total_lineage_shifts: 0,
-            total_stimulus: 0.0,
-            signals_by_topic: HashMap::new(),
-            lineage_by_type: HashMap::new(),
-            stimuli_by_topic: HashMap::new(),
-            avg_topology_degree: 0.0,
-            max_isolation_count: 0,
-        };
+        let stats = run_stats();
         let zeros: Vec<AttackOutcome> = (0..3)
             .map(|i| AttackOutcome {
                 candidate: AttackCandidate {
@@ -2185,6 +4864,8 @@ mod tests {
                     stimulus_ref: None,
                     generation: 0,
                     parent_id: None,
+                    origin_scenario: None,
+                    cumulative_factors: BTreeMap::new(),
                     mutation: None,
                 },
                 fitness_score: 0.0,
@@ -2196,4 +4877,207 @@ mod tests {
 
         assert!(roulette_wheel_selection(&zeros, &mut rng).is_ok());
     }
+
+    #[test]
+    fn only_exhausted_budgets_drop_zero_valued_stimulus() {
+        let mut app = MorphogeneticApp::new(
+            vec![crate::cellular::SecurityCell::new("solo")],
+            StreamingPipeline::default(),
+            crate::config::TopologyConfig::default(),
+        );
+        let mut budget = StimulusBudget::new(HashMap::from([("activator".to_string(), 0.5)]));
+        inject_within_budget(&mut app, &mut budget, Signal::new("activator", 0.5));
+        inject_within_budget(&mut app, &mut budget, Signal::new("activator", 0.5));
+        inject_within_budget(&mut app, &mut budget, Signal::new("heartbeat", 0.0));
+        app.step(0, 0.0);
+
+        let metrics = app.telemetry_mut().take_step_metrics().remove(0);
+        assert_eq!(metrics.stimulus_by_topic.get("activator"), Some(&0.5));
+        assert_eq!(metrics.stimulus_by_topic.get("heartbeat"), Some(&0.0));
+    }
+
+    #[test]
+    fn preloaded_activator_drives_the_first_step() {
+        let first_step = |extra: &str| {
+            let scenario = crate::config::load_from_reader(
+                format!(
+                    "initial_cell_count: 3\nsimulation_steps: 1\nthreat_profile:\n  background_threat: 0.0\n  spike_threshold: 0.5\n{extra}"
+                )
+                .as_bytes(),
+            )
+            .expect("scenario parses");
+            let mut app = MorphogeneticApp::new(
+                scenario.build_population(),
+                StreamingPipeline::default(),
+                scenario.topology.clone(),
+            );
+            run_scenario(&mut app, &scenario, None)
+                .expect("scenario runs")
+                .remove(0)
+        };
+
+        let quiet = first_step("");
+        assert_eq!(quiet.replications, 0);
+        let preloaded = first_step("initial_signals:\n  - topic: activator\n    value: 1.0\n");
+        assert_eq!(preloaded.replications, 3, "every cell hears the activator");
+    }
+
+    #[test]
+    fn missing_scenario_reports_scenario_load_with_path() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let missing = dir.path().join("no-such-scenario.yaml");
+        let candidate = AttackCandidate {
+            id: "missing".into(),
+            scenario_ref: missing.to_string_lossy().to_string(),
+            stimulus_ref: None,
+            generation: 0,
+            parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+            mutation: None,
+        };
+
+        match apply_mutation_and_generate_files(
+            &candidate,
+            dir.path(),
+            ArtifactLayout::Nested,
+            StimulusMissingPolicy::Error,
+        ) {
+            Err(HarnessError::ScenarioLoad {
+                path,
+                source: ConfigError::Io(_),
+            }) => assert_eq!(path, missing),
+            other => panic!("expected ScenarioLoad, got {other:?}"),
+        }
+        assert!(matches!(
+            load_stimulus(dir.path().join("absent.jsonl")),
+            Err(HarnessError::StimulusLoad { .. })
+        ));
+    }
+
+    #[test]
+    fn skip_policy_runs_candidate_with_missing_stimulus_unstimulated() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let scenario_path = dir.path().join("scenario.yaml");
+        fs::write(
+            &scenario_path,
+            "scenario_name: bogus-stimulus\ninitial_cell_count: 2\nsimulation_steps: 3\n",
+        )
+        .expect("write scenario");
+        let candidate = AttackCandidate {
+            id: "bogus".into(),
+            scenario_ref: scenario_path.to_string_lossy().to_string(),
+            stimulus_ref: Some(dir.path().join("gone.jsonl").to_string_lossy().to_string()),
+            generation: 0,
+            parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+            mutation: None,
+        };
+        let generate = |policy| {
+            apply_mutation_and_generate_files(
+                &candidate,
+                dir.path(),
+                ArtifactLayout::Nested,
+                policy,
+            )
+        };
+
+        assert!(matches!(
+            generate(StimulusMissingPolicy::Error),
+            Err(HarnessError::StimulusLoad { .. })
+        ));
+        for policy in [StimulusMissingPolicy::Skip, StimulusMissingPolicy::Warn] {
+            let (mutated_scenario, stimulus) =
+                generate(policy).expect("missing stimulus tolerated");
+            assert_eq!(stimulus, None);
+
+            let scenario = load_scenario(&mutated_scenario).expect("mutated scenario");
+            let mut app = MorphogeneticApp::new(
+                scenario.build_population(),
+                StreamingPipeline::default(),
+                scenario.topology.clone(),
+            );
+            let steps = run_scenario(&mut app, &scenario, None).expect("scenario runs");
+            assert_eq!(steps.len(), 3);
+            assert!(steps.iter().all(|step| step.stimulus_total == 0.0));
+        }
+    }
+
+    #[test]
+    fn stats_and_metrics_serialize_maps_in_key_order() {
+        let topics = ["reproducer", "activator", "inhibitor", "consensus:vote"];
+        let step_with = |order: &[&str]| StepMetrics {
+            cell_count: 3,
+            replications: 1,
+            signals_total: order.len() as u32,
+            stimulus_total: 0.4,
+            signals_by_topic: order.iter().map(|topic| (topic.to_string(), 1)).collect(),
+            stimulus_by_topic: order.iter().map(|topic| (topic.to_string(), 0.1)).collect(),
+            ..step_metrics(0, 0.5)
+        };
+        let reversed: Vec<&str> = topics.iter().rev().copied().collect();
+        let forward = vec![step_with(&topics)];
+        let backward = vec![step_with(&reversed)];
+
+        let json = |steps: &[StepMetrics]| {
+            let stats = build_statistics_from_steps(steps).expect("stats");
+            serde_json::to_string(&stats).expect("serialize")
+        };
+        assert_eq!(json(&forward), json(&backward));
+        assert!(json(&forward).contains(
+            r#""signals_by_topic":{"activator":1,"consensus:vote":1,"inhibitor":1,"reproducer":1}"#
+        ));
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let csv = |name: &str, steps: &[StepMetrics]| {
+            let path = dir.path().join(name);
+            write_step_metrics_csv(&path, steps).expect("csv written");
+            fs::read(path).expect("csv read")
+        };
+        assert_eq!(csv("forward.csv", &forward), csv("backward.csv", &backward));
+    }
+
+    #[test]
+    fn candidate_at_max_mutation_depth_queues_no_follow_up() {
+        let mut harness = AdversarialHarness::new(EvolutionConfig {
+            max_mutation_depth: Some(2),
+            ..EvolutionConfig::default_smoke_test()
+        });
+        let candidate = |generation: u32| AttackCandidate {
+            id: format!("depth-{generation}"),
+            scenario_ref: "docs/examples/intense-defense.yaml".into(),
+            stimulus_ref: None,
+            generation,
+            parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: BTreeMap::new(),
+            mutation: None,
+        };
+        let steps = || {
+            vec![StepMetrics {
+                signals_total: 1,
+                stimulus_total: 0.1,
+                signals_by_topic: BTreeMap::from([("activator".into(), 1)]),
+                stimulus_by_topic: BTreeMap::from([("activator".into(), 0.1)]),
+                ..step_metrics(0, 0.2)
+            }]
+        };
+
+        let (_, follow_up, analysis) = harness
+            .evaluate_steps(candidate(1), steps())
+            .expect("evaluation succeeds");
+        assert!(analysis.recommended_mutation.is_some());
+        assert_eq!(follow_up.expect("below the limit").generation, 2);
+        assert_eq!(harness.backlog_len(), 1);
+
+        let (outcome, follow_up, analysis) = harness
+            .evaluate_steps(candidate(2), steps())
+            .expect("evaluation succeeds");
+        assert!(analysis.recommended_mutation.is_some());
+        assert!(follow_up.is_none());
+        assert_eq!(harness.backlog_len(), 1);
+        assert_eq!(outcome.candidate.id, "depth-2");
+        assert_eq!(harness.archive.len(), 2);
+    }
 }
@@ -1,5 +1,6 @@
 use morphogenetic_security::adversarial::{
-    AdversarialHarness, AttackCandidate, EvolutionConfig, HarnessAnalysis,
+    AdversarialHarness, AttackCandidate, EvolutionConfig, HarnessAnalysis, RunMetadata,
+    load_scenario,
 };
 use serde_json::json;
 use std::env;
@@ -8,8 +9,9 @@ use std::path::PathBuf;
 use std::process;
 
 fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
     if let Err(err) = run() {
-        eprintln!("{err}");
+        log::error!("{err}");
         process::exit(1);
     }
 }
@@ -30,36 +32,32 @@ fn run() -> Result<(), String> {
     if let Some(max_generations) = args.max_generations {
         config.max_generations = max_generations;
     }
-    // if let Some(elite_size) = args.elite_size {
-    //     config.elite_size = elite_size;
-    // }
-    // if let Some(exploration_generations) = args.exploration_generations {
-    //     config.exploration_generations = exploration_generations;
-    // }
+    if let Some(elite_size) = args.elite_size {
+        config.elite_size = elite_size;
+    }
 
     let mut harness = if let Some(state_path) = args.state_path.as_ref() {
         if state_path.exists() {
-            let harness = AdversarialHarness::load_state(state_path).map_err(|err| {
+            let harness = AdversarialHarness::load_state_auto(state_path).map_err(|err| {
                 format!(
                     "Failed to load harness state `{}`: {err}",
                     state_path.display()
                 )
             })?;
-            if args.batch_size.is_some() || args.max_generations.is_some()
-            // || args.elite_size.is_some()
-            // || args.exploration_generations.is_some()
+            if args.batch_size.is_some()
+                || args.max_generations.is_some()
+                || args.elite_size.is_some()
             {
-                println!("[info] Loaded existing harness; configuration overrides ignored.");
+                log::info!("Loaded existing harness; configuration overrides ignored.");
             }
             harness
         } else {
-            println!(
-                "[info] Initialising new harness state at {} (batch_size={}, max_generations={})",
+            log::info!(
+                "Initialising new harness state at {} (batch_size={}, max_generations={}, elite_size={})",
                 state_path.display(),
                 config.batch_size,
                 config.max_generations,
-                // config.elite_size,
-                // config.exploration_generations
+                config.elite_size,
             );
             AdversarialHarness::new(config.clone())
         }
@@ -76,8 +74,9 @@ fn run() -> Result<(), String> {
             .map(|path| path.to_string_lossy().to_string()),
         generation: args.generation,
         parent_id: None,
+        origin_scenario: None,
+        cumulative_factors: Default::default(),
         mutation: None,
-        // refinement_active_for: 0,
     };
 
     let (outcome, maybe_mutation, analysis) =
@@ -87,19 +86,34 @@ fn run() -> Result<(), String> {
     let backlog_len = harness.backlog_len();
 
     print_summary(&analysis, &outcome, maybe_mutation.as_ref(), backlog_len);
+    if args.explain {
+        print_explanation(&analysis);
+    }
 
     if let Some(path) = args.emit_json {
-        write_json(&analysis, &outcome, maybe_mutation.as_ref(), path)?;
+        let metadata = RunMetadata::new(
+            args.scenario_ref.clone(),
+            outcome.candidate.stimulus_ref.clone(),
+            scenario_seed(&args.scenario_ref),
+            harness.config(),
+        );
+        write_json(
+            &analysis,
+            &outcome,
+            maybe_mutation.as_ref(),
+            &metadata,
+            path,
+        )?;
     }
 
     if let Some(state_path) = &args.state_path {
-        harness.save_state(state_path).map_err(|err| {
+        harness.save_state_auto(state_path).map_err(|err| {
             format!(
                 "Failed to persist harness state `{}`: {err}",
                 state_path.display()
             )
         })?;
-        println!(
+        log::info!(
             "Harness state saved to {} (backlog: {})",
             state_path.display(),
             harness.backlog_len()
@@ -109,6 +123,18 @@ fn run() -> Result<(), String> {
     Ok(())
 }
 
+/// The fault RNG seed the scenario behind the metrics ran with, or `None`
+/// when the scenario can't be loaded.
+fn scenario_seed(scenario_ref: &str) -> Option<u64> {
+    match load_scenario(scenario_ref) {
+        Ok(scenario) => Some(scenario.fault_seed.unwrap_or_default()),
+        Err(err) => {
+            log::warn!("Cannot read seed from scenario `{scenario_ref}`: {err}");
+            None
+        }
+    }
+}
+
 fn print_summary(
     analysis: &HarnessAnalysis,
     outcome: &morphogenetic_security::adversarial::AttackOutcome,
@@ -126,9 +152,10 @@ fn print_summary(
         analysis.breach_observed
     );
     println!(
-        "Steps: {} | avg threat {:.2} (max {:.2}) | replications {} | signals {} | stimulus {:.2}",
+        "Steps: {} | avg threat {:.2} (p90 {:.2}, max {:.2}) | replications {} | signals {} | stimulus {:.2}",
         stats.step_count,
         stats.avg_threat,
+        stats.p90_threat,
         stats.max_threat,
         stats.total_replications,
         stats.total_signals,
@@ -154,10 +181,24 @@ fn print_summary(
     println!("Harness backlog size after evaluation: {backlog_len}");
 }
 
+fn print_explanation(analysis: &HarnessAnalysis) {
+    let breakdown = &analysis.fitness_breakdown;
+    println!("=== Fitness Breakdown ===");
+    for (name, weight, value) in breakdown.components() {
+        println!(
+            "  {name:<12} {value:.3} x {weight:.1} = {:.3}",
+            value * weight
+        );
+    }
+    println!("  {:<12} {:.3}", "total", breakdown.total);
+    println!("Recommendation rule: {}", analysis.recommendation_reason);
+}
+
 fn write_json(
     analysis: &HarnessAnalysis,
     outcome: &morphogenetic_security::adversarial::AttackOutcome,
     maybe_mutation: Option<&AttackCandidate>,
+    metadata: &RunMetadata,
     path: PathBuf,
 ) -> Result<(), String> {
     let stats = &analysis.statistics;
@@ -175,6 +216,9 @@ fn write_json(
             "step_count": stats.step_count,
             "avg_threat": stats.avg_threat,
             "max_threat": stats.max_threat,
+            "p50_threat": stats.p50_threat,
+            "p90_threat": stats.p90_threat,
+            "p99_threat": stats.p99_threat,
             "avg_cell_count": stats.avg_cell_count,
             "min_cell_count": stats.min_cell_count,
             "max_cell_count": stats.max_cell_count,
@@ -186,7 +230,9 @@ fn write_json(
             "lineage_by_type": stats.lineage_by_type,
             "stimuli_by_topic": stats.stimuli_by_topic,
         },
+        "fitness_breakdown": analysis.fitness_breakdown,
         "recommended_mutation": analysis.recommended_mutation,
+        "recommendation_reason": analysis.recommendation_reason,
         "next_candidate": maybe_mutation.map(|candidate| {
             json!({
                 "id": candidate.id,
@@ -196,6 +242,7 @@ fn write_json(
                 "mutation": candidate.mutation,
             })
         }),
+        "run_metadata": metadata,
     });
 
     let parent = path.parent().map(PathBuf::from);
@@ -211,7 +258,7 @@ fn write_json(
         serde_json::to_string_pretty(&payload).map_err(|err| err.to_string())?,
     )
     .map_err(|err| format!("Failed to write JSON output: {err}"))?;
-    println!("Wrote harness evaluation JSON to {}", path.display());
+    log::info!("Wrote harness evaluation JSON to {}", path.display());
     Ok(())
 }
 
@@ -229,8 +276,10 @@ fn parse_args() -> Result<CliArgs, String> {
     let mut stimulus_path: Option<PathBuf> = None;
     let mut batch_size: Option<usize> = None;
     let mut max_generations: Option<u32> = None;
+    let mut elite_size: Option<usize> = None;
     let mut emit_json: Option<PathBuf> = None;
     let mut state_path: Option<PathBuf> = None;
+    let mut explain = false;
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
@@ -286,6 +335,16 @@ fn parse_args() -> Result<CliArgs, String> {
                         .map_err(|_| "Max generations must be a positive integer".to_string())?,
                 );
             }
+            "--elite-size" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --elite-size".to_string())?;
+                elite_size = Some(
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| "Elite size must be a non-negative integer".to_string())?,
+                );
+            }
             "--emit-json" => {
                 emit_json =
                     Some(PathBuf::from(args.next().ok_or_else(|| {
@@ -298,6 +357,9 @@ fn parse_args() -> Result<CliArgs, String> {
                         .ok_or_else(|| "Missing value for --state".to_string())?,
                 ));
             }
+            "--explain" => {
+                explain = true;
+            }
             unknown => {
                 return Err(format!("Unknown argument `{unknown}`"));
             }
@@ -318,9 +380,11 @@ fn parse_args() -> Result<CliArgs, String> {
         metrics_path,
         batch_size,
         max_generations,
+        elite_size,
         emit_json,
         stimulus_path,
         state_path,
+        explain,
     })
 }
 
@@ -333,8 +397,10 @@ Options:
   --stimulus <path>        Associate a stimulus schedule with the candidate
   --batch-size <n>         Override harness batch size (default: 3)
   --max-generations <n>    Override harness archival depth (default: 10)
-  --state <path>           Load/save harness state for persistent backlogs
+  --elite-size <n>         Override how many top candidates each generation requeues (default: 0)
+  --state <path>           Load/save harness state for persistent backlogs (`.bin` = bincode)
   --emit-json <path>       Persist evaluation output as JSON
+  --explain                Print per-component fitness and the recommendation rule that fired
   --help                   Show this message"
     );
 }
@@ -348,5 +414,7 @@ struct CliArgs {
     state_path: Option<PathBuf>,
     batch_size: Option<usize>,
     max_generations: Option<u32>,
+    elite_size: Option<usize>,
     emit_json: Option<PathBuf>,
+    explain: bool,
 }
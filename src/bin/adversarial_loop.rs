@@ -1,29 +1,40 @@
 use morphogenetic_security::MorphogeneticApp;
 use morphogenetic_security::adversarial::{
-    AdversarialHarness, AttackCandidate, EvolutionConfig, ExecutionReport, HarnessError,
-    MutationStrategy, SelectionStrategy, StepMetrics,
+    AdversarialHarness, ArtifactLayout, AttackCandidate, EvolutionConfig, ExecutionReport,
+    HarnessError, MutationStrategy, SelectionStrategy, StimulusMissingPolicy,
+    load_candidate_stimulus, load_scenario, run_scenario,
 };
-use morphogenetic_security::cellular::SecurityCell;
-use morphogenetic_security::config;
-use morphogenetic_security::signaling::Signal; // Import Signal
 use morphogenetic_security::stimulus::StimulusSchedule;
-use morphogenetic_security::telemetry::{TelemetryEvent, TelemetryPipeline};
-use std::collections::HashMap;
+use morphogenetic_security::telemetry::StreamingPipeline;
+use morphogenetic_security::util::write_atomic;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process;
+use std::time::{Duration, Instant};
 
 fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
     if let Err(err) = run() {
-        eprintln!("{err}");
+        log::error!("{err}");
         process::exit(1);
     }
 }
 
 fn run() -> Result<(), String> {
     let args = parse_args()?;
+    if args.validate_state {
+        return validate_state(&args.state_path);
+    }
     let mut harness = initialise_harness(&args)?;
+    if args.print_config {
+        println!("{}", harness.config().to_json_pretty());
+        return Ok(());
+    }
+
+    if let Some(candidate_id) = &args.replay {
+        return replay_candidate(&harness, candidate_id, &args);
+    }
 
     if !args.seeds.is_empty() {
         for seed in &args.seeds {
@@ -36,44 +47,73 @@ fn run() -> Result<(), String> {
                     .map(|path| path.to_string_lossy().to_string()),
                 generation: 0,
                 parent_id: None,
+                origin_scenario: None,
+                cumulative_factors: Default::default(),
                 mutation: None,
             });
         }
-        println!(
-            "[info] Enqueued {} seed candidate(s); backlog now {}",
+        log::info!(
+            "Enqueued {} seed candidate(s); backlog now {}",
             args.seeds.len(),
             harness.backlog_len()
         );
     }
 
-    if harness.backlog_len() == 0 {
-        println!("[warn] Harness backlog is empty; nothing to execute.");
+    let Some(next) = harness.peek_next() else {
+        log::warn!("Harness backlog is empty; nothing to execute.");
         persist_harness(&harness, &args.state_path)?;
         return Ok(());
-    }
+    };
+    log::info!(
+        "Next candidate `{}`; {} pending",
+        next.id,
+        harness.backlog_len()
+    );
+    print_backlog(&harness);
 
     let artifact_root = args.artifact_dir.clone();
+    let layout = harness.config().artifact_layout;
+    let stimulus_missing = harness.config().stimulus_missing_policy;
     let stimulus_path = args.stimulus_path.clone();
+    let allow_long_run = args.allow_long_run;
+    let started = Instant::now();
+    let max_wall_time = args.max_wall_time;
+    let over_budget = move |completed: usize| {
+        let Some(budget) = max_wall_time else {
+            return false;
+        };
+        let elapsed = started.elapsed();
+        if elapsed < budget {
+            return false;
+        }
+        log::info!(
+            "Wall-time budget of {:.1}s exhausted after {completed} generation(s) ({:.1}s elapsed).",
+            budget.as_secs_f64(),
+            elapsed.as_secs_f64()
+        );
+        true
+    };
 
     let evaluations = harness
-        .run_generations(args.generations, &artifact_root.clone(), move |candidate| {
-            let (mutated_scenario_path, mutated_stimulus_path) =
-                morphogenetic_security::adversarial::apply_mutation_and_generate_files(
+        .run_generations_until(
+            args.generations,
+            &artifact_root.clone(),
+            move |candidate| {
+                execute_candidate(
                     candidate,
                     &artifact_root,
-                )?;
-
-            let mut mutated_candidate = candidate.clone();
-            mutated_candidate.scenario_ref = mutated_scenario_path.to_string_lossy().to_string();
-            mutated_candidate.stimulus_ref =
-                mutated_stimulus_path.map(|p| p.to_string_lossy().to_string());
-
-            simulate_candidate(&mutated_candidate, &artifact_root, stimulus_path.as_deref())
-        })
+                    layout,
+                    stimulus_missing,
+                    stimulus_path.as_deref(),
+                    allow_long_run,
+                )
+            },
+            over_budget,
+        )
         .map_err(|err| format!("Harness execution failed: {err}"))?;
 
     if evaluations.is_empty() {
-        println!("[info] No candidates executed (limited by generations or backlog).");
+        log::info!("No candidates executed (limited by generations or backlog).");
     } else {
         for evaluation in &evaluations {
             let outcome = &evaluation.outcome;
@@ -114,23 +154,120 @@ fn run() -> Result<(), String> {
         }
     }
 
-    println!(
-        "[info] Harness backlog after loop: {} candidate(s)",
+    log::info!(
+        "Harness backlog after loop: {} candidate(s)",
         harness.backlog_len()
     );
 
     persist_harness(&harness, &args.state_path)?;
+    log::info!("Persisted harness state to {}", args.state_path.display());
+
+    Ok(())
+}
+
+/// Load `state_path` without running anything and print a summary of it.
+/// JSON states are loaded leniently so recoverable truncation is reported
+/// rather than fatal.
+fn validate_state(state_path: &Path) -> Result<(), String> {
+    let load_error =
+        |err: HarnessError| format!("Invalid harness state `{}`: {err}", state_path.display());
+    let (harness, dropped) =
+        AdversarialHarness::load_state_lenient_auto(state_path).map_err(load_error)?;
+    if dropped > 0 {
+        log::warn!(
+            "State `{}` is damaged; dropped {dropped} unrecoverable item(s)",
+            state_path.display()
+        );
+    }
+
+    let config = harness.config().to_json_pretty();
+    let archive = harness.archive();
+    let generation = archive
+        .iter()
+        .map(|outcome| &outcome.candidate)
+        .chain(harness.backlog())
+        .map(|candidate| candidate.generation)
+        .max()
+        .unwrap_or(0);
+    let fitness = archive.iter().map(|outcome| outcome.fitness_score);
+
+    println!("State `{}` loaded", state_path.display());
+    println!("Config: {config}");
+    println!("Backlog length: {}", harness.backlog_len());
+    println!("Archive size: {}", archive.len());
+    println!("Generation: {generation}");
+    match (
+        fitness.clone().max_by(f32::total_cmp),
+        fitness.min_by(f32::total_cmp),
+    ) {
+        (Some(best), Some(worst)) => {
+            println!("Best fitness: {best:.3}");
+            println!("Worst fitness: {worst:.3}");
+        }
+        _ => println!("Best fitness: n/a (archive empty)"),
+    }
+    println!("Dropped on load: {dropped}");
+    Ok(())
+}
+
+/// Re-run one archived candidate under `<artifact-dir>/replay` and write its
+/// fresh outcome next to the replay telemetry. The harness state is not saved.
+fn replay_candidate(
+    harness: &AdversarialHarness,
+    candidate_id: &str,
+    args: &CliArgs,
+) -> Result<(), String> {
+    let replay_root = args.artifact_dir.join("replay");
+    let stimulus_path = args.stimulus_path.as_deref();
+    let layout = harness.config().artifact_layout;
+    let stimulus_missing = harness.config().stimulus_missing_policy;
+    let evaluation = harness
+        .replay(candidate_id, |candidate| {
+            execute_candidate(
+                candidate,
+                &replay_root,
+                layout,
+                stimulus_missing,
+                stimulus_path,
+                args.allow_long_run,
+            )
+        })
+        .map_err(|err| {
+            format!(
+                "Replay of `{candidate_id}` from `{}` failed: {err}",
+                args.state_path.display()
+            )
+        })?;
+
+    let outcome_path = layout
+        .candidate_dir(
+            &replay_root,
+            evaluation.candidate.generation,
+            &evaluation.candidate.id,
+        )
+        .join("outcome.json");
+    write_atomic(&outcome_path, |writer| {
+        serde_json::to_writer_pretty(writer, &evaluation.outcome).map_err(HarnessError::from)
+    })
+    .map_err(|err| format!("Failed to write replay outcome: {err}"))?;
+
     println!(
-        "[info] Persisted harness state to {}",
-        args.state_path.display()
+        "replayed `{}` gen {} => fitness {:.3} | breach={}",
+        evaluation.outcome.candidate.id,
+        evaluation.outcome.candidate.generation,
+        evaluation.outcome.fitness_score,
+        evaluation.outcome.breach_observed
     );
-
+    if let Some(telemetry_path) = &evaluation.report.telemetry_path {
+        println!("  telemetry captured at {}", telemetry_path.display());
+    }
+    println!("  outcome written to {}", outcome_path.display());
     Ok(())
 }
 
 fn initialise_harness(args: &CliArgs) -> Result<AdversarialHarness, String> {
     if args.state_path.exists() {
-        AdversarialHarness::load_state(&args.state_path)
+        AdversarialHarness::load_state_auto(&args.state_path)
             .map_err(|err| {
                 format!(
                     "Failed to load harness state `{}`: {err}",
@@ -141,8 +278,11 @@ fn initialise_harness(args: &CliArgs) -> Result<AdversarialHarness, String> {
                 if args.batch_size.is_some()
                     || args.max_generations.is_some()
                     || args.crossover_rate.is_some()
+                    || args.artifact_layout.is_some()
+                    || args.stimulus_missing_policy.is_some()
+                    || args.elite_size.is_some()
                 {
-                    println!("[info] Existing harness loaded; configuration overrides ignored.");
+                    log::info!("Existing harness loaded; configuration overrides ignored.");
                 }
             })
     } else {
@@ -159,28 +299,40 @@ fn initialise_harness(args: &CliArgs) -> Result<AdversarialHarness, String> {
         if let Some(retain) = args.retain_elite {
             config.retain_elite = retain;
         }
+        if let Some(layout) = args.artifact_layout {
+            config.artifact_layout = layout;
+        }
+        if let Some(policy) = args.stimulus_missing_policy {
+            config.stimulus_missing_policy = policy;
+        }
+        if let Some(elite_size) = args.elite_size {
+            config.elite_size = elite_size;
+        }
 
         if let Some(strategy) = &args.selection_strategy {
             match strategy.to_lowercase().as_str() {
                 "tournament" => {
-                    config.selection_strategy = SelectionStrategy::Tournament { size: 3 }
+                    config.selection_strategy = SelectionStrategy::Tournament {
+                        size: 3,
+                        with_replacement: false,
+                    }
                 }
                 "roulette" | "roulettewheel" => {
                     config.selection_strategy = SelectionStrategy::RouletteWheel
                 }
-                _ => println!("[warn] Unknown selection strategy `{strategy}`; using default."),
+                _ => log::warn!("Unknown selection strategy `{strategy}`; using default."),
             }
         }
 
         if let Some(strategy) = &args.mutation_strategy {
             match strategy.to_lowercase().as_str() {
                 "random" => config.mutation_strategy = MutationStrategy::Random,
-                _ => println!("[warn] Unknown mutation strategy `{strategy}`; using default."),
+                _ => log::warn!("Unknown mutation strategy `{strategy}`; using default."),
             }
         }
 
-        println!(
-            "[info] Initialising new harness with batch_size={} max_generations={} crossover_rate={} selection={:?} mutation={:?} retain_elite={}",
+        log::info!(
+            "Initialising new harness with batch_size={} max_generations={} crossover_rate={} selection={:?} mutation={:?} retain_elite={}",
             config.batch_size,
             config.max_generations,
             config.crossover_rate,
@@ -194,53 +346,91 @@ fn initialise_harness(args: &CliArgs) -> Result<AdversarialHarness, String> {
 
 fn persist_harness(harness: &AdversarialHarness, path: &Path) -> Result<(), String> {
     harness
-        .save_state(path)
+        .save_state_auto(path)
         .map_err(|err| format!("Failed to persist harness state: {err}"))
 }
 
+/// Materialise `candidate`'s mutation into scenario/stimulus files and run it.
+fn execute_candidate(
+    candidate: &AttackCandidate,
+    artifact_root: &Path,
+    layout: ArtifactLayout,
+    stimulus_missing: StimulusMissingPolicy,
+    default_stimulus: Option<&Path>,
+    allow_long_run: bool,
+) -> Result<ExecutionReport, HarnessError> {
+    let (mutated_scenario_path, mutated_stimulus_path) =
+        morphogenetic_security::adversarial::apply_mutation_and_generate_files(
+            candidate,
+            artifact_root,
+            layout,
+            stimulus_missing,
+        )?;
+
+    let mut mutated_candidate = candidate.clone();
+    mutated_candidate.origin_scenario = Some(candidate.origin().to_string());
+    mutated_candidate.scenario_ref = mutated_scenario_path.to_string_lossy().to_string();
+    mutated_candidate.stimulus_ref = mutated_stimulus_path.map(|p| p.to_string_lossy().to_string());
+
+    simulate_candidate(
+        &mutated_candidate,
+        artifact_root,
+        layout,
+        stimulus_missing,
+        default_stimulus,
+        allow_long_run,
+    )
+}
+
+fn print_backlog(harness: &AdversarialHarness) {
+    println!("Backlog ({} pending):", harness.backlog_len());
+    for candidate in harness.backlog() {
+        let mutation = candidate
+            .mutation
+            .as_ref()
+            .map(|mutation| format!("{mutation:?}"))
+            .unwrap_or_else(|| "none".to_string());
+        println!(
+            "  `{}` gen {} | scenario {} | mutation {mutation}",
+            candidate.id, candidate.generation, candidate.scenario_ref
+        );
+    }
+}
+
 fn simulate_candidate(
     candidate: &AttackCandidate,
     artifact_root: &Path,
-    _default_stimulus: Option<&Path>,
+    layout: ArtifactLayout,
+    stimulus_missing: StimulusMissingPolicy,
+    default_stimulus: Option<&Path>,
+    allow_long_run: bool,
 ) -> Result<ExecutionReport, HarnessError> {
-    let run_dir = artifact_root
-        .join(format!("gen{:03}", candidate.generation))
-        .join(&candidate.id);
+    let run_dir = layout.candidate_dir(artifact_root, candidate.generation, &candidate.id);
     fs::create_dir_all(&run_dir)?;
 
     let telemetry_path = run_dir.join("telemetry.jsonl");
     let metrics_path = run_dir.join("step_metrics.csv");
 
-    let scenario_config =
-        config::load_from_path(PathBuf::from(&candidate.scenario_ref)).map_err(|err| {
-            HarnessError::Custom(format!(
-                "Failed to load scenario `{}`: {err}",
-                candidate.scenario_ref
-            ))
-        })?;
+    let scenario_config = load_scenario(&candidate.scenario_ref)?;
+    scenario_config.check_step_cap(allow_long_run)?;
 
     let mut stimulus_schedule: Option<StimulusSchedule> = None;
     let mut persisted_stimulus: Option<PathBuf> = None;
 
-    if let Some(path) = &candidate.stimulus_ref {
-        let stimulus_source_path = PathBuf::from(path);
-        if !stimulus_source_path.exists() {
-            return Err(HarnessError::Custom(format!(
-                "Stimulus schedule `{}` not found",
-                stimulus_source_path.display()
-            )));
-        }
+    // Fallback to default stimulus if the candidate has none.
+    let source = candidate
+        .stimulus_ref
+        .as_deref()
+        .map(Path::new)
+        .or(default_stimulus);
+    if let Some(path) = source
+        && let Some(schedule) = load_candidate_stimulus(path, stimulus_missing)?
+    {
+        // Persist as JSONL whatever the source format was.
         let destination = run_dir.join("stimulus.jsonl");
-        fs::copy(&stimulus_source_path, &destination)?;
+        schedule.save_to_path(&destination)?;
         persisted_stimulus = Some(destination);
-        stimulus_schedule =
-            Some(StimulusSchedule::load(&stimulus_source_path).map_err(HarnessError::Io)?);
-    } else if let Some(default_path) = _default_stimulus {
-        // Fallback to default stimulus if provided
-        let destination = run_dir.join("stimulus.jsonl");
-        fs::copy(default_path, &destination)?;
-        persisted_stimulus = Some(destination);
-        stimulus_schedule = Some(StimulusSchedule::load(default_path).map_err(HarnessError::Io)?);
+        stimulus_schedule = Some(schedule);
     }
 
     if let Some(_mutation) = &candidate.mutation {
@@ -249,152 +439,10 @@ fn simulate_candidate(
         // We explicitly ignore it here to satisfy the linter and prevent double application.
     }
 
-    let telemetry = TelemetryPipeline::with_file(&telemetry_path).map_err(HarnessError::Io)?;
-
-    let cell_count = std::cmp::max(1, scenario_config.initial_cell_count);
-    let mut cells = Vec::with_capacity(cell_count);
-    for idx in 0..cell_count {
-        let mut cell = SecurityCell::new(format!("seed-{idx}"));
-        cell.genome.reproduction_threshold = scenario_config.threat_profile.spike_threshold;
-        // Adjust reproduction energy cost or other parameter based on rate?
-        // High rate -> low cost or low threshold?
-        // Let's scale reproduction_energy_cost inversely to rate.
-        if scenario_config.cell_reproduction_rate > 0.0 {
-            cell.genome.reproduction_energy_cost /= scenario_config.cell_reproduction_rate;
-        }
-        cells.push(cell);
-    }
-
+    let telemetry = StreamingPipeline::with_file(&telemetry_path).map_err(HarnessError::Io)?;
+    let cells = scenario_config.build_population();
     let mut app = MorphogeneticApp::new(cells, telemetry, scenario_config.topology.clone());
-    let steps = std::cmp::max(1, scenario_config.simulation_steps);
-    let mut per_step: Vec<StepMetrics> = Vec::with_capacity(steps as usize);
-    let mut stimulus_ledger: HashMap<u32, HashMap<String, f32>> = HashMap::new();
-    let mut active_stimuli: Vec<morphogenetic_security::stimulus::StimulusCommand> = Vec::new();
-
-    for step in 0..steps {
-        let threat = scenario_config.threat_level_for_step(step);
-        if threat >= scenario_config.threat_profile.spike_threshold {
-            app.inject_signal(Signal {
-                topic: "activator".to_string(),
-                value: threat,
-                source: None,
-                target: None,
-                attestation: None,
-            });
-            // Record automatic spike in ledger
-            let entry = stimulus_ledger.entry(step).or_default();
-            *entry.entry("activator".to_string()).or_insert(0.0) += threat;
-        }
-
-        if let Some(schedule) = stimulus_schedule.as_mut() {
-            // Fetch new commands for this step
-            for command in schedule.take_for_step(step) {
-                active_stimuli.push(command);
-            }
-        }
-
-        // Inject all active stimuli and record in ledger
-        let step_stimulus_ledger = stimulus_ledger.entry(step).or_default();
-        for command in &active_stimuli {
-            app.inject_signal(Signal {
-                topic: command.topic.clone(),
-                value: command.value,
-                source: command.source.clone(),
-                target: command.target.clone(),
-                attestation: None,
-            });
-            *step_stimulus_ledger
-                .entry(command.topic.clone())
-                .or_insert(0.0) += command.value;
-        }
-
-        // Prune expired stimuli
-        active_stimuli.retain(|command| {
-            // Keep if not expired. Duration is 1-based (1 means only the start step).
-            step < command.step + command.duration - 1
-        });
-
-        let before = app.telemetry().events().len();
-        app.step(step, threat);
-        let events = app.telemetry().events();
-        let new_events = &events[before..];
-
-        let mut replications = 0u32;
-        let mut deaths = 0u32;
-        let mut signals_by_topic: HashMap<String, u32> = HashMap::new();
-        let mut lineage_by_lineage: HashMap<String, u32> = HashMap::new();
-        let mut summary_threat: Option<f32> = None;
-        let mut summary_cells: Option<u32> = None;
-        let mut summary_population_stats: Option<
-            morphogenetic_security::cellular::PopulationStats,
-        > = None;
-        let mut summary_topology_stats: Option<morphogenetic_security::telemetry::TopologyStats> =
-            None;
-
-        for snapshot in new_events {
-            match &snapshot.event {
-                TelemetryEvent::CellReplicated { .. } => {
-                    replications += 1;
-                }
-                TelemetryEvent::CellDied { .. } => {
-                    deaths += 1;
-                }
-                TelemetryEvent::SignalEmitted { topic, .. } => {
-                    *signals_by_topic.entry(topic.clone()).or_insert(0) += 1;
-                }
-                TelemetryEvent::LineageShift { lineage, .. } => {
-                    *lineage_by_lineage.entry(lineage.clone()).or_insert(0) += 1;
-                }
-                TelemetryEvent::StepSummary {
-                    threat_score,
-                    cell_count,
-                    population_stats,
-                    topology_stats,
-                    ..
-                } => {
-                    summary_threat = Some(*threat_score);
-                    summary_cells = Some(*cell_count as u32);
-                    summary_population_stats = population_stats.clone();
-                    summary_topology_stats = topology_stats.clone();
-                }
-                TelemetryEvent::Scenario { .. }
-                | TelemetryEvent::LinkAdded { .. }
-                | TelemetryEvent::LinkRemoved { .. }
-                | TelemetryEvent::AnomalyDetected { .. }
-                | TelemetryEvent::VoteCast { .. }
-                | TelemetryEvent::PeerQuarantined { .. }
-                | TelemetryEvent::TrustScoreUpdated { .. } => {}
-            }
-        }
-
-        let threat_score = summary_threat.ok_or_else(|| {
-            HarnessError::Custom(format!(
-                "Step summary missing for candidate `{}` step {}",
-                candidate.id, step
-            ))
-        })?;
-        let cell_count = summary_cells.unwrap_or(0);
-        let stimulus_by_topic = stimulus_ledger.remove(&step).unwrap_or_default();
-        let stimulus_total = stimulus_by_topic.values().copied().sum();
-        let signals_total = signals_by_topic.values().copied().sum();
-        let lineage_total = lineage_by_lineage.values().copied().sum();
-
-        per_step.push(StepMetrics {
-            step,
-            threat_score,
-            cell_count,
-            replications,
-            deaths,
-            signals_total,
-            lineage_shifts_total: lineage_total,
-            stimulus_total,
-            signals_by_topic,
-            lineage_shifts_by_lineage: lineage_by_lineage,
-            stimulus_by_topic,
-            population_stats: summary_population_stats,
-            topology_stats: summary_topology_stats,
-        });
-    }
+    let per_step = run_scenario(&mut app, &scenario_config, stimulus_schedule)?;
 
     morphogenetic_security::adversarial::write_step_metrics_csv(&metrics_path, &per_step)?;
 
@@ -441,6 +489,14 @@ fn parse_args() -> Result<CliArgs, String> {
     let mut selection_strategy: Option<String> = None;
     let mut mutation_strategy: Option<String> = None;
     let mut retain_elite: Option<bool> = None;
+    let mut artifact_layout: Option<ArtifactLayout> = None;
+    let mut stimulus_missing_policy: Option<StimulusMissingPolicy> = None;
+    let mut max_wall_time: Option<Duration> = None;
+    let mut replay: Option<String> = None;
+    let mut validate_state = false;
+    let mut allow_long_run = false;
+    let mut print_config = false;
+    let mut elite_size: Option<usize> = None;
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
@@ -528,6 +584,59 @@ fn parse_args() -> Result<CliArgs, String> {
                         .map_err(|_| "Retain elite must be 'true' or 'false'".to_string())?,
                 );
             }
+            "--artifact-layout" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --artifact-layout".to_string())?;
+                artifact_layout = Some(match value.to_lowercase().as_str() {
+                    "nested" => ArtifactLayout::Nested,
+                    "flat" => ArtifactLayout::Flat,
+                    _ => return Err(format!("Unknown artifact layout `{value}`")),
+                });
+            }
+            "--stimulus-missing" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --stimulus-missing".to_string())?;
+                stimulus_missing_policy = Some(match value.to_lowercase().as_str() {
+                    "error" => StimulusMissingPolicy::Error,
+                    "skip" => StimulusMissingPolicy::Skip,
+                    "warn" => StimulusMissingPolicy::Warn,
+                    _ => return Err(format!("Unknown stimulus-missing policy `{value}`")),
+                });
+            }
+            "--max-wall-time" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --max-wall-time".to_string())?;
+                let seconds = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+                    .ok_or_else(|| {
+                        "Max wall time must be a non-negative number of seconds".to_string()
+                    })?;
+                max_wall_time = Some(Duration::from_secs_f64(seconds));
+            }
+            "--replay" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --replay".to_string())?;
+                replay = Some(value);
+            }
+            "--validate-state" => validate_state = true,
+            "--allow-long-run" => allow_long_run = true,
+            "--print-config" => print_config = true,
+            "--elite-size" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --elite-size".to_string())?;
+                elite_size = Some(
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| "Elite size must be a non-negative integer".to_string())?,
+                );
+            }
             unknown => {
                 return Err(format!("Unknown argument `{unknown}`"));
             }
@@ -536,6 +645,9 @@ fn parse_args() -> Result<CliArgs, String> {
 
     let state_path =
         state_path.ok_or_else(|| "Missing required argument --state <path>".to_string())?;
+    if replay.is_some() && !seeds.is_empty() {
+        return Err("--replay cannot be combined with --seed".to_string());
+    }
 
     Ok(CliArgs {
         state_path,
@@ -549,6 +661,14 @@ fn parse_args() -> Result<CliArgs, String> {
         selection_strategy,
         mutation_strategy,
         retain_elite,
+        artifact_layout,
+        stimulus_missing_policy,
+        max_wall_time,
+        replay,
+        validate_state,
+        allow_long_run,
+        print_config,
+        elite_size,
     })
 }
 
@@ -556,6 +676,9 @@ fn print_usage() {
     println!(
         "Usage: cargo run --bin adversarial_loop -- --state <state.json> [options]
 
+State files ending in `.bin` use the compact bincode format (requires
+`--features bincode`); any other extension is stored as JSON.
+
 Options:
   --generations <n>        Number of loop iterations to execute (default: 1)
   --artifact-dir <path>    Directory for telemetry and metrics outputs (default: target/adversarial_runs)
@@ -567,6 +690,16 @@ Options:
   --selection-strategy <s> Selection strategy (tournament, roulette)
   --mutation-strategy <s>  Mutation strategy (random)
   --retain-elite <bool>    Whether to retain elite candidates (true, false)
+  --elite-size <n>         Requeue the top n archived candidates unchanged each generation
+  --artifact-layout <l>    Per-candidate directories: nested (gen000/<id>) or flat (gen000-<id>)
+  --stimulus-missing <p>   Missing stimulus files: error (default), skip, or warn
+  --max-wall-time <secs>   Stop between generations once this much time has elapsed
+  --replay <candidate_id>  Re-run an archived candidate once and write its outcome JSON
+                           under <artifact-dir>/replay (state is left unchanged)
+  --validate-state         Load the state, print a summary, and exit without running
+  --allow-long-run         Run scenarios whose simulation_steps exceed max_simulation_steps
+  --print-config           Print the effective config as JSON (after overrides) and exit;
+                           nothing is run or saved
   --help                   Show this message"
     );
 }
@@ -583,6 +716,14 @@ struct CliArgs {
     selection_strategy: Option<String>,
     mutation_strategy: Option<String>,
     retain_elite: Option<bool>,
+    artifact_layout: Option<ArtifactLayout>,
+    stimulus_missing_policy: Option<StimulusMissingPolicy>,
+    max_wall_time: Option<Duration>,
+    replay: Option<String>,
+    validate_state: bool,
+    allow_long_run: bool,
+    print_config: bool,
+    elite_size: Option<usize>,
 }
 
 struct SeedCandidate {
@@ -18,6 +18,7 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
     let dir = std::env::args()
         .nth(1)
         .map(PathBuf::from)
@@ -273,7 +274,7 @@ fn load_outcomes(dir: &Path) -> Result<Vec<OutcomeDisplay>, Box<dyn Error>> {
     for entry in entries {
         match parse_outcome(&entry) {
             Ok(outcome) => outcomes.push(outcome),
-            Err(err) => eprintln!("Failed to parse {}: {err}", entry.display()),
+            Err(err) => log::warn!("Failed to parse {}: {err}", entry.display()),
         }
     }
 
@@ -0,0 +1,312 @@
+use csv::WriterBuilder;
+use morphogenetic_security::MorphogeneticApp;
+use morphogenetic_security::adversarial::{
+    AdversarialHarness, AttackCandidate, AttackOutcome, EvolutionConfig, HarnessAnalysis,
+    HarnessError, load_scenario, load_stimulus, run_scenario,
+};
+use morphogenetic_security::telemetry::StreamingPipeline;
+use morphogenetic_security::util::write_atomic;
+use serde_json::json;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    if let Err(err) = run() {
+        log::error!("{err}");
+        process::exit(1);
+    }
+}
+
+/// One evaluated scenario from the sweep.
+struct SweepResult {
+    name: String,
+    path: PathBuf,
+    outcome: AttackOutcome,
+    analysis: HarnessAnalysis,
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    let scenarios = collect_scenarios(&args.inputs)?;
+    if scenarios.is_empty() {
+        return Err("No scenario files found".to_string());
+    }
+    fs::create_dir_all(&args.output_dir).map_err(|err| {
+        format!(
+            "Failed to create output directory `{}`: {err}",
+            args.output_dir.display()
+        )
+    })?;
+
+    let jobs = args.jobs.clamp(1, scenarios.len());
+    log::info!(
+        "Sweeping {} scenario(s) with {jobs} job(s)",
+        scenarios.len()
+    );
+
+    // Workers pull the next unclaimed scenario; results keep input order.
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<SweepResult, HarnessError>>>> =
+        Mutex::new((0..scenarios.len()).map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(path) = scenarios.get(index) else {
+                        break;
+                    };
+                    let result = evaluate_scenario(path, args.stimulus_path.as_deref());
+                    results.lock().expect("sweep results lock")[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    let mut rows = Vec::with_capacity(scenarios.len());
+    let mut failures = 0;
+    for (path, result) in scenarios.iter().zip(results.into_inner().expect("results")) {
+        match result.expect("every scenario is claimed by a worker") {
+            Ok(result) => {
+                write_outcome_json(&args.output_dir, &result)
+                    .map_err(|err| format!("Failed to write outcome JSON: {err}"))?;
+                rows.push(result);
+            }
+            Err(err) => {
+                log::error!("Scenario `{}` failed: {err}", path.display());
+                failures += 1;
+            }
+        }
+    }
+
+    let csv_path = args.output_dir.join("comparison.csv");
+    write_comparison_csv(&csv_path, &rows)
+        .map_err(|err| format!("Failed to write comparison CSV: {err}"))?;
+
+    println!(
+        "{:<24} {:>8} {:>7} {:>10}",
+        "scenario", "fitness", "breach", "avg_threat"
+    );
+    for row in &rows {
+        println!(
+            "{:<24} {:>8.3} {:>7} {:>10.3}",
+            row.name,
+            row.outcome.fitness_score,
+            row.outcome.breach_observed,
+            row.analysis.statistics.avg_threat
+        );
+    }
+    println!("Comparison written to {}", csv_path.display());
+
+    if failures > 0 {
+        return Err(format!("{failures} scenario(s) failed"));
+    }
+    Ok(())
+}
+
+/// Expand directories to the `.yaml`/`.yml` files they contain, sorted by
+/// name; plain file arguments are kept as given.
+fn collect_scenarios(inputs: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut scenarios = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            let mut found: Vec<PathBuf> = fs::read_dir(input)
+                .map_err(|err| format!("Failed to read `{}`: {err}", input.display()))?
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|ext| ext == "yaml" || ext == "yml")
+                })
+                .collect();
+            found.sort();
+            scenarios.extend(found);
+        } else {
+            scenarios.push(input.clone());
+        }
+    }
+    Ok(scenarios)
+}
+
+/// Run one scenario in-process and score it with a private harness.
+fn evaluate_scenario(path: &Path, stimulus: Option<&Path>) -> Result<SweepResult, HarnessError> {
+    let scenario = load_scenario(path)?;
+    let schedule = stimulus.map(load_stimulus).transpose()?;
+    let mut app = MorphogeneticApp::new(
+        scenario.build_population(),
+        StreamingPipeline::default(),
+        scenario.topology.clone(),
+    );
+    let steps = run_scenario(&mut app, &scenario, schedule)?;
+
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| scenario.scenario_name.clone());
+    let candidate = AttackCandidate {
+        id: format!("sweep-{name}"),
+        scenario_ref: path.to_string_lossy().to_string(),
+        stimulus_ref: stimulus.map(|path| path.to_string_lossy().to_string()),
+        generation: 0,
+        parent_id: None,
+        origin_scenario: None,
+        cumulative_factors: Default::default(),
+        mutation: None,
+    };
+    let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+    let (outcome, _follow_up, analysis) = harness.evaluate_steps(candidate, steps)?;
+    Ok(SweepResult {
+        name,
+        path: path.to_path_buf(),
+        outcome,
+        analysis,
+    })
+}
+
+/// Write `<name>_outcome.json` in the layout `pitch_tui` loads.
+fn write_outcome_json(output_dir: &Path, result: &SweepResult) -> Result<(), HarnessError> {
+    let stats = &result.analysis.statistics;
+    let payload = json!({
+        "outcome": {
+            "candidate_id": result.outcome.candidate.id,
+            "generation": result.outcome.candidate.generation,
+            "fitness_score": result.outcome.fitness_score,
+            "breach_observed": result.outcome.breach_observed,
+            "scenario_ref": result.outcome.candidate.scenario_ref,
+        },
+        "statistics": stats,
+        "fitness_breakdown": result.analysis.fitness_breakdown,
+        "recommended_mutation": result.analysis.recommended_mutation,
+        "recommendation_reason": result.analysis.recommendation_reason,
+    });
+    let path = output_dir.join(format!("{}_outcome.json", result.name));
+    write_atomic(path, |file| -> Result<(), HarnessError> {
+        serde_json::to_writer_pretty(file, &payload)?;
+        Ok(())
+    })
+}
+
+fn write_comparison_csv(path: &Path, rows: &[SweepResult]) -> Result<(), HarnessError> {
+    write_atomic(path, |file| -> Result<(), HarnessError> {
+        let mut writer = WriterBuilder::new().has_headers(true).from_writer(file);
+        writer.write_record([
+            "scenario",
+            "path",
+            "fitness",
+            "breach",
+            "step_count",
+            "avg_threat",
+            "p90_threat",
+            "max_threat",
+            "min_cell_count",
+            "max_cell_count",
+            "total_replications",
+            "total_signals",
+            "total_stimulus",
+        ])?;
+        for row in rows {
+            let stats = &row.analysis.statistics;
+            writer.write_record([
+                row.name.clone(),
+                row.path.to_string_lossy().to_string(),
+                format!("{:.6}", row.outcome.fitness_score),
+                row.outcome.breach_observed.to_string(),
+                stats.step_count.to_string(),
+                format!("{:.6}", stats.avg_threat),
+                format!("{:.6}", stats.p90_threat),
+                format!("{:.6}", stats.max_threat),
+                stats.min_cell_count.to_string(),
+                stats.max_cell_count.to_string(),
+                stats.total_replications.to_string(),
+                stats.total_signals.to_string(),
+                format!("{:.6}", stats.total_stimulus),
+            ])?;
+        }
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+fn parse_args() -> Result<CliArgs, String> {
+    let mut args = env::args().skip(1).peekable();
+    if matches!(args.peek(), Some(flag) if flag == "--help" || flag == "-h") {
+        print_usage();
+        process::exit(0);
+    }
+
+    let mut inputs: Vec<PathBuf> = Vec::new();
+    let mut stimulus_path: Option<PathBuf> = None;
+    let mut output_dir = PathBuf::from("target/sweep");
+    let mut jobs: usize = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stimulus" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --stimulus".to_string())?;
+                stimulus_path = Some(PathBuf::from(value));
+            }
+            "--output-dir" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --output-dir".to_string())?;
+                output_dir = PathBuf::from(value);
+            }
+            "--jobs" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --jobs".to_string())?;
+                jobs = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|jobs| *jobs > 0)
+                    .ok_or_else(|| "Jobs must be a positive integer".to_string())?;
+            }
+            _ if arg.starts_with("--") => {
+                return Err(format!("Unknown argument `{arg}`"));
+            }
+            positional => inputs.push(PathBuf::from(positional)),
+        }
+    }
+
+    if inputs.is_empty() {
+        return Err("Missing scenario files or directories".to_string());
+    }
+
+    Ok(CliArgs {
+        inputs,
+        stimulus_path,
+        output_dir,
+        jobs,
+    })
+}
+
+fn print_usage() {
+    println!(
+        "Usage: cargo run --bin scenario_sweep -- <scenario.yaml|dir>... [options]
+
+Runs every scenario in-process, writes one `<name>_outcome.json` per scenario
+(loadable by pitch_tui), and a `comparison.csv` with one row per scenario.
+Directories are expanded to the .yaml/.yml files they contain.
+
+Options:
+  --stimulus <path>     Stimulus schedule JSONL applied to every scenario
+  --jobs <n>            Scenarios to run in parallel (default: 1)
+  --output-dir <path>   Destination for outputs (default: target/sweep)
+  --help                Show this message"
+    );
+}
+
+struct CliArgs {
+    inputs: Vec<PathBuf>,
+    stimulus_path: Option<PathBuf>,
+    output_dir: PathBuf,
+    jobs: usize,
+}
@@ -0,0 +1,259 @@
+use csv::WriterBuilder;
+use morphogenetic_security::adversarial::{
+    AdversarialHarness, AttackCandidate, EvolutionConfig, HarnessError, load_scenario,
+    load_stimulus, run_scenario,
+};
+use morphogenetic_security::cellular::CellGenome;
+use morphogenetic_security::telemetry::StreamingPipeline;
+use morphogenetic_security::util::write_atomic;
+use morphogenetic_security::{MorphogeneticApp, ScenarioConfig};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    if let Err(err) = run() {
+        log::error!("{err}");
+        process::exit(1);
+    }
+}
+
+/// Fitness response to nudging one gene across the whole population.
+struct SensitivityRow {
+    gene: String,
+    fitness_minus: f32,
+    fitness_plus: f32,
+    /// Central-difference estimate of d(fitness)/d(gene).
+    sensitivity: f32,
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    let scenario = load_scenario(&args.scenario_path).map_err(|err| err.to_string())?;
+
+    let genes: Vec<String> = match &args.genes {
+        Some(genes) => genes.clone(),
+        None => CellGenome::GENE_NAMES
+            .iter()
+            .map(|gene| gene.to_string())
+            .collect(),
+    };
+    if let Some(unknown) = genes
+        .iter()
+        .find(|gene| !CellGenome::GENE_NAMES.contains(&gene.as_str()))
+    {
+        return Err(format!("Unknown gene `{unknown}`"));
+    }
+
+    let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+    let mut evaluate = |perturbation: Option<(&str, f32)>| {
+        evaluate_fitness(&mut harness, &scenario, &args, perturbation)
+            .map_err(|err| format!("Simulation failed: {err}"))
+    };
+
+    let baseline = evaluate(None)?;
+    log::info!(
+        "Baseline fitness {baseline:.4}; perturbing {} gene(s) by ±{}",
+        genes.len(),
+        args.delta
+    );
+
+    let mut rows = Vec::with_capacity(genes.len());
+    for gene in genes {
+        let fitness_minus = evaluate(Some((&gene, -args.delta)))?;
+        let fitness_plus = evaluate(Some((&gene, args.delta)))?;
+        rows.push(SensitivityRow {
+            sensitivity: (fitness_plus - fitness_minus) / (2.0 * args.delta),
+            gene,
+            fitness_minus,
+            fitness_plus,
+        });
+    }
+    rows.sort_by(|a, b| b.sensitivity.abs().total_cmp(&a.sensitivity.abs()));
+
+    write_rows(&args.output_path, &rows)
+        .map_err(|err| format!("Failed to write sensitivity CSV: {err}"))?;
+
+    println!("Baseline fitness {baseline:.4}. Most sensitive genes:");
+    for row in rows.iter().take(5) {
+        println!("  {:<34} {:+.4}", row.gene, row.sensitivity);
+    }
+    println!(
+        "Ranked sensitivities written to {}",
+        args.output_path.display()
+    );
+    Ok(())
+}
+
+/// Run the scenario once in-process, optionally shifting `gene` by `delta` in
+/// every initial cell, and score it with the harness.
+fn evaluate_fitness(
+    harness: &mut AdversarialHarness,
+    scenario: &ScenarioConfig,
+    args: &CliArgs,
+    perturbation: Option<(&str, f32)>,
+) -> Result<f32, HarnessError> {
+    let mut cells = scenario.build_population();
+    if let Some((gene, delta)) = perturbation {
+        for cell in &mut cells {
+            if let Some(value) = cell.genome.gene_mut(gene) {
+                *value += delta;
+            }
+        }
+    }
+
+    let schedule = args.stimulus_path.as_ref().map(load_stimulus).transpose()?;
+    let mut app = MorphogeneticApp::new(
+        cells,
+        StreamingPipeline::default(),
+        scenario.topology.clone(),
+    );
+    let steps = run_scenario(&mut app, scenario, schedule)?;
+
+    let candidate = AttackCandidate {
+        id: match perturbation {
+            Some((gene, delta)) => format!("sensitivity-{gene}{delta:+}"),
+            None => "sensitivity-baseline".to_string(),
+        },
+        scenario_ref: args.scenario_path.to_string_lossy().to_string(),
+        stimulus_ref: args
+            .stimulus_path
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
+        generation: 0,
+        parent_id: None,
+        origin_scenario: None,
+        cumulative_factors: Default::default(),
+        mutation: None,
+    };
+    let (outcome, _follow_up, _analysis) = harness.evaluate_steps(candidate, steps)?;
+    Ok(outcome.fitness_score)
+}
+
+fn write_rows(path: &Path, rows: &[SensitivityRow]) -> Result<(), HarnessError> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(path, |file| -> Result<(), HarnessError> {
+        let mut writer = WriterBuilder::new().has_headers(true).from_writer(file);
+        writer.write_record([
+            "rank",
+            "gene",
+            "fitness_minus",
+            "fitness_plus",
+            "sensitivity",
+        ])?;
+        for (index, row) in rows.iter().enumerate() {
+            writer.write_record([
+                (index + 1).to_string(),
+                row.gene.clone(),
+                format!("{:.6}", row.fitness_minus),
+                format!("{:.6}", row.fitness_plus),
+                format!("{:.6}", row.sensitivity),
+            ])?;
+        }
+        writer.flush()?;
+        Ok(())
+    })
+}
+
+fn parse_args() -> Result<CliArgs, String> {
+    let mut args = env::args().skip(1).peekable();
+    if matches!(args.peek(), Some(flag) if flag == "--help" || flag == "-h") {
+        print_usage();
+        process::exit(0);
+    }
+
+    let mut scenario_path: Option<PathBuf> = None;
+    let mut stimulus_path: Option<PathBuf> = None;
+    let mut output_path = PathBuf::from("target/sensitivity.csv");
+    let mut delta: f32 = 0.1;
+    let mut genes: Option<Vec<String>> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stimulus" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --stimulus".to_string())?;
+                stimulus_path = Some(PathBuf::from(value));
+            }
+            "--output" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --output".to_string())?;
+                output_path = PathBuf::from(value);
+            }
+            "--delta" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --delta".to_string())?;
+                delta = value
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|delta| delta.is_finite() && *delta > 0.0)
+                    .ok_or_else(|| "Delta must be a positive number".to_string())?;
+            }
+            "--genes" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --genes".to_string())?;
+                genes = Some(
+                    value
+                        .split(',')
+                        .map(|gene| gene.trim().to_string())
+                        .collect(),
+                );
+            }
+            _ if arg.starts_with("--") => {
+                return Err(format!("Unknown argument `{arg}`"));
+            }
+            positional => {
+                if scenario_path.is_none() {
+                    scenario_path = Some(PathBuf::from(positional));
+                } else {
+                    return Err(format!("Unexpected positional argument `{positional}`"));
+                }
+            }
+        }
+    }
+
+    let scenario_path =
+        scenario_path.ok_or_else(|| "Missing required scenario path".to_string())?;
+
+    Ok(CliArgs {
+        scenario_path,
+        stimulus_path,
+        output_path,
+        delta,
+        genes,
+    })
+}
+
+fn print_usage() {
+    println!(
+        "Usage: cargo run --bin sensitivity -- <scenario.yaml> [options]
+
+Perturbs each genome field by ±delta across the initial population, re-runs
+the scenario, and ranks fields by the resulting fitness gradient.
+
+Options:
+  --stimulus <path>   Stimulus schedule JSONL applied to every run
+  --delta <f32>       Perturbation size per gene (default: 0.1)
+  --genes <a,b,...>   Only perturb these genes (default: all)
+  --output <path>     Ranked CSV destination (default: target/sensitivity.csv)
+  --help              Show this message"
+    );
+}
+
+struct CliArgs {
+    scenario_path: PathBuf,
+    stimulus_path: Option<PathBuf>,
+    output_path: PathBuf,
+    delta: f32,
+    genes: Option<Vec<String>>,
+}
@@ -0,0 +1,118 @@
+use morphogenetic_security::ScenarioConfig;
+use morphogenetic_security::config;
+use morphogenetic_security::debugger::{DebugSession, StepReport, StopReason};
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const HELP: &str = "\
+Commands:
+  <enter> | s        execute the next step
+  r <N>              run until step N has executed
+  c                  run to the end of the scenario
+  b <EventKind>      break after any step recording that event (e.g. AnomalyDetected)
+  b                  clear the breakpoint
+  h                  show this help
+  q                  quit";
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    if let Err(err) = run() {
+        log::error!("{err}");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let config = match env::args().nth(1) {
+        Some(path) => config::load_from_path(&path).map_err(|err| err.to_string())?,
+        None => ScenarioConfig::default(),
+    };
+    println!(
+        "Debugging scenario `{}` ({} step(s)).",
+        config.scenario_name,
+        config.simulation_steps.max(1)
+    );
+    println!("{HELP}");
+
+    let mut session = DebugSession::new(config);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("[step {}/{}]> ", session.next_step(), session.total_steps());
+        io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line.map_err(|err| err.to_string())?;
+        let mut parts = line.split_whitespace();
+
+        match (parts.next(), parts.next()) {
+            (None | Some("s"), _) => match session.step() {
+                Some(report) => print_report(&report),
+                None => println!("Scenario finished."),
+            },
+            (Some("r"), Some(target)) => {
+                let Ok(target) = target.parse::<u32>() else {
+                    println!("Invalid step `{target}`; expected unsigned integer");
+                    continue;
+                };
+                let (report, reason) = session.run_to(target);
+                finish_run(report, reason);
+            }
+            (Some("c"), _) => {
+                let (report, reason) = session.run_to(u32::MAX);
+                finish_run(report, reason);
+            }
+            (Some("b"), kind) => {
+                session.set_breakpoint(kind.map(str::to_string));
+                match session.breakpoint() {
+                    Some(kind) => println!("Breaking on `{kind}`."),
+                    None => println!("Breakpoint cleared."),
+                }
+            }
+            (Some("h"), _) => println!("{HELP}"),
+            (Some("q"), _) => break,
+            (Some(other), _) => println!("Unknown command `{other}`; type h for help"),
+        }
+    }
+
+    Ok(())
+}
+
+fn finish_run(report: Option<StepReport>, reason: StopReason) {
+    if let Some(report) = &report {
+        print_report(report);
+    }
+    match reason {
+        StopReason::ReachedStep => {}
+        StopReason::Breakpoint(kind) => println!("Breakpoint hit: `{kind}`."),
+        StopReason::Finished => println!("Scenario finished."),
+    }
+}
+
+fn print_report(report: &StepReport) {
+    println!("== step {} (threat {:.3}) ==", report.step, report.threat);
+    for cell in &report.cells {
+        println!(
+            "  {:<24} {:<20} energy {:.3} stress {:.3}",
+            cell.id,
+            format!("{:?}", cell.lineage),
+            cell.energy,
+            cell.stress_level
+        );
+    }
+    let actions: Vec<_> = report
+        .events
+        .iter()
+        .filter(|event| !matches!(event.kind(), "StepSummary" | "Scenario"))
+        .collect();
+    if actions.is_empty() {
+        println!("  (no actions)");
+    }
+    for event in actions {
+        println!("  {event:?}");
+    }
+}
@@ -4,8 +4,9 @@ use std::path::PathBuf;
 use std::process;
 
 fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
     if let Err(err) = run() {
-        eprintln!("{err}");
+        log::error!("{err}");
         process::exit(1);
     }
 }
@@ -14,7 +15,7 @@ fn run() -> Result<(), String> {
     let mut args = env::args().skip(1);
     let file_path = args
         .next()
-        .ok_or_else(|| "Usage: stimulus <file> <topic> <value> <step>".to_string())?;
+        .ok_or_else(|| "Usage: stimulus <file> <topic> <value> <step> [target]".to_string())?;
     let topic = args
         .next()
         .ok_or_else(|| "Missing <topic> argument".to_string())?;
@@ -24,6 +25,7 @@ fn run() -> Result<(), String> {
     let step_str = args
         .next()
         .ok_or_else(|| "Missing <step> argument".to_string())?;
+    let target = args.next();
 
     if args.next().is_some() {
         return Err("Too many arguments supplied".to_string());
@@ -40,7 +42,7 @@ fn run() -> Result<(), String> {
         step,
         topic,
         value,
-        target: None,
+        target,
         source: None,
         duration: 1,
     };
@@ -48,8 +50,11 @@ fn run() -> Result<(), String> {
     append_command(PathBuf::from(file_path), &command)
         .map_err(|err| format!("Failed to append stimulus command: {err}"))?;
     println!(
-        "Appended stimulus command: step={}, topic={}, value={}",
-        command.step, command.topic, command.value
+        "Appended stimulus command: step={}, topic={}, value={}, target={}",
+        command.step,
+        command.topic,
+        command.value,
+        command.target.as_deref().unwrap_or("all")
     );
     Ok(())
 }
@@ -0,0 +1,113 @@
+use morphogenetic_security::adversarial::{StepMetrics, load_stimulus, write_step_metrics_csv};
+use morphogenetic_security::telemetry::read_jsonl;
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    if let Err(err) = run() {
+        log::error!("{err}");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    let events = read_jsonl(&args.telemetry_path).map_err(|err| {
+        format!(
+            "Failed to read telemetry `{}`: {err}",
+            args.telemetry_path.display()
+        )
+    })?;
+    let mut steps = StepMetrics::from_telemetry(&events);
+
+    // Telemetry does not carry injected stimulus, so fold the recorded
+    // schedule back into the matching step rows.
+    if let Some(path) = &args.stimulus_path {
+        let mut schedule = load_stimulus(path).map_err(|err| err.to_string())?;
+        for step in &mut steps {
+            for command in schedule.take_for_step(step.step) {
+                *step.stimulus_by_topic.entry(command.topic).or_default() += command.value;
+                step.stimulus_total += command.value;
+            }
+        }
+    }
+
+    write_step_metrics_csv(&args.output_path, &steps)
+        .map_err(|err| format!("Failed to write metrics CSV: {err}"))?;
+    println!(
+        "Wrote {} step row(s) to {}",
+        steps.len(),
+        args.output_path.display()
+    );
+    Ok(())
+}
+
+fn parse_args() -> Result<CliArgs, String> {
+    let mut args = env::args().skip(1).peekable();
+    if matches!(args.peek(), Some(flag) if flag == "--help" || flag == "-h") {
+        print_usage();
+        process::exit(0);
+    }
+
+    let mut telemetry_path: Option<PathBuf> = None;
+    let mut stimulus_path: Option<PathBuf> = None;
+    let mut output_path = PathBuf::from("target/metrics.csv");
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stimulus" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --stimulus".to_string())?;
+                stimulus_path = Some(PathBuf::from(value));
+            }
+            "--output" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --output".to_string())?;
+                output_path = PathBuf::from(value);
+            }
+            _ if arg.starts_with("--") => {
+                return Err(format!("Unknown argument `{arg}`"));
+            }
+            positional => {
+                if telemetry_path.is_none() {
+                    telemetry_path = Some(PathBuf::from(positional));
+                } else {
+                    return Err(format!("Unexpected positional argument `{positional}`"));
+                }
+            }
+        }
+    }
+
+    let telemetry_path =
+        telemetry_path.ok_or_else(|| "Missing required telemetry path".to_string())?;
+
+    Ok(CliArgs {
+        telemetry_path,
+        stimulus_path,
+        output_path,
+    })
+}
+
+fn print_usage() {
+    println!(
+        "Usage: cargo run --bin telemetry_to_metrics -- <telemetry.jsonl> [options]
+
+Replays a telemetry log and writes one metrics CSV row per step, in the
+layout `adversarial_cycle --metrics` reads.
+
+Options:
+  --stimulus <path>   Stimulus schedule recorded for the run (fills stimulus columns)
+  --output <path>     CSV destination (default: target/metrics.csv)
+  --help              Show this message"
+    );
+}
+
+struct CliArgs {
+    telemetry_path: PathBuf,
+    stimulus_path: Option<PathBuf>,
+    output_path: PathBuf,
+}
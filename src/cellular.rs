@@ -1,21 +1,63 @@
 //! Cellular automaton primitives for morphogenetic security nodes.
-use crate::immune::{Attestation, TPM, ThreatEvent};
-use crate::signaling::Signal;
+use crate::immune::{Attestation, Pki, TPM, ThreatEvent, TpmSnapshot};
+use crate::signaling::{Signal, SignalChannel, TopicSignals, is_actionable};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+
+/// Trust assigned to peers a cell has no history with.
+const NEUTRAL_TRUST: f32 = 0.5;
+
+/// Energy a freshly created cell starts with.
+pub const INITIAL_ENERGY: f32 = 1.0;
+
+/// Upper clamp on cell energy.
+pub const MAX_ENERGY: f32 = 1.5;
+
+/// Energy a Healer must hold before it spends effort calming neighbors.
+const HEALER_MIN_ENERGY: f32 = 0.5;
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CellEnvironment {
     pub step: u32,
     pub local_threat_score: f32,
-    pub neighbor_signals: Vec<Signal>,
+    pub neighbor_signals: Arc<[Signal]>,
     pub detected_neighbors: Vec<String>,
+    /// Optional topic index over `neighbor_signals`, built once by the
+    /// orchestrator so cells can skip re-categorising the flat buffer.
+    #[serde(skip)]
+    pub topic_index: Option<Arc<TopicSignals>>,
+    /// Current population as a fraction of the orchestrator's carrying capacity.
+    #[serde(default)]
+    pub population_density: f32,
+    /// Registry that attestations are verified against; `None` falls back
+    /// to the process-global [`Pki::global`].
+    #[serde(skip)]
+    pub pki: Option<Pki>,
+    /// Custom topics cells tally as one of the built-in channels.
+    #[serde(skip)]
+    pub recognized_topics: Option<Arc<HashMap<String, SignalChannel>>>,
+    /// Per-lineage energy multipliers; `None` leaves every lineage equal.
+    #[serde(skip)]
+    pub lineage_economy: Option<Arc<LineageEconomy>>,
+    /// Genome adjustments applied on threat events; `None` uses
+    /// [`AdaptationTable::default`].
+    #[serde(skip)]
+    pub adaptation_table: Option<Arc<AdaptationTable>>,
+}
+
+impl CellEnvironment {
+    /// The registry to verify attestations against this step.
+    pub fn pki(&self) -> Pki {
+        self.pki.clone().unwrap_or_else(Pki::global)
+    }
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CellLineage {
     Stem,
     Firewall,
@@ -24,8 +66,165 @@ pub enum CellLineage {
     Healer,
 }
 
+impl CellLineage {
+    pub const ALL: [CellLineage; 5] = [
+        CellLineage::Stem,
+        CellLineage::Firewall,
+        CellLineage::IntrusionDetection,
+        CellLineage::Encryption,
+        CellLineage::Healer,
+    ];
+}
+
+/// Allowed developmental paths between lineages, as `(from, to)` pairs.
+///
+/// Staying in the current lineage is always permitted. The default allows
+/// every transition, matching the unconstrained behaviour of `tick`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LineageRules {
+    pub allowed: BTreeSet<(CellLineage, CellLineage)>,
+}
+
+impl LineageRules {
+    pub fn permits(&self, from: &CellLineage, to: &CellLineage) -> bool {
+        from == to || self.allowed.contains(&(from.clone(), to.clone()))
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for LineageRules {
+    fn default() -> Self {
+        let allowed = CellLineage::ALL
+            .iter()
+            .flat_map(|from| {
+                CellLineage::ALL
+                    .iter()
+                    .filter(move |to| *to != from)
+                    .map(move |to| (from.clone(), to.clone()))
+            })
+            .collect();
+        Self { allowed }
+    }
+}
+
+/// Scaling applied to a cell's genome energy dynamics while it belongs to a
+/// lineage. `drain` covers both the threat and the inhibitor drain.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EnergyMultipliers {
+    #[serde(default = "unit_multiplier")]
+    pub recharge: f32,
+    #[serde(default = "unit_multiplier")]
+    pub drain: f32,
+}
+
+fn unit_multiplier() -> f32 {
+    1.0
+}
+
+impl Default for EnergyMultipliers {
+    fn default() -> Self {
+        Self {
+            recharge: 1.0,
+            drain: 1.0,
+        }
+    }
+}
+
+/// Per-lineage energy multipliers. Lineages without an entry run on the
+/// genome's values unchanged, so the default economy treats all alike.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct LineageEconomy {
+    pub multipliers: BTreeMap<CellLineage, EnergyMultipliers>,
+}
+
+impl LineageEconomy {
+    pub fn for_lineage(&self, lineage: &CellLineage) -> EnergyMultipliers {
+        self.multipliers.get(lineage).copied().unwrap_or_default()
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.multipliers.is_empty()
+    }
+}
+
+/// One genome adjustment made when a cell records a threat event. The gene
+/// is scaled by `1 + delta * confidence`, with the factor kept within
+/// `[0.5, 2.0]`, so negative deltas weaken it and positive deltas strengthen it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GeneAdjustment {
+    pub gene: String,
+    pub delta: f32,
+}
+
+impl GeneAdjustment {
+    fn new(gene: &str, delta: f32) -> Self {
+        Self {
+            gene: gene.to_string(),
+            delta,
+        }
+    }
+}
+
+/// Threat topic -> genome adjustments applied by
+/// [`CellGenome::adapt_to_event`]. Topics without an entry leave the genome
+/// untouched. The default hardens cells against `activator` only.
+///
+/// Threat events are keyed by the activator-class topic that contributed
+/// most to the threat, so a recognized custom topic needs its own entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct AdaptationTable {
+    pub entries: BTreeMap<String, Vec<GeneAdjustment>>,
+}
+
+static DEFAULT_ADAPTATION_TABLE: OnceLock<AdaptationTable> = OnceLock::new();
+
+impl AdaptationTable {
+    /// Process-wide [`AdaptationTable::default`], for cells whose
+    /// environment carries no table.
+    pub fn shared_default() -> &'static AdaptationTable {
+        DEFAULT_ADAPTATION_TABLE.get_or_init(AdaptationTable::default)
+    }
+
+    pub fn adjustments(&self, topic: &str) -> &[GeneAdjustment] {
+        self.entries
+            .get(topic)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for AdaptationTable {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::from([(
+                "activator".to_string(),
+                vec![
+                    // Become less sensitive to activator...
+                    GeneAdjustment::new("stress_sensitivity", -0.05),
+                    // ...and make inhibitor more effective against it.
+                    GeneAdjustment::new("threat_inhibitor_factor", 0.05),
+                ],
+            )]),
+        }
+    }
+}
+
 #[allow(dead_code)]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CellState {
     pub lineage: CellLineage,
     pub energy: f32,
@@ -37,6 +236,9 @@ pub struct CellState {
     pub neighbor_trust: HashMap<String, f32>,
     #[serde(default)]
     pub blacklist: Vec<String>,
+    /// Step of the most recent successful lineage change.
+    #[serde(default)]
+    pub last_differentiation_step: Option<u32>,
 }
 
 #[allow(dead_code)]
@@ -63,6 +265,59 @@ pub struct CellGenome {
     pub trust_reward: f32,
     pub trust_penalty: f32,
     pub min_trust_threshold: f32,
+    /// How strongly population density raises the reproduction threshold.
+    #[serde(default)]
+    pub density_sensitivity: f32,
+    /// When false, inhibitor only calms threat and no longer drains energy.
+    #[serde(default = "default_inhibitor_energy_cost_enabled")]
+    pub inhibitor_energy_cost_enabled: bool,
+    /// Minimum steps between lineage changes; 0 disables throttling.
+    #[serde(default)]
+    pub differentiation_cooldown: u32,
+    /// Trust prior assigned to neighbors without an entry in `neighbor_trust`.
+    #[serde(default = "default_initial_trust")]
+    pub initial_trust: f32,
+    /// Readiness (`energy * (1 - stress)`) at which a Stem cell broadcasts
+    /// `cooperative`; 0 disables emission.
+    #[serde(default)]
+    pub cooperative_emission_threshold: f32,
+    /// When true, blacklisted peers that regain trust through valid
+    /// attestations are reconnected instead of staying quarantined forever.
+    #[serde(default)]
+    pub peer_rehabilitation: bool,
+    /// Baseline stress that decay never drops below, modelling chronic
+    /// threat. Capped at `stress_differentiation_threshold` when applied.
+    #[serde(default)]
+    pub stress_floor: f32,
+    /// Inhibitor a Healer emits per unit of neighbor activator it hears once
+    /// that activator reaches `signal_emission_threshold`; 0 disables.
+    #[serde(default)]
+    pub healer_inhibitor_output: f32,
+    /// Slope of the sigmoid mapping how far threat exceeds
+    /// `anomaly_sensitivity` to an anomaly report's confidence.
+    #[serde(default = "default_anomaly_confidence_gain")]
+    pub anomaly_confidence_gain: f32,
+    /// Summed confidence of attested votes against a detected neighbor
+    /// needed to quarantine it. Each vote weighs less than 1, so the default
+    /// 1.5 takes at least two confident voters.
+    #[serde(default = "default_quarantine_vote_threshold")]
+    pub quarantine_vote_threshold: f32,
+}
+
+fn default_inhibitor_energy_cost_enabled() -> bool {
+    true
+}
+
+fn default_anomaly_confidence_gain() -> f32 {
+    4.0
+}
+
+fn default_quarantine_vote_threshold() -> f32 {
+    1.5
+}
+
+fn default_initial_trust() -> f32 {
+    NEUTRAL_TRUST
 }
 
 impl Default for CellGenome {
@@ -89,6 +344,16 @@ impl Default for CellGenome {
             trust_reward: 0.05,
             trust_penalty: 0.2,
             min_trust_threshold: 0.2,
+            density_sensitivity: 0.0,
+            inhibitor_energy_cost_enabled: true,
+            differentiation_cooldown: 0,
+            initial_trust: default_initial_trust(),
+            cooperative_emission_threshold: 0.0,
+            peer_rehabilitation: false,
+            stress_floor: 0.0,
+            healer_inhibitor_output: 0.0,
+            anomaly_confidence_gain: default_anomaly_confidence_gain(),
+            quarantine_vote_threshold: default_quarantine_vote_threshold(),
         }
     }
 }
@@ -96,7 +361,13 @@ impl Default for CellGenome {
 impl CellGenome {
     #[allow(dead_code)]
     pub fn mutate(&mut self) {
-        let mut rng = rand::thread_rng();
+        self.mutate_with(&mut rand::thread_rng());
+    }
+
+    /// Mutate using `rng` and return the names of the genes whose value
+    /// actually changed, in [`Self::GENE_NAMES`] order.
+    pub fn mutate_with<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Vec<String> {
+        let mut before = self.clone();
         let rate = 0.1; // 10% chance per gene
         let strength = 0.2; // +/- 0.2 change
 
@@ -128,15 +399,176 @@ impl CellGenome {
         mutate_field(&mut self.trust_reward);
         mutate_field(&mut self.trust_penalty);
         mutate_field(&mut self.min_trust_threshold);
+        mutate_field(&mut self.density_sensitivity);
+        self.clamp_to_valid_ranges();
+
+        let mut after = self.clone();
+        Self::GENE_NAMES
+            .iter()
+            .filter(|gene| before.gene_mut(gene).copied() != after.gene_mut(gene).copied())
+            .map(|gene| gene.to_string())
+            .collect()
     }
 
-    #[allow(dead_code)]
-    pub fn adapt_to_event(&mut self, event: &ThreatEvent) {
-        if event.topic == "activator" {
-            // Harden against activator by decreasing sensitivity
-            self.stress_sensitivity *= (1.0 - 0.05 * event.confidence).max(0.5);
-            // And increasing inhibitor effectiveness
-            self.threat_inhibitor_factor *= (1.0 + 0.05 * event.confidence).min(2.0);
+    /// Inclusive `(gene, min, max)` bounds enforced by
+    /// [`Self::clamp_to_valid_ranges`]. Rates and probabilities stay within
+    /// `[0, 1]`; energy amounts within `[0, MAX_ENERGY]`; thresholds compared
+    /// against summed signal or threat levels get headroom up to 5. Genes whose
+    /// zero value means "disabled" may reach 0; the rest keep the 0.01 floor
+    /// that mutation has always applied.
+    pub const GENE_RANGES: &'static [(&'static str, f32, f32)] = &[
+        ("threat_inhibitor_factor", 0.01, 2.0),
+        ("stress_decay", 0.01, 1.0),
+        ("stress_sensitivity", 0.01, 2.0),
+        ("energy_recharge", 0.01, 1.0),
+        ("energy_threat_drain", 0.01, 1.0),
+        ("energy_inhibitor_drain", 0.01, 1.0),
+        ("reproduction_threshold", 0.01, 5.0),
+        ("reproduction_energy_cost", 0.01, MAX_ENERGY),
+        ("reproduction_energy_min", 0.01, MAX_ENERGY),
+        ("stress_differentiation_threshold", 0.01, 1.0),
+        ("healer_inhibitor_threshold", 0.01, 5.0),
+        ("healer_stress_limit", 0.01, 1.0),
+        ("encryption_cooperative_threshold", 0.01, 5.0),
+        ("encryption_energy_min", 0.01, MAX_ENERGY),
+        ("signal_emission_threshold", 0.01, 5.0),
+        ("connection_cost", 0.01, 1.0),
+        ("isolation_threshold", 0.01, 1.0),
+        ("anomaly_sensitivity", 0.01, 5.0),
+        ("trust_reward", 0.0, 1.0),
+        ("trust_penalty", 0.0, 1.0),
+        ("min_trust_threshold", 0.0, 1.0),
+        ("density_sensitivity", 0.0, 10.0),
+        ("initial_trust", 0.0, 1.0),
+        ("cooperative_emission_threshold", 0.0, MAX_ENERGY),
+        ("stress_floor", 0.0, 1.0),
+        ("healer_inhibitor_output", 0.0, 5.0),
+        ("anomaly_confidence_gain", 0.01, 50.0),
+        ("quarantine_vote_threshold", 0.01, 10.0),
+    ];
+
+    /// Pull every gene back inside [`Self::GENE_RANGES`]. NaN genes are reset
+    /// to their lower bound.
+    pub fn clamp_to_valid_ranges(&mut self) {
+        for &(gene, _, _) in Self::GENE_RANGES {
+            self.clamp_gene(gene);
+        }
+    }
+
+    /// Inclusive `(min, max)` bounds of `gene`, or `None` for unknown genes.
+    pub fn gene_range(gene: &str) -> Option<(f32, f32)> {
+        Self::GENE_RANGES
+            .iter()
+            .find(|range| range.0 == gene)
+            .map(|&(_, min, max)| (min, max))
+    }
+
+    /// Pull a single gene back inside its [`Self::GENE_RANGES`] entry.
+    fn clamp_gene(&mut self, gene: &str) {
+        let Some((min, max)) = Self::gene_range(gene) else {
+            return;
+        };
+        if let Some(value) = self.gene_mut(gene) {
+            *value = if value.is_nan() {
+                min
+            } else {
+                value.clamp(min, max)
+            };
+        }
+    }
+
+    /// Names accepted by [`Self::set_gene`] and [`Self::gene_mut`], in
+    /// [`Self::GENE_RANGES`] order.
+    pub const GENE_NAMES: &'static [&'static str] = &{
+        let mut names = [""; CellGenome::GENE_RANGES.len()];
+        let mut index = 0;
+        while index < names.len() {
+            names[index] = CellGenome::GENE_RANGES[index].0;
+            index += 1;
+        }
+        names
+    };
+
+    /// Set a gene by its field name. Returns `false` for unknown genes.
+    pub fn set_gene(&mut self, gene: &str, value: f32) -> bool {
+        match self.gene_mut(gene) {
+            Some(field) => {
+                *field = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Mutable access to a gene by its field name; `None` for unknown genes.
+    pub fn gene_mut(&mut self, gene: &str) -> Option<&mut f32> {
+        let field = match gene {
+            "threat_inhibitor_factor" => &mut self.threat_inhibitor_factor,
+            "stress_decay" => &mut self.stress_decay,
+            "stress_sensitivity" => &mut self.stress_sensitivity,
+            "energy_recharge" => &mut self.energy_recharge,
+            "energy_threat_drain" => &mut self.energy_threat_drain,
+            "energy_inhibitor_drain" => &mut self.energy_inhibitor_drain,
+            "reproduction_threshold" => &mut self.reproduction_threshold,
+            "reproduction_energy_cost" => &mut self.reproduction_energy_cost,
+            "reproduction_energy_min" => &mut self.reproduction_energy_min,
+            "stress_differentiation_threshold" => &mut self.stress_differentiation_threshold,
+            "healer_inhibitor_threshold" => &mut self.healer_inhibitor_threshold,
+            "healer_stress_limit" => &mut self.healer_stress_limit,
+            "encryption_cooperative_threshold" => &mut self.encryption_cooperative_threshold,
+            "encryption_energy_min" => &mut self.encryption_energy_min,
+            "signal_emission_threshold" => &mut self.signal_emission_threshold,
+            "connection_cost" => &mut self.connection_cost,
+            "isolation_threshold" => &mut self.isolation_threshold,
+            "anomaly_sensitivity" => &mut self.anomaly_sensitivity,
+            "trust_reward" => &mut self.trust_reward,
+            "trust_penalty" => &mut self.trust_penalty,
+            "min_trust_threshold" => &mut self.min_trust_threshold,
+            "density_sensitivity" => &mut self.density_sensitivity,
+            "initial_trust" => &mut self.initial_trust,
+            "cooperative_emission_threshold" => &mut self.cooperative_emission_threshold,
+            "stress_floor" => &mut self.stress_floor,
+            "healer_inhibitor_output" => &mut self.healer_inhibitor_output,
+            "anomaly_confidence_gain" => &mut self.anomaly_confidence_gain,
+            "quarantine_vote_threshold" => &mut self.quarantine_vote_threshold,
+            _ => return None,
+        };
+        Some(field)
+    }
+
+    /// Calibrated confidence in an anomaly at `effective_threat`: a sigmoid
+    /// of its margin over `anomaly_sensitivity`, so a bare crossing scores
+    /// 0.5 and stronger threats approach 1.
+    pub fn anomaly_confidence(&self, effective_threat: f32) -> f32 {
+        let margin = effective_threat - self.anomaly_sensitivity;
+        1.0 / (1.0 + (-self.anomaly_confidence_gain * margin).exp())
+    }
+
+    /// Steady-state energy under a constant `background_threat` with no
+    /// inhibitor. Energy moves by a fixed amount each step, so it settles at
+    /// [`MAX_ENERGY`] when recharge outpaces threat drain, at 0 (death) when
+    /// it does not, and stays at [`INITIAL_ENERGY`] when the two balance.
+    pub fn equilibrium_energy(&self, background_threat: f32) -> f32 {
+        let net = self.energy_recharge - background_threat * self.energy_threat_drain;
+        if net > f32::EPSILON {
+            MAX_ENERGY
+        } else if net < -f32::EPSILON {
+            0.0
+        } else {
+            INITIAL_ENERGY
+        }
+    }
+
+    /// Apply `table`'s adjustments for the event's topic. Adjusted genes are
+    /// clamped to [`Self::GENE_RANGES`], so repeated events cannot push the
+    /// genome out of its valid ranges.
+    pub fn adapt_to_event(&mut self, event: &ThreatEvent, table: &AdaptationTable) {
+        for adjustment in table.adjustments(&event.topic) {
+            let factor = (1.0 + adjustment.delta * event.confidence).clamp(0.5, 2.0);
+            if let Some(value) = self.gene_mut(&adjustment.gene) {
+                *value *= factor;
+                self.clamp_gene(&adjustment.gene);
+            }
         }
     }
 }
@@ -193,6 +625,20 @@ impl PopulationStats {
     }
 }
 
+/// Per-tick accumulation of the signals a cell absorbed.
+#[derive(Default)]
+struct SignalTallies {
+    activator: f32,
+    inhibitor: f32,
+    cooperative: f32,
+    accused_votes: HashMap<String, f32>,
+    /// Weighted activator-channel contribution per delivered topic.
+    threat_by_topic: HashMap<String, f32>,
+    /// (origin, accused) pairs already counted, so one accusation arriving
+    /// over several relay paths is only tallied once.
+    counted_votes: HashSet<(String, String)>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct SecurityCell {
@@ -202,6 +648,28 @@ pub struct SecurityCell {
     pub tpm: TPM,
 }
 
+/// Persistable copy of a [`SecurityCell`]; see [`TpmSnapshot`] for how keys
+/// are handled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellSnapshot {
+    pub id: String,
+    pub state: CellState,
+    pub genome: CellGenome,
+    pub tpm: TpmSnapshot,
+}
+
+impl CellSnapshot {
+    /// Rebuild the cell, publishing its (possibly regenerated) key to `pki`.
+    pub fn restore(self, pki: &Pki) -> SecurityCell {
+        SecurityCell {
+            id: self.id,
+            state: self.state,
+            genome: self.genome,
+            tpm: self.tpm.restore(pki),
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum CellAction {
@@ -211,11 +679,17 @@ pub enum CellAction {
     EmitSignal(String, f32),
     Die,
     Connect(String),
+    /// Lift a quarantine on a peer whose trust recovered, then reconnect.
+    Rehabilitate(String),
     Disconnect(String),
+    /// Disconnect from a peer that neighbors' consensus votes convicted.
+    Quarantine(String),
     ReportAnomaly(String, f32, Option<String>, Option<Attestation>),
 }
 
 impl SecurityCell {
+    /// A fresh stem cell. Its key pair is not published anywhere; the owning
+    /// [`crate::MorphogeneticApp`] registers it with the app's [`Pki`].
     #[allow(dead_code)]
     pub fn new(id: impl Into<String>) -> Self {
         let id = id.into();
@@ -223,126 +697,293 @@ impl SecurityCell {
             id: id.clone(),
             state: CellState {
                 lineage: CellLineage::Stem,
-                energy: 1.0,
+                energy: INITIAL_ENERGY,
                 stress_level: 0.0,
                 dead: false,
                 immune_memory: Vec::new(),
                 neighbor_trust: HashMap::new(),
                 blacklist: Vec::new(),
+                last_differentiation_step: None,
             },
             genome: CellGenome::default(),
-            tpm: TPM::new(id),
+            tpm: TPM::generate(id),
         }
     }
 
-    #[allow(dead_code)]
-    pub fn tick(&mut self, environment: &CellEnvironment) -> CellAction {
-        let mut activator = 0.0;
-        let mut inhibitor = 0.0;
-        let mut cooperative = 0.0;
-        let mut accused_votes: HashMap<String, f32> = HashMap::new();
+    /// Copy of this cell's genome for offline analysis or reseeding.
+    pub fn export_genome(&self) -> CellGenome {
+        self.genome.clone()
+    }
 
-        // 0. Trust Pruning: remove trust entries for neighbors no longer detected
-        self.state
-            .neighbor_trust
-            .retain(|id, _| environment.detected_neighbors.contains(id));
+    /// Snapshot without the signing key; a restored cell is issued a new one.
+    pub fn snapshot(&self) -> CellSnapshot {
+        CellSnapshot {
+            id: self.id.clone(),
+            state: self.state.clone(),
+            genome: self.genome.clone(),
+            tpm: self.tpm.snapshot(),
+        }
+    }
+
+    /// Snapshot that preserves the signing key across a restore.
+    pub fn snapshot_with_keys(&self) -> CellSnapshot {
+        CellSnapshot {
+            tpm: self.tpm.snapshot_with_keys(),
+            ..self.snapshot()
+        }
+    }
 
-        for signal in &environment.neighbor_signals {
-            if let Some(source) = &signal.source {
-                let trust = *self.state.neighbor_trust.get(source).unwrap_or(&0.5);
+    /// Apply one delivered signal to trust bookkeeping and the step's tallies.
+    fn absorb_signal(
+        genome: &CellGenome,
+        neighbor_trust: &mut HashMap<String, f32>,
+        signal: &Signal,
+        step: u32,
+        pki: &Pki,
+        recognized: &HashMap<String, SignalChannel>,
+        tallies: &mut SignalTallies,
+    ) {
+        // System signals carry full weight; neighbor signals are scaled by the
+        // receiver's trust in their source.
+        let mut weight = 1.0;
+        if let Some(source) = &signal.source {
+            let trust = *neighbor_trust.get(source).unwrap_or(&genome.initial_trust);
+            weight = trust;
+
+            // Penalize if source is untrusted (below min_trust_threshold)
+            if trust < genome.min_trust_threshold {
+                return;
+            }
 
-                // Penalize if source is untrusted (below min_trust_threshold)
-                if trust < self.genome.min_trust_threshold {
-                    continue;
+            // Verify attestation if present and bind it to the source
+            if let Some(attestation) = &signal.attestation {
+                let payload = attestation_payload(signal);
+                if attestation.cell_id == *source && pki.verify(attestation, step as u64, &payload)
+                {
+                    *neighbor_trust
+                        .entry(source.clone())
+                        .or_insert(genome.initial_trust) = (trust + genome.trust_reward).min(1.0);
+                } else {
+                    *neighbor_trust
+                        .entry(source.clone())
+                        .or_insert(genome.initial_trust) = (trust - genome.trust_penalty).max(0.0);
                 }
+            } else if signal.topic.starts_with("consensus:") {
+                // Consensus signals MUST be attested. If we are here, attestation is None.
+                // Penalize trust immediately to prevent DoS from unauthenticated consensus spam.
+                let trust = *neighbor_trust.get(source).unwrap_or(&genome.initial_trust);
+                let new_trust = (trust - genome.trust_penalty).max(0.0);
+                neighbor_trust.insert(source.clone(), new_trust);
+            }
+        }
 
-                // Verify attestation if present and bind it to the source
-                if let Some(attestation) = &signal.attestation {
-                    let payload = format!(
-                        "{}:{:.1}:{}",
-                        signal.topic,
-                        signal.value,
-                        signal.target.as_deref().unwrap_or("none")
-                    );
-
+        match SignalChannel::resolve(&signal.topic, recognized) {
+            Some(SignalChannel::Activator) => {
+                tallies.activator += signal.value * weight;
+                *tallies
+                    .threat_by_topic
+                    .entry(signal.topic.clone())
+                    .or_default() += signal.value * weight;
+            }
+            Some(SignalChannel::Inhibitor) => tallies.inhibitor += signal.value * weight,
+            Some(SignalChannel::Cooperative) => tallies.cooperative += signal.value * weight,
+            None if signal.topic.starts_with("consensus:") => {
+                // Only count vote if attestation is valid and bound to source
+                if let (Some(source), Some(attestation)) = (&signal.source, &signal.attestation) {
+                    let payload = attestation_payload(signal);
                     if attestation.cell_id == *source
-                        && TPM::verify(attestation, environment.step as u64, &payload)
+                        && pki.verify(attestation, step as u64, &payload)
                     {
-                        *self
-                            .state
-                            .neighbor_trust
-                            .entry(source.clone())
-                            .or_insert(0.5) = (trust + self.genome.trust_reward).min(1.0);
-                    } else {
-                        *self
-                            .state
-                            .neighbor_trust
-                            .entry(source.clone())
-                            .or_insert(0.5) = (trust - self.genome.trust_penalty).max(0.0);
+                        // If signal has a target, that's the accused. Otherwise the
+                        // original reporter flagged itself or its vicinity as anomalous.
+                        let origin = signal.true_origin().unwrap_or(source).to_string();
+                        let accused = signal.target.clone().unwrap_or_else(|| origin.clone());
+                        if tallies.counted_votes.insert((origin, accused.clone())) {
+                            *tallies.accused_votes.entry(accused).or_insert(0.0) += signal.value;
+                        }
                     }
-                } else if signal.topic.starts_with("consensus:") {
-                    // Consensus signals MUST be attested. If we are here, attestation is None.
-                    // Penalize trust immediately to prevent DoS from unauthenticated consensus spam.
-                    let trust = *self.state.neighbor_trust.get(source).unwrap_or(&0.5);
-                    let new_trust = (trust - self.genome.trust_penalty).max(0.0);
-                    self.state.neighbor_trust.insert(source.clone(), new_trust);
                 }
             }
+            None => {}
+        }
+    }
 
-            match signal.topic.as_str() {
-                "activator" => activator += signal.value,
-                "inhibitor" => inhibitor += signal.value,
-                "cooperative" => cooperative += signal.value,
-                topic if topic.starts_with("consensus:") => {
-                    // Only count vote if attestation is valid and bound to source
-                    if let (Some(source), Some(attestation)) = (&signal.source, &signal.attestation)
-                    {
-                        let payload = format!(
-                            "{}:{:.1}:{}",
-                            signal.topic,
-                            signal.value,
-                            signal.target.as_deref().unwrap_or("none")
-                        );
-                        if attestation.cell_id == *source
-                            && TPM::verify(attestation, environment.step as u64, &payload)
-                        {
-                            // If signal has a target, that's the accused.
-                            // Otherwise, the source is reporting itself or its vicinity as anomalous.
-                            let accused = signal.target.clone().unwrap_or_else(|| source.clone());
-                            *accused_votes.entry(accused).or_insert(0.0) += signal.value;
-                        }
-                    }
+    /// Credit valid attestations from blacklisted peers that the tally skipped
+    /// for being below `min_trust_threshold`, and return the first peer whose
+    /// trust has climbed back to it, mirroring the `<` that quarantined it.
+    fn review_blacklist(&mut self, environment: &CellEnvironment) -> Option<String> {
+        let genome = &self.genome;
+        let pki = environment.pki();
+        for signal in environment.neighbor_signals.iter() {
+            let (Some(source), Some(attestation)) = (&signal.source, &signal.attestation) else {
+                continue;
+            };
+            if !self.state.blacklist.contains(source) {
+                continue;
+            }
+            let trust = *self
+                .state
+                .neighbor_trust
+                .get(source)
+                .unwrap_or(&genome.initial_trust);
+            // Trusted peers were already credited while tallying.
+            if trust >= genome.min_trust_threshold {
+                continue;
+            }
+            if attestation.cell_id == *source
+                && pki.verify(
+                    attestation,
+                    environment.step as u64,
+                    &attestation_payload(signal),
+                )
+            {
+                self.state
+                    .neighbor_trust
+                    .insert(source.clone(), (trust + genome.trust_reward).min(1.0));
+            }
+        }
+        self.state
+            .blacklist
+            .iter()
+            .find(|peer| {
+                self.state
+                    .neighbor_trust
+                    .get(*peer)
+                    .is_some_and(|trust| *trust >= genome.min_trust_threshold)
+            })
+            .cloned()
+    }
+
+    /// Tally delivered signals, updating `neighbor_trust` as attestations are checked.
+    fn tally_signals(
+        genome: &CellGenome,
+        neighbor_trust: &mut HashMap<String, f32>,
+        environment: &CellEnvironment,
+    ) -> SignalTallies {
+        let mut tallies = SignalTallies::default();
+        let step = environment.step;
+        let pki = environment.pki();
+        let no_custom_topics = HashMap::new();
+        let recognized = environment
+            .recognized_topics
+            .as_deref()
+            .unwrap_or(&no_custom_topics);
+        let mut absorb = |signal: &Signal| {
+            Self::absorb_signal(
+                genome,
+                neighbor_trust,
+                signal,
+                step,
+                &pki,
+                recognized,
+                &mut tallies,
+            )
+        };
+        match &environment.topic_index {
+            Some(index) => {
+                index.actionable().for_each(&mut absorb);
+                // Custom topics are only indexed as actionable when attested.
+                for topic in recognized.keys() {
+                    index
+                        .topic(topic)
+                        .filter(|signal| !is_actionable(signal))
+                        .for_each(&mut absorb);
                 }
-                _ => {}
             }
+            None => environment.neighbor_signals.iter().for_each(absorb),
+        }
+        tallies
+    }
+
+    fn threat_from_tallies(&self, local_threat_score: f32, tallies: &SignalTallies) -> f32 {
+        (local_threat_score + tallies.activator
+            - tallies.inhibitor * self.genome.threat_inhibitor_factor)
+            .max(0.0)
+    }
+
+    /// The threat `tick` would act on for `environment`, without mutating the
+    /// cell. Trust updates made while tallying are applied to a scratch copy.
+    pub fn effective_threat(&self, environment: &CellEnvironment) -> f32 {
+        let mut neighbor_trust = self.state.neighbor_trust.clone();
+        neighbor_trust.retain(|id, _| environment.detected_neighbors.contains(id));
+        let tallies = Self::tally_signals(&self.genome, &mut neighbor_trust, environment);
+        self.threat_from_tallies(environment.local_threat_score, &tallies)
+    }
+
+    #[allow(dead_code)]
+    pub fn tick(&mut self, environment: &CellEnvironment) -> CellAction {
+        // 0. Trust Pruning: remove trust entries for neighbors no longer detected,
+        // keeping quarantined peers' scores when they may earn their way back.
+        let blacklist = &self.state.blacklist;
+        let keep_blacklisted = self.genome.peer_rehabilitation;
+        self.state.neighbor_trust.retain(|id, _| {
+            environment.detected_neighbors.contains(id)
+                || (keep_blacklisted && blacklist.contains(id))
+        });
+
+        let tallies =
+            Self::tally_signals(&self.genome, &mut self.state.neighbor_trust, environment);
+        let effective_threat = self.threat_from_tallies(environment.local_threat_score, &tallies);
+        let SignalTallies {
+            activator,
+            inhibitor,
+            cooperative,
+            accused_votes,
+            threat_by_topic,
+            ..
+        } = tallies;
+
+        // 0b. Rehabilitation: reconnect a quarantined peer whose trust recovered
+        if self.genome.peer_rehabilitation
+            && let Some(peer) = self.review_blacklist(environment)
+        {
+            return CellAction::Rehabilitate(peer);
         }
 
         // 1. Coordinated Quarantine: Disconnect from neighbors with high consensus votes
         for (accused, votes) in &accused_votes {
-            if *votes > 1.5 && environment.detected_neighbors.contains(accused) {
-                return CellAction::Disconnect(accused.clone());
+            if *votes > self.genome.quarantine_vote_threshold
+                && environment.detected_neighbors.contains(accused)
+            {
+                return CellAction::Quarantine(accused.clone());
             }
         }
 
         // 2. Trust-based Isolation: Active disconnection from untrusted neighbors
         for neighbor in &environment.detected_neighbors {
-            let trust = self.state.neighbor_trust.get(neighbor).unwrap_or(&0.5);
+            let trust = self
+                .state
+                .neighbor_trust
+                .get(neighbor)
+                .unwrap_or(&self.genome.initial_trust);
             if *trust < self.genome.min_trust_threshold {
                 return CellAction::Disconnect(neighbor.clone());
             }
         }
 
-        let effective_threat = (environment.local_threat_score + activator
-            - inhibitor * self.genome.threat_inhibitor_factor)
-            .max(0.0);
-
+        let stress_floor = self
+            .genome
+            .stress_floor
+            .min(self.genome.stress_differentiation_threshold)
+            .clamp(0.0, 1.0);
         self.state.stress_level = (self.state.stress_level * self.genome.stress_decay
             + effective_threat * self.genome.stress_sensitivity)
-            .clamp(0.0, 1.0);
-        self.state.energy = (self.state.energy + self.genome.energy_recharge
-            - effective_threat * self.genome.energy_threat_drain
-            - inhibitor * self.genome.energy_inhibitor_drain)
-            .clamp(0.0, 1.5);
+            .clamp(stress_floor, 1.0);
+        // Inhibitor's threat reduction above is unconditional; its energy cost is optional.
+        let inhibitor_drain = if self.genome.inhibitor_energy_cost_enabled {
+            inhibitor * self.genome.energy_inhibitor_drain
+        } else {
+            0.0
+        };
+        let economy = environment
+            .lineage_economy
+            .as_deref()
+            .map(|economy| economy.for_lineage(&self.state.lineage))
+            .unwrap_or_default();
+        self.state.energy = (self.state.energy + self.genome.energy_recharge * economy.recharge
+            - (effective_threat * self.genome.energy_threat_drain + inhibitor_drain)
+                * economy.drain)
+            .clamp(0.0, MAX_ENERGY);
 
         if self.state.energy <= 0.01 {
             return CellAction::Die;
@@ -362,28 +1003,48 @@ impl SecurityCell {
             && inhibitor < 0.2
         // Not being suppressed
         {
+            let confidence = self.genome.anomaly_confidence(effective_threat);
+            // The loudest activator-class topic is blamed; a threat carried by
+            // the local score alone stays on the built-in channel.
+            let threat_topic = threat_by_topic
+                .into_iter()
+                .max_by(|(a_topic, a), (b_topic, b)| a.total_cmp(b).then(b_topic.cmp(a_topic)))
+                .map(|(topic, _)| topic)
+                .unwrap_or_else(|| "activator".to_string());
             // Record in memory if not already there recently (cooldown of 50 steps)
             let recent_match =
                 self.state.immune_memory.iter().any(|e| {
-                    e.topic == "activator" && environment.step.saturating_sub(e.step) < 50
+                    e.topic == threat_topic && environment.step.saturating_sub(e.step) < 50
                 });
 
             if !recent_match {
                 let event = ThreatEvent {
                     step: environment.step,
-                    topic: "activator".to_string(),
+                    topic: threat_topic,
                     magnitude: effective_threat,
-                    confidence: 0.8,
+                    confidence,
                 };
-                self.genome.adapt_to_event(&event);
+                let table = environment
+                    .adaptation_table
+                    .as_deref()
+                    .unwrap_or_else(|| AdaptationTable::shared_default());
+                self.genome.adapt_to_event(&event, table);
                 self.state.immune_memory.push(event);
             }
 
             // Find the neighbor contributing most to activator signals (the "accused")
-            let accused_target = environment
-                .neighbor_signals
-                .iter()
-                .filter(|s| s.topic == "activator" && s.source.is_some())
+            let activator_signals: Box<dyn Iterator<Item = &Signal>> =
+                match &environment.topic_index {
+                    Some(index) => Box::new(index.topic("activator")),
+                    None => Box::new(
+                        environment
+                            .neighbor_signals
+                            .iter()
+                            .filter(|s| s.topic == "activator"),
+                    ),
+                };
+            let accused_target = activator_signals
+                .filter(|s| s.source.is_some())
                 .max_by(|a, b| {
                     a.value
                         .partial_cmp(&b.value)
@@ -395,13 +1056,27 @@ impl SecurityCell {
             // Payload MUST match what handle_action broadcasts: consensus:topic:value:target
             let consensus_topic = format!("consensus:{}", topic);
             let target_str = accused_target.as_deref().unwrap_or("none");
-            let payload = format!("{}:{:.1}:{}", consensus_topic, effective_threat, target_str);
+            // The vote carries the calibrated confidence, not the raw threat.
+            let payload = format!("{}:{:.1}:{}", consensus_topic, confidence, target_str);
             let attestation = self.tpm.attest(environment.step as u64, &payload);
 
-            return CellAction::ReportAnomaly(topic, effective_threat, accused_target, attestation);
+            return CellAction::ReportAnomaly(topic, confidence, accused_target, attestation);
+        }
+
+        // Healers answer loud neighbor activator with proportional inhibitor.
+        if matches!(self.state.lineage, CellLineage::Healer)
+            && self.genome.healer_inhibitor_output > 0.0
+            && self.state.energy >= HEALER_MIN_ENERGY
+            && activator >= self.genome.signal_emission_threshold
+        {
+            let output = (activator * self.genome.healer_inhibitor_output).min(1.0);
+            return CellAction::EmitSignal("inhibitor".to_string(), output);
         }
 
-        if effective_threat >= self.genome.reproduction_threshold
+        // Crowding raises the bar for replication, giving logistic growth.
+        let reproduction_threshold = self.genome.reproduction_threshold
+            + self.genome.density_sensitivity * environment.population_density;
+        if effective_threat >= reproduction_threshold
             && self.state.energy >= self.genome.reproduction_energy_min
         {
             self.state.energy = (self.state.energy - self.genome.reproduction_energy_cost).max(0.0);
@@ -428,6 +1103,16 @@ impl SecurityCell {
             return CellAction::Differentiate(CellLineage::Encryption);
         }
 
+        // Calm, well-fed stem cells invite neighbors toward cooperative lineages.
+        let readiness = self.state.energy * (1.0 - self.state.stress_level);
+        if matches!(self.state.lineage, CellLineage::Stem)
+            && self.genome.cooperative_emission_threshold > 0.0
+            && readiness >= self.genome.cooperative_emission_threshold
+            && effective_threat < self.genome.signal_emission_threshold
+        {
+            return CellAction::EmitSignal("cooperative".to_string(), readiness.min(1.0));
+        }
+
         if effective_threat >= self.genome.signal_emission_threshold {
             return CellAction::EmitSignal("activator".to_string(), effective_threat);
         }
@@ -436,19 +1121,146 @@ impl SecurityCell {
     }
 }
 
+/// Bytes a sender attests for `signal`. Relayed signals also bind their
+/// origin, so a relay cannot reattribute the accusation.
+pub(crate) fn attestation_payload(signal: &Signal) -> String {
+    let payload = format!(
+        "{}:{:.1}:{}",
+        signal.topic,
+        signal.value,
+        signal.target.as_deref().unwrap_or("none")
+    );
+    match &signal.origin {
+        Some(origin) => format!("{payload}:{origin}"),
+        None => payload,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn env_with_threat(threat: f32) -> CellEnvironment {
         CellEnvironment {
-            step: 0,
             local_threat_score: threat,
-            neighbor_signals: Vec::new(),
-            detected_neighbors: Vec::new(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn every_listed_gene_is_addressable() {
+        let mut genome = CellGenome::default();
+        for gene in CellGenome::GENE_NAMES {
+            assert!(genome.gene_mut(gene).is_some(), "unknown gene {gene}");
+        }
+        assert!(genome.gene_mut("not_a_gene").is_none());
+    }
+
+    #[test]
+    fn forced_mutation_reports_every_changed_gene() {
+        // An all-zero RNG fires every mutation and always draws the lowest delta.
+        let mut rng = rand::rngs::mock::StepRng::new(0, 0);
+        let mut genome = CellGenome::default();
+        let changed = genome.mutate_with(&mut rng);
+        let density = CellGenome::GENE_NAMES
+            .iter()
+            .position(|gene| *gene == "density_sensitivity")
+            .unwrap();
+        assert_eq!(changed, CellGenome::GENE_NAMES[..=density]);
+        assert!((genome.stress_decay - 0.25).abs() < 1e-6);
+        assert_eq!(genome.initial_trust, CellGenome::default().initial_trust);
+    }
+
+    #[test]
+    fn out_of_range_genome_is_clamped_into_bounds() {
+        let ranged: Vec<&str> = CellGenome::GENE_RANGES.iter().map(|r| r.0).collect();
+        assert_eq!(ranged, CellGenome::GENE_NAMES);
+
+        let mut defaults = CellGenome::default();
+        defaults.clamp_to_valid_ranges();
+        assert_eq!(
+            serde_json::to_value(&defaults).unwrap(),
+            serde_json::to_value(CellGenome::default()).unwrap(),
+            "defaults already lie inside the ranges"
+        );
+
+        let mut genome = CellGenome {
+            reproduction_threshold: 50.0,
+            stress_decay: -3.0,
+            trust_penalty: f32::NAN,
+            initial_trust: 1.7,
+            ..CellGenome::default()
+        };
+        genome.clamp_to_valid_ranges();
+        assert_eq!(genome.reproduction_threshold, 5.0);
+        assert_eq!(genome.stress_decay, 0.01);
+        assert_eq!(genome.trust_penalty, 0.0);
+        assert_eq!(genome.initial_trust, 1.0);
+        for &(gene, min, max) in CellGenome::GENE_RANGES {
+            let value = *genome.gene_mut(gene).unwrap();
+            assert!((min..=max).contains(&value), "{gene} = {value}");
+        }
+    }
+
+    #[test]
+    fn equilibrium_energy_matches_repeated_ticks() {
+        for threat in [0.3, 0.6, 2.0] {
+            let mut cell = SecurityCell::new("steady");
+            let expected = cell.genome.equilibrium_energy(threat);
+            for _ in 0..30 {
+                if matches!(cell.tick(&env_with_threat(threat)), CellAction::Die) {
+                    cell.state.energy = 0.0;
+                    break;
+                }
+            }
+            assert!(
+                (cell.state.energy - expected).abs() < 1e-6,
+                "threat {threat}: simulated {} vs analytic {expected}",
+                cell.state.energy
+            );
         }
     }
 
+    #[test]
+    fn stress_settles_at_floor_without_threat() {
+        let mut cell = SecurityCell::new("vigilant");
+        cell.state.stress_level = 0.9;
+        cell.genome.stress_floor = 0.3;
+        for _ in 0..40 {
+            cell.tick(&env_with_threat(0.0));
+        }
+        assert!((cell.state.stress_level - 0.3).abs() < 1e-6);
+
+        // A floor above the differentiation threshold is capped to it.
+        cell.genome.stress_floor = 0.9;
+        cell.genome.stress_differentiation_threshold = 0.5;
+        cell.state.lineage = CellLineage::IntrusionDetection;
+        cell.tick(&env_with_threat(0.0));
+        assert!((cell.state.stress_level - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn calm_energetic_stem_cell_emits_cooperative() {
+        let mut cell = SecurityCell::new("host");
+        assert!(matches!(cell.tick(&env_with_threat(0.0)), CellAction::Idle));
+
+        cell.genome.cooperative_emission_threshold = 0.8;
+        match cell.tick(&env_with_threat(0.0)) {
+            CellAction::EmitSignal(topic, value) => {
+                assert_eq!(topic, "cooperative");
+                assert!(value >= 0.8);
+            }
+            other => panic!("expected cooperative emission, got {other:?}"),
+        }
+
+        cell.state.stress_level = 0.9;
+        cell.genome.stress_decay = 1.0;
+        assert!(!matches!(
+            cell.tick(&env_with_threat(0.0)),
+            CellAction::EmitSignal(_, _)
+        ));
+    }
+
     #[test]
     fn cell_replicates_when_threat_exceeds_threshold() {
         let mut cell = SecurityCell::new("alpha");
@@ -458,6 +1270,251 @@ mod tests {
         assert!(cell.state.energy < 1.0);
     }
 
+    #[test]
+    fn high_population_density_suppresses_replication() {
+        let crowded_env = |density: f32| CellEnvironment {
+            population_density: density,
+            ..env_with_threat(0.85)
+        };
+
+        let mut sparse = SecurityCell::new("sparse");
+        sparse.genome.reproduction_threshold = 0.5;
+        sparse.genome.density_sensitivity = 1.0;
+        assert!(matches!(
+            sparse.tick(&crowded_env(0.1)),
+            CellAction::Replicate(_)
+        ));
+
+        let mut crowded = SecurityCell::new("crowded");
+        crowded.genome.reproduction_threshold = 0.5;
+        crowded.genome.density_sensitivity = 1.0;
+        let action = crowded.tick(&crowded_env(0.9));
+        assert!(!matches!(action, CellAction::Replicate(_)), "{action:?}");
+    }
+
+    #[test]
+    fn low_initial_trust_quarantines_neighbor_sooner() {
+        let steps_until_quarantine = |initial_trust: f32| {
+            let mut cell = SecurityCell::new("receiver");
+            cell.genome.initial_trust = initial_trust;
+            for step in 0..10 {
+                // Unattested consensus traffic costs the sender trust each step.
+                let environment = CellEnvironment {
+                    step,
+                    neighbor_signals: vec![Signal::new("consensus:vote", 1.0).with_source("peer")]
+                        .into(),
+                    detected_neighbors: vec!["peer".to_string()],
+                    ..Default::default()
+                };
+                if matches!(cell.tick(&environment), CellAction::Disconnect(ref id) if id == "peer")
+                {
+                    return Some(step);
+                }
+            }
+            None
+        };
+
+        let wary = steps_until_quarantine(0.3).expect("wary cell quarantines");
+        let neutral = steps_until_quarantine(NEUTRAL_TRUST).expect("neutral cell quarantines");
+        assert!(wary < neutral, "wary {wary} vs neutral {neutral}");
+    }
+
+    #[test]
+    fn low_trust_attenuates_neighbor_activator() {
+        let emitted_threat = |trust: f32| {
+            let mut cell = SecurityCell::new("receiver");
+            cell.genome.signal_emission_threshold = 0.1;
+            cell.state.neighbor_trust.insert("peer".to_string(), trust);
+            let environment = CellEnvironment {
+                neighbor_signals: vec![Signal::new("activator", 0.5).with_source("peer")].into(),
+                detected_neighbors: vec!["peer".to_string()],
+                ..Default::default()
+            };
+            match cell.tick(&environment) {
+                CellAction::EmitSignal(_, value) => value,
+                other => panic!("expected activator emission, got {other:?}"),
+            }
+        };
+
+        let trusted = emitted_threat(1.0);
+        let confident = emitted_threat(0.9);
+        let neutral = emitted_threat(0.5);
+        let doubted = emitted_threat(0.3);
+        assert!((trusted - 0.5).abs() < 1e-6);
+        assert!((confident - 0.45).abs() < 1e-6);
+        assert!((neutral - 0.25).abs() < 1e-6);
+        assert!((doubted - 0.15).abs() < 1e-6);
+        assert!(neutral < confident);
+        assert!(doubted < neutral);
+    }
+
+    #[test]
+    fn disabling_inhibitor_energy_cost_keeps_threat_reduction() {
+        let run = |energy_cost_enabled: bool| {
+            let mut cell = SecurityCell::new("calm");
+            cell.state.energy = 0.5;
+            cell.genome.energy_inhibitor_drain = 0.5;
+            cell.genome.healer_inhibitor_threshold = 10.0;
+            cell.genome.signal_emission_threshold = 0.0;
+            cell.genome.inhibitor_energy_cost_enabled = energy_cost_enabled;
+            let environment = CellEnvironment {
+                local_threat_score: 0.8,
+                neighbor_signals: vec![Signal::new("inhibitor", 1.0)].into(),
+                ..Default::default()
+            };
+            let threat = match cell.tick(&environment) {
+                CellAction::EmitSignal(_, value) => value,
+                other => panic!("expected emission, got {other:?}"),
+            };
+            (threat, cell.state.energy)
+        };
+
+        let (threat_on, energy_on) = run(true);
+        let (threat_off, energy_off) = run(false);
+        // Threat reduction is identical and still below the raw 0.8.
+        assert!((threat_on - threat_off).abs() < 1e-6);
+        assert!(threat_off < 0.8);
+        // Energy: 0.5 + recharge - threat drain, minus inhibitor drain only when enabled.
+        let expected_off = 0.5 + 0.15 - threat_off * 0.15;
+        assert!((energy_off - expected_off).abs() < 1e-6);
+        assert!((energy_on - (expected_off - 0.5)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn effective_threat_matches_hand_computed_signal_math() {
+        let mut cell = SecurityCell::new("probe");
+        cell.genome.threat_inhibitor_factor = 0.5;
+        cell.state
+            .neighbor_trust
+            .insert("doubted".to_string(), 0.25);
+        let signal = |topic: &str, value: f32, source: Option<&str>| Signal {
+            source: source.map(str::to_string),
+            ..Signal::new(topic, value)
+        };
+        let environment = CellEnvironment {
+            step: 3,
+            local_threat_score: 0.2,
+            neighbor_signals: vec![
+                signal("activator", 0.4, None),
+                signal("activator", 0.3, Some("peer")),
+                signal("activator", 0.6, Some("doubted")),
+                signal("inhibitor", 0.5, None),
+            ]
+            .into(),
+            detected_neighbors: vec!["peer".to_string(), "doubted".to_string()],
+            ..Default::default()
+        };
+
+        // activator = 0.4 + 0.3 * 0.5 (neutral trust) + 0.6 * 0.25 = 0.7
+        // effective = 0.2 + 0.7 - 0.5 * 0.5 = 0.65
+        let expected = 0.65;
+        let trust_before = cell.state.neighbor_trust.clone();
+        assert!((cell.effective_threat(&environment) - expected).abs() < 1e-6);
+        assert_eq!(cell.state.neighbor_trust, trust_before);
+        assert!((cell.state.energy - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn custom_topic_mapped_to_activator_raises_effective_threat() {
+        let cell = SecurityCell::new("probe");
+        let signals: Arc<[Signal]> =
+            vec![Signal::new("ids.alert", 0.6), Signal::new("unmapped", 0.9)].into();
+        let recognized = Arc::new(HashMap::from([(
+            "ids.alert".to_string(),
+            SignalChannel::Activator,
+        )]));
+        let environment = |recognized_topics, indexed: bool| CellEnvironment {
+            neighbor_signals: Arc::clone(&signals),
+            topic_index: indexed.then(|| Arc::new(TopicSignals::new(Arc::clone(&signals)))),
+            recognized_topics,
+            ..env_with_threat(0.2)
+        };
+
+        assert!((cell.effective_threat(&environment(None, false)) - 0.2).abs() < 1e-6);
+        for indexed in [false, true] {
+            let threat = cell.effective_threat(&environment(Some(recognized.clone()), indexed));
+            assert!((threat - 0.8).abs() < 1e-6, "indexed={indexed}: {threat}");
+        }
+    }
+
+    #[test]
+    fn encryption_drain_multiplier_burns_energy_faster_than_stem() {
+        let economy = Arc::new(LineageEconomy {
+            multipliers: BTreeMap::from([(
+                CellLineage::Encryption,
+                EnergyMultipliers {
+                    recharge: 1.0,
+                    drain: 2.0,
+                },
+            )]),
+        });
+        let environment = CellEnvironment {
+            lineage_economy: Some(economy),
+            ..env_with_threat(0.8)
+        };
+        let energy_after_tick = |lineage: CellLineage| {
+            let mut cell = SecurityCell::new("worker");
+            cell.state.lineage = lineage;
+            cell.state.energy = 1.0;
+            cell.tick(&environment);
+            cell.state.energy
+        };
+
+        let stem = energy_after_tick(CellLineage::Stem);
+        let encryption = energy_after_tick(CellLineage::Encryption);
+        // Stem: 1.0 + 0.15 - 0.8 * 0.15 = 1.03; Encryption drains twice as hard.
+        assert!((stem - 1.03).abs() < 1e-6, "{stem}");
+        assert!((encryption - 0.91).abs() < 1e-6, "{encryption}");
+
+        let default_economy = env_with_threat(0.8);
+        let mut plain = SecurityCell::new("worker");
+        plain.state.lineage = CellLineage::Encryption;
+        plain.tick(&default_economy);
+        assert!((plain.state.energy - stem).abs() < 1e-6);
+    }
+
+    #[test]
+    fn inhibitor_adaptation_entry_adjusts_a_different_gene_than_activator() {
+        let event = |topic: &str| ThreatEvent {
+            step: 0,
+            topic: topic.to_string(),
+            magnitude: 1.0,
+            confidence: 1.0,
+        };
+        let mut table = AdaptationTable::default();
+        table.entries.insert(
+            "inhibitor".to_string(),
+            vec![GeneAdjustment::new("energy_inhibitor_drain", -0.5)],
+        );
+        let baseline = CellGenome::default();
+
+        let mut activator = CellGenome::default();
+        activator.adapt_to_event(&event("activator"), &table);
+        assert!(activator.stress_sensitivity < baseline.stress_sensitivity);
+        assert!(activator.threat_inhibitor_factor > baseline.threat_inhibitor_factor);
+        assert_eq!(
+            activator.energy_inhibitor_drain,
+            baseline.energy_inhibitor_drain
+        );
+
+        let mut inhibitor = CellGenome::default();
+        inhibitor.adapt_to_event(&event("inhibitor"), &table);
+        assert!((inhibitor.energy_inhibitor_drain - 0.05).abs() < 1e-6);
+        assert_eq!(inhibitor.stress_sensitivity, baseline.stress_sensitivity);
+        assert_eq!(
+            inhibitor.threat_inhibitor_factor,
+            baseline.threat_inhibitor_factor
+        );
+
+        // Repeated events stay inside the gene's valid range.
+        for _ in 0..200 {
+            inhibitor.adapt_to_event(&event("inhibitor"), &table);
+            activator.adapt_to_event(&event("activator"), &table);
+        }
+        assert_eq!(inhibitor.energy_inhibitor_drain, 0.01);
+        assert_eq!(activator.threat_inhibitor_factor, 2.0);
+    }
+
     #[test]
     fn cell_differentiates_under_stress() {
         let mut cell = SecurityCell::new("beta");
@@ -476,18 +1533,11 @@ mod tests {
     fn cell_transitions_to_healer_with_inhibitor_support() {
         let mut cell = SecurityCell::new("gamma");
         cell.state.stress_level = 0.2;
-        let signals = vec![Signal {
-            topic: "inhibitor".to_string(),
-            value: 0.65,
-            source: None,
-            target: None,
-            attestation: None,
-        }];
+        let signals = vec![Signal::new("inhibitor", 0.65)];
         let environment = CellEnvironment {
-            step: 0,
             local_threat_score: 0.05,
-            neighbor_signals: signals,
-            detected_neighbors: Vec::new(),
+            neighbor_signals: signals.into(),
+            ..Default::default()
         };
         let action = cell.tick(&environment);
         match action {
@@ -503,18 +1553,11 @@ mod tests {
         let mut cell = SecurityCell::new("delta");
         // Explicitly set threshold to ensure test passes regardless of default hardening
         cell.genome.signal_emission_threshold = 0.4;
-        let signals = vec![Signal {
-            topic: "activator".to_string(),
-            value: 0.1,
-            source: None,
-            target: None,
-            attestation: None,
-        }];
+        let signals = vec![Signal::new("activator", 0.1)];
         let environment = CellEnvironment {
-            step: 0,
             local_threat_score: 0.45,
-            neighbor_signals: signals,
-            detected_neighbors: Vec::new(),
+            neighbor_signals: signals.into(),
+            ..Default::default()
         };
         let action = cell.tick(&environment);
         match action {
@@ -573,23 +1616,65 @@ mod tests {
         let payload = "consensus:activator:2.0:neighbor_2".to_string();
         let attestation = neighbor_tpm.attest(0, &payload).unwrap();
 
-        environment.neighbor_signals.push(Signal {
-            topic: "consensus:activator".to_string(),
-            value: 2.0,
-            source: Some("neighbor_1".to_string()),
-            target: Some("neighbor_2".to_string()),
-            attestation: Some(attestation),
-        });
+        environment.neighbor_signals = vec![
+            Signal::new("consensus:activator", 2.0)
+                .with_source("neighbor_1")
+                .with_target("neighbor_2")
+                .with_attestation(attestation),
+        ]
+        .into();
 
         let action = cell.tick(&environment);
         match action {
-            CellAction::Disconnect(target) => {
+            CellAction::Quarantine(target) => {
                 assert_eq!(target, "neighbor_2");
             }
-            other => panic!("expected coordinated disconnect of neighbor_2, got {other:?}"),
+            other => panic!("expected coordinated quarantine of neighbor_2, got {other:?}"),
         }
     }
 
+    #[test]
+    fn quarantine_vote_threshold_sets_how_many_voters_convict() {
+        let convicted = |threshold: f32, voters: usize| {
+            let pki = Pki::new();
+            let votes: Vec<Signal> = (0..voters)
+                .map(|idx| {
+                    let voter = format!("voter-{idx}");
+                    let tpm = TPM::generate(voter.clone());
+                    pki.register(&tpm);
+                    let vote = Signal::new("consensus:activator", 0.9)
+                        .with_source(voter)
+                        .with_target("suspect");
+                    let attestation = tpm.attest(0, &attestation_payload(&vote));
+                    Signal {
+                        attestation,
+                        ..vote
+                    }
+                })
+                .collect();
+            let mut detected_neighbors: Vec<String> =
+                (0..voters).map(|idx| format!("voter-{idx}")).collect();
+            detected_neighbors.push("suspect".to_string());
+            let mut cell = SecurityCell::new("judge");
+            cell.genome.quarantine_vote_threshold = threshold;
+            let action = cell.tick(&CellEnvironment {
+                neighbor_signals: votes.into(),
+                detected_neighbors,
+                pki: Some(pki),
+                ..Default::default()
+            });
+            matches!(action, CellAction::Quarantine(ref id) if id == "suspect")
+        };
+
+        // Default: one confident voter is not enough, two are.
+        let default = CellGenome::default().quarantine_vote_threshold;
+        assert!(!convicted(default, 1));
+        assert!(convicted(default, 2));
+        // Raising the bar to 2.0 takes a third voter.
+        assert!(!convicted(2.0, 2));
+        assert!(convicted(2.0, 3));
+    }
+
     #[test]
     fn test_trust_score_disconnection() {
         let mut cell = SecurityCell::new("lambda");
@@ -597,18 +1682,12 @@ mod tests {
         cell.genome.trust_penalty = 0.4;
 
         // Signal from "untrusted_neighbor" with INVALID attestation (missing) for consensus topic
-        let signals = vec![Signal {
-            topic: "consensus:activator".to_string(),
-            value: 1.0,
-            source: Some("untrusted_neighbor".to_string()),
-            target: None,
-            attestation: None,
-        }];
+        let signals =
+            vec![Signal::new("consensus:activator", 1.0).with_source("untrusted_neighbor")];
         let env = CellEnvironment {
-            step: 0,
-            local_threat_score: 0.0,
-            neighbor_signals: signals,
+            neighbor_signals: signals.into(),
             detected_neighbors: vec!["untrusted_neighbor".to_string()],
+            ..Default::default()
         };
 
         let action = cell.tick(&env);
@@ -625,20 +1704,19 @@ mod tests {
         let mut cell = SecurityCell::new("kappa");
         cell.state.lineage = CellLineage::IntrusionDetection;
         cell.genome.anomaly_sensitivity = 0.4;
+        cell.state
+            .neighbor_trust
+            .insert("attacker".to_string(), 1.0);
+        let pki = Pki::new();
+        pki.register(&cell.tpm);
 
         // Add a "threat" source to be accused
-        let signals = vec![Signal {
-            topic: "activator".to_string(),
-            value: 0.6,
-            source: Some("attacker".to_string()),
-            target: None,
-            attestation: None,
-        }];
+        let signals = vec![Signal::new("activator", 0.6).with_source("attacker")];
         let environment = CellEnvironment {
             step: 5,
-            local_threat_score: 0.0,
-            neighbor_signals: signals,
+            neighbor_signals: signals.into(),
             detected_neighbors: vec!["attacker".to_string()],
+            ..Default::default()
         };
 
         let action = cell.tick(&environment);
@@ -653,12 +1731,88 @@ mod tests {
                 // Verify the attestation manually to ensure binding works
                 // NOTE: The signed payload uses "consensus:activator"
                 let payload = format!("consensus:{}:{:.1}:{}", topic, confidence, "attacker");
-                assert!(TPM::verify(&att, 5, &payload));
+                assert!(pki.verify(&att, 5, &payload));
+                assert!(!Pki::global().verify(&att, 5, &payload));
             }
             other => panic!("expected anomaly report, got {other:?}"),
         }
     }
 
+    #[test]
+    fn dominant_custom_threat_topic_adapts_its_configured_gene_on_tick() {
+        let mut table = AdaptationTable::default();
+        table.entries.insert(
+            "port_scan".to_string(),
+            vec![GeneAdjustment::new("energy_inhibitor_drain", -0.5)],
+        );
+        let environment = CellEnvironment {
+            neighbor_signals: vec![Signal::new("port_scan", 0.6), Signal::new("activator", 0.1)]
+                .into(),
+            recognized_topics: Some(Arc::new(HashMap::from([(
+                "port_scan".to_string(),
+                SignalChannel::Activator,
+            )]))),
+            adaptation_table: Some(Arc::new(table)),
+            ..env_with_threat(0.0)
+        };
+        let mut cell = SecurityCell::new("sentinel");
+        cell.state.lineage = CellLineage::IntrusionDetection;
+        cell.genome.anomaly_sensitivity = 0.5;
+        let baseline = cell.genome.clone();
+
+        assert!(matches!(
+            cell.tick(&environment),
+            CellAction::ReportAnomaly(..)
+        ));
+        let remembered = cell.state.immune_memory.last().expect("event recorded");
+        assert_eq!(remembered.topic, "port_scan");
+        assert!(cell.genome.energy_inhibitor_drain < baseline.energy_inhibitor_drain);
+        assert_eq!(cell.genome.stress_sensitivity, baseline.stress_sensitivity);
+        assert_eq!(
+            cell.genome.threat_inhibitor_factor,
+            baseline.threat_inhibitor_factor
+        );
+
+        // The cooldown applies to the topic that was recorded.
+        cell.tick(&CellEnvironment {
+            step: 1,
+            ..environment
+        });
+        assert_eq!(cell.state.immune_memory.len(), 1);
+    }
+
+    #[test]
+    fn stronger_threats_report_anomalies_with_higher_confidence() {
+        let report = |threat: f32| {
+            let mut cell = SecurityCell::new("sentinel");
+            cell.state.lineage = CellLineage::IntrusionDetection;
+            cell.genome.anomaly_sensitivity = 0.5;
+            match cell.tick(&env_with_threat(threat)) {
+                CellAction::ReportAnomaly(_, confidence, _, _) => {
+                    let remembered = cell.state.immune_memory.last().expect("event recorded");
+                    assert_eq!(remembered.confidence, confidence);
+                    confidence
+                }
+                other => panic!("expected anomaly report, got {other:?}"),
+            }
+        };
+
+        let marginal = report(0.55);
+        let strong = report(1.2);
+        assert!(marginal > 0.5 && marginal < 0.6, "{marginal}");
+        assert!(strong > marginal + 0.3, "{strong} vs {marginal}");
+        assert!(strong < 1.0);
+
+        let mut genome = CellGenome {
+            anomaly_sensitivity: 0.5,
+            ..CellGenome::default()
+        };
+        let gentle = genome.anomaly_confidence(0.7);
+        genome.anomaly_confidence_gain *= 4.0;
+        assert!(genome.anomaly_confidence(0.7) > gentle);
+        assert_eq!(genome.anomaly_confidence(0.5), 0.5);
+    }
+
     #[test]
     fn test_immune_adaptation_and_inheritance() {
         let mut parent = SecurityCell::new("parent");
@@ -669,8 +1823,7 @@ mod tests {
         let env = CellEnvironment {
             step: 10,
             local_threat_score: 0.6,
-            neighbor_signals: Vec::new(),
-            detected_neighbors: Vec::new(),
+            ..Default::default()
         };
 
         let _ = parent.tick(&env);
@@ -693,22 +1846,15 @@ mod tests {
         cell.genome.min_trust_threshold = 0.2;
 
         let mut environment = CellEnvironment {
-            step: 0,
-            local_threat_score: 0.0,
-            neighbor_signals: Vec::new(),
             detected_neighbors: vec!["traitor".to_string()],
+            ..Default::default()
         };
 
         // Step 0: Traitor sends unauthenticated consensus signal (Trust 0.5 -> 0.3)
         // We expect Idle (no DoS via NotifyTrustUpdate, and no Disconnect yet)
         environment.step = 0;
-        environment.neighbor_signals = vec![Signal {
-            topic: "consensus:activator".to_string(),
-            value: 1.0,
-            source: Some("traitor".to_string()),
-            target: None,
-            attestation: None,
-        }];
+        environment.neighbor_signals =
+            vec![Signal::new("consensus:activator", 1.0).with_source("traitor")].into();
         let action = cell.tick(&environment);
         assert!(
             matches!(action, CellAction::Idle),
@@ -725,4 +1871,48 @@ mod tests {
             other => panic!("Expected isolation of traitor, got {other:?}"),
         }
     }
+
+    #[test]
+    fn topic_index_skips_inert_topics_without_changing_tick() {
+        let mut signals: Vec<Signal> = (0..1000)
+            .map(|idx| {
+                Signal::new(format!("telemetry:{}", idx % 50), 1.0)
+                    .with_source(format!("peer-{}", idx % 7))
+            })
+            .collect();
+        for value in [0.3, 0.4] {
+            signals.push(Signal::new("activator", value).with_source("peer-1"));
+        }
+        signals.push(Signal::new("inhibitor", 0.1));
+        let signals: Arc<[Signal]> = signals.into();
+        let index = Arc::new(TopicSignals::new(Arc::clone(&signals)));
+
+        // Per-cell work shrinks from every delivered signal to the actionable few.
+        assert_eq!(index.actionable_len(), 3);
+        assert_eq!(index.topics().count(), 52);
+
+        let flat_env = CellEnvironment {
+            neighbor_signals: Arc::clone(&signals),
+            detected_neighbors: vec!["peer-1".to_string()],
+            ..Default::default()
+        };
+        let indexed_env = CellEnvironment {
+            topic_index: Some(index),
+            ..flat_env.clone()
+        };
+
+        let mut flat_cell = SecurityCell::new("flat");
+        flat_cell.genome.signal_emission_threshold = 0.3;
+        let mut indexed_cell = SecurityCell::new("indexed");
+        indexed_cell.genome.signal_emission_threshold = 0.3;
+
+        match (flat_cell.tick(&flat_env), indexed_cell.tick(&indexed_env)) {
+            (CellAction::EmitSignal(_, flat_value), CellAction::EmitSignal(_, indexed_value)) => {
+                assert!((flat_value - indexed_value).abs() < f32::EPSILON);
+            }
+            other => panic!("expected matching emissions, got {other:?}"),
+        }
+        assert!((flat_cell.state.stress_level - indexed_cell.state.stress_level).abs() < 1e-6);
+        assert!((flat_cell.state.energy - indexed_cell.state.energy).abs() < 1e-6);
+    }
 }
@@ -1,12 +1,23 @@
 //! Scenario configuration and loading utilities.
 
+use crate::cellular::{
+    AdaptationTable, CellGenome, CellLineage, EnergyMultipliers, LineageEconomy, LineageRules,
+    SecurityCell,
+};
+use crate::signaling::{MAX_SIGNAL_PERSISTENCE, Signal, SignalChannel};
+use crate::stimulus::StimulusCommand;
+use crate::util::write_atomic;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::fs::File;
 use std::io::{self, Read};
 use std::path::Path;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ScenarioConfig {
     #[serde(default = "default_scenario_name")]
     pub scenario_name: String,
@@ -14,17 +25,68 @@ pub struct ScenarioConfig {
     pub initial_cell_count: usize,
     #[serde(default = "default_simulation_steps")]
     pub simulation_steps: u32,
+    /// Largest `simulation_steps` a run accepts without an explicit
+    /// long-run override; see [`ScenarioConfig::check_step_cap`].
+    #[serde(default = "default_max_simulation_steps")]
+    pub max_simulation_steps: u32,
+    /// Leading steps that are simulated but excluded from collected metrics.
+    #[serde(default)]
+    pub warmup_steps: u32,
     #[serde(default)]
     pub threat_profile: ThreatProfile,
     #[serde(default)]
     pub spikes: Vec<ThreatSpike>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bursts: Vec<ThreatBurst>,
+    /// Automated defense that injects `inhibitor` signals whenever the
+    /// defense level crosses its `spike_threshold`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defense_profile: Option<ThreatProfile>,
+    /// Defense surges layered on top of `defense_profile.background_threat`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub defense_spikes: Vec<ThreatSpike>,
     #[serde(default = "default_cell_reproduction_rate")]
     pub cell_reproduction_rate: f32,
     #[serde(default)]
     pub topology: TopologyConfig,
+    /// Explicit initial population; replaces the `seed-0..N` loop when set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub seed_cells: Vec<SeedCellSpec>,
+    /// Pre-established trust: cell id -> neighbor id -> trust in `[0, 1]`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub initial_trust: BTreeMap<String, BTreeMap<String, f32>>,
+    /// Cap on the cumulative value injected per stimulus topic over a run.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub stimulus_budget: HashMap<String, f32>,
+    /// External stimulus topic -> engine topic (e.g. `attack.ddos: activator`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub topic_aliases: HashMap<String, String>,
+    /// Chaos events that kill a random share of the live population.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fault_profile: Vec<FaultEvent>,
+    /// Seed for choosing which cells a fault kills (default `0`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fault_seed: Option<u64>,
+    /// Signals already on the bus when the run starts, delivered on step 0.
+    /// Cell keys are generated fresh each run, so a pre-loaded attestation
+    /// never verifies; attested topics cost their claimed source trust.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub initial_signals: Vec<Signal>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SeedCellSpec {
+    pub id: String,
+    #[serde(default = "default_seed_lineage")]
+    pub lineage: CellLineage,
+    /// Gene values applied on top of the scenario-derived genome.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub genome_overrides: BTreeMap<String, f32>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ThreatProfile {
     #[serde(default = "default_background_threat")]
     pub background_threat: f32,
@@ -33,10 +95,89 @@ pub struct ThreatProfile {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TopologyConfig {
     pub strategy: TopologyStrategy,
     #[serde(default)]
     pub explicit_links: Option<Vec<Vec<String>>>, // List of [source, target] pairs
+    /// Whether a cell receives signals it sourced itself on the next step.
+    #[serde(default)]
+    pub deliver_self_signals: bool,
+    /// Lineage transitions cells may take when differentiating.
+    #[serde(default, skip_serializing_if = "LineageRules::is_default")]
+    pub lineage_rules: LineageRules,
+    /// Per-lineage multipliers on energy recharge and drain.
+    #[serde(default, skip_serializing_if = "LineageEconomy::is_default")]
+    pub lineage_economy: LineageEconomy,
+    /// Threat topic -> genome adjustments cells make when they record a
+    /// threat event on that topic.
+    #[serde(default, skip_serializing_if = "AdaptationTable::is_default")]
+    pub adaptation_table: AdaptationTable,
+    /// Emit `TrustSnapshot` telemetry every N steps; 0 disables snapshots.
+    #[serde(default)]
+    pub trust_snapshot_interval: u32,
+    /// Upper bound on cells included in each trust snapshot.
+    #[serde(default = "default_trust_snapshot_max_cells")]
+    pub trust_snapshot_max_cells: usize,
+    /// Graph mode: maximum links per cell; `None` leaves degree unbounded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_degree: Option<usize>,
+    /// Fraction of each signal's value carried over to the next step, at
+    /// most 0.99; 0 drains the bus completely every step.
+    #[serde(default)]
+    pub signal_persistence: f32,
+    /// Compute `PopulationStats` every N steps (0 disables them); `None`
+    /// samples every 10th step, or every step below 500 cells.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub population_stats_interval: Option<u32>,
+    /// Per-cell cap on signals delivered each step; the highest-value signals
+    /// are kept. `None` delivers everything.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_incoming_signals: Option<usize>,
+    /// Replications allowed per step; later `Replicate` actions idle instead.
+    /// `None` leaves replication unbounded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_replications_per_step: Option<usize>,
+    /// Report how many cells idled in each `StepSummary`.
+    #[serde(default)]
+    pub record_idle: bool,
+    /// Keep every cell's per-step `CellAction` in memory for debugging.
+    #[serde(default)]
+    pub trace_actions: bool,
+    /// Most recent trace entries retained when `trace_actions` is set.
+    #[serde(default = "default_action_trace_cap")]
+    pub action_trace_cap: usize,
+    /// Graph mode: times an attested consensus signal may be re-broadcast
+    /// past its sender's neighbors. 0 disables relaying.
+    #[serde(default)]
+    pub consensus_relay_hops: u32,
+    /// Probability that any one signal is dropped on its way to any one
+    /// recipient. 0 delivers everything.
+    #[serde(default)]
+    pub signal_loss_rate: f32,
+    /// Seed for the RNG that decides which signals are lost (default `0`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_loss_seed: Option<u64>,
+    /// Emit `GenomeMutated` telemetry listing the genes that changed in each
+    /// replicated child.
+    #[serde(default)]
+    pub record_genome_mutations: bool,
+    /// Custom topic name -> internal channel, so cells react to
+    /// domain-specific topics alongside the built-in names.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub recognized_topics: HashMap<String, SignalChannel>,
+    /// Step-over-step growth factor in emitted activator volume that counts
+    /// as runaway feedback once sustained; `None` disables the probe.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feedback_growth_threshold: Option<f32>,
+}
+
+fn default_action_trace_cap() -> usize {
+    10_000
+}
+
+fn default_trust_snapshot_max_cells() -> usize {
+    16
 }
 
 impl Default for TopologyConfig {
@@ -44,11 +185,32 @@ impl Default for TopologyConfig {
         Self {
             strategy: TopologyStrategy::Global,
             explicit_links: None,
+            deliver_self_signals: false,
+            lineage_rules: LineageRules::default(),
+            lineage_economy: LineageEconomy::default(),
+            adaptation_table: AdaptationTable::default(),
+            trust_snapshot_interval: 0,
+            trust_snapshot_max_cells: default_trust_snapshot_max_cells(),
+            max_degree: None,
+            signal_persistence: 0.0,
+            population_stats_interval: None,
+            max_incoming_signals: None,
+            max_replications_per_step: None,
+            record_idle: false,
+            trace_actions: false,
+            action_trace_cap: default_action_trace_cap(),
+            consensus_relay_hops: 0,
+            signal_loss_rate: 0.0,
+            signal_loss_seed: None,
+            record_genome_mutations: false,
+            recognized_topics: HashMap::new(),
+            feedback_growth_threshold: None,
         }
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum TopologyStrategy {
     Global, // Broadcast to all
     Graph,  // Explicit neighbor list
@@ -74,14 +236,30 @@ impl Default for ScenarioConfig {
             scenario_name: default_scenario_name(),
             initial_cell_count: default_initial_cells(),
             simulation_steps: default_simulation_steps(),
+            max_simulation_steps: default_max_simulation_steps(),
+            warmup_steps: 0,
             threat_profile: ThreatProfile::default(),
             spikes: Vec::new(),
+            bursts: Vec::new(),
+            defense_profile: None,
+            defense_spikes: Vec::new(),
             cell_reproduction_rate: default_cell_reproduction_rate(),
             topology: TopologyConfig::default(),
+            seed_cells: Vec::new(),
+            initial_trust: BTreeMap::new(),
+            stimulus_budget: HashMap::new(),
+            topic_aliases: HashMap::new(),
+            fault_profile: Vec::new(),
+            fault_seed: None,
+            initial_signals: Vec::new(),
         }
     }
 }
 
+fn default_seed_lineage() -> CellLineage {
+    CellLineage::Stem
+}
+
 fn default_scenario_name() -> String {
     "baseline".to_string()
 }
@@ -94,6 +272,10 @@ fn default_simulation_steps() -> u32 {
     1
 }
 
+fn default_max_simulation_steps() -> u32 {
+    1_000_000
+}
+
 fn default_background_threat() -> f32 {
     0.1
 }
@@ -107,6 +289,7 @@ fn default_cell_reproduction_rate() -> f32 {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ThreatSpike {
     pub step: u32,
     pub intensity: f32,
@@ -118,10 +301,34 @@ fn default_spike_duration() -> u32 {
     1
 }
 
+/// Kill `kill_fraction` of the live cells at `step`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FaultEvent {
+    pub step: u32,
+    pub kill_fraction: f32,
+}
+
+/// Flat-intensity attack window that ends abruptly ("burst then silence").
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ThreatBurst {
+    pub start_step: u32,
+    pub duration: u32,
+    pub intensity: f32,
+}
+
+impl ThreatBurst {
+    pub fn is_active(&self, step: u32) -> bool {
+        step >= self.start_step && step - self.start_step < self.duration
+    }
+}
+
 #[derive(Debug)]
 pub enum ConfigError {
     Io(io::Error),
     Parse(serde_yaml::Error),
+    Invalid(String),
 }
 
 impl fmt::Display for ConfigError {
@@ -129,6 +336,7 @@ impl fmt::Display for ConfigError {
         match self {
             ConfigError::Io(err) => write!(f, "I/O error while reading config: {err}"),
             ConfigError::Parse(err) => write!(f, "Failed to parse config: {err}"),
+            ConfigError::Invalid(reason) => write!(f, "Invalid config: {reason}"),
         }
     }
 }
@@ -138,6 +346,7 @@ impl std::error::Error for ConfigError {
         match self {
             ConfigError::Io(err) => Some(err),
             ConfigError::Parse(err) => Some(err),
+            ConfigError::Invalid(_) => None,
         }
     }
 }
@@ -162,18 +371,290 @@ pub fn load_from_path<P: AsRef<Path>>(path: P) -> Result<ScenarioConfig, ConfigE
 pub fn load_from_reader<R: Read>(mut reader: R) -> Result<ScenarioConfig, ConfigError> {
     let mut buf = String::new();
     reader.read_to_string(&mut buf)?;
-    let config = serde_yaml::from_str(&buf)?;
+    let config: ScenarioConfig = serde_yaml::from_str(&buf)?;
+    config.validate()?;
     Ok(config)
 }
 
+/// JSON Schema describing `ScenarioConfig` and its nested types, for editor
+/// validation and autocompletion of scenario files.
+#[cfg(feature = "schema")]
+pub fn json_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(ScenarioConfig))
+        .expect("generated schema is valid JSON")
+}
+
+/// A field whose value differs between two configs.
+///
+/// `path` uses dotted names with `[index]` for list entries, e.g.
+/// `spikes[1].intensity`. A side is `None` when the field or list entry is
+/// absent from that config.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    pub path: String,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |value: &Option<String>| value.clone().unwrap_or_else(|| "<absent>".into());
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            side(&self.left),
+            side(&self.right)
+        )
+    }
+}
+
+/// Field-by-field differences between two parsed configs, independent of
+/// YAML key order. Lists such as `spikes` are compared per index.
+pub fn diff(a: &ScenarioConfig, b: &ScenarioConfig) -> Vec<FieldDiff> {
+    let as_value =
+        |config: &ScenarioConfig| serde_json::to_value(config).unwrap_or(serde_json::Value::Null);
+    let mut diffs = Vec::new();
+    diff_values(
+        String::new(),
+        Some(&as_value(a)),
+        Some(&as_value(b)),
+        &mut diffs,
+    );
+    diffs
+}
+
+fn diff_values(
+    path: String,
+    left: Option<&serde_json::Value>,
+    right: Option<&serde_json::Value>,
+    diffs: &mut Vec<FieldDiff>,
+) {
+    use serde_json::Value;
+    // Compare a container present on one side only against an empty one so
+    // its leaves are reported individually.
+    let empty = |other: Option<&Value>| match other {
+        Some(Value::Object(_)) => Some(Value::Object(Default::default())),
+        Some(Value::Array(_)) => Some(Value::Array(Vec::new())),
+        _ => None,
+    };
+    let (left_empty, right_empty) = (empty(right), empty(left));
+    let left = left.or(left_empty.as_ref());
+    let right = right.or(right_empty.as_ref());
+    match (left, right) {
+        (Some(Value::Object(left)), Some(Value::Object(right))) => {
+            let keys: std::collections::BTreeSet<&String> =
+                left.keys().chain(right.keys()).collect();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_values(child, left.get(key), right.get(key), diffs);
+            }
+        }
+        (Some(Value::Array(left)), Some(Value::Array(right))) => {
+            for index in 0..left.len().max(right.len()) {
+                diff_values(
+                    format!("{path}[{index}]"),
+                    left.get(index),
+                    right.get(index),
+                    diffs,
+                );
+            }
+        }
+        (left, right) if left == right => {}
+        (left, right) => diffs.push(FieldDiff {
+            path,
+            left: left.map(render_value),
+            right: right.map(render_value),
+        }),
+    }
+}
+
+/// Config floats are `f32`; print them at that precision rather than as the
+/// widened `f64` that `serde_json` stores.
+fn render_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Number(number) if number.is_f64() => {
+            (number.as_f64().unwrap_or_default() as f32).to_string()
+        }
+        other => other.to_string(),
+    }
+}
+
 impl ScenarioConfig {
     #[allow(dead_code)]
     pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> Result<(), ConfigError> {
-        let file = File::create(path)?;
-        serde_yaml::to_writer(file, self)?;
+        write_atomic(path, |file| -> Result<(), ConfigError> {
+            serde_yaml::to_writer(file, self)?;
+            Ok(())
+        })
+    }
+
+    /// Reject runs longer than `max_simulation_steps` unless
+    /// `allow_long_run` is set, so a mistyped step count fails fast instead
+    /// of launching an intractable simulation.
+    pub fn check_step_cap(&self, allow_long_run: bool) -> Result<(), ConfigError> {
+        if allow_long_run || self.simulation_steps <= self.max_simulation_steps {
+            return Ok(());
+        }
+        Err(ConfigError::Invalid(format!(
+            "simulation_steps ({}) exceeds max_simulation_steps ({}); raise the cap or pass --allow-long-run",
+            self.simulation_steps, self.max_simulation_steps
+        )))
+    }
+
+    /// Reject seed specs with duplicate ids or unknown gene overrides.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut seen = HashSet::new();
+        let probe = CellGenome::default();
+        for spec in &self.seed_cells {
+            if !seen.insert(spec.id.as_str()) {
+                return Err(ConfigError::Invalid(format!(
+                    "duplicate seed cell id `{}`",
+                    spec.id
+                )));
+            }
+            let mut genome = probe.clone();
+            for (gene, value) in &spec.genome_overrides {
+                if !genome.set_gene(gene, *value) {
+                    return Err(ConfigError::Invalid(format!(
+                        "unknown gene `{gene}` in overrides for seed cell `{}`",
+                        spec.id
+                    )));
+                }
+                let (min, max) = CellGenome::gene_range(gene).unwrap_or((f32::MIN, f32::MAX));
+                if !(min..=max).contains(value) {
+                    return Err(ConfigError::Invalid(format!(
+                        "gene `{gene}` override {value} for seed cell `{}` is outside [{min}, {max}]",
+                        spec.id
+                    )));
+                }
+            }
+            if genome.stress_floor > genome.stress_differentiation_threshold {
+                return Err(ConfigError::Invalid(format!(
+                    "stress_floor {} for seed cell `{}` exceeds its stress_differentiation_threshold {}",
+                    genome.stress_floor, spec.id, genome.stress_differentiation_threshold
+                )));
+            }
+        }
+        if self.warmup_steps >= self.simulation_steps.max(1) {
+            return Err(ConfigError::Invalid(format!(
+                "warmup_steps ({}) must be less than simulation_steps ({})",
+                self.warmup_steps, self.simulation_steps
+            )));
+        }
+        let persistence = self.topology.signal_persistence;
+        if !(0.0..=MAX_SIGNAL_PERSISTENCE).contains(&persistence) {
+            return Err(ConfigError::Invalid(format!(
+                "signal_persistence {persistence} must be in [0, {MAX_SIGNAL_PERSISTENCE}]"
+            )));
+        }
+        let loss_rate = self.topology.signal_loss_rate;
+        if !(0.0..=1.0).contains(&loss_rate) {
+            return Err(ConfigError::Invalid(format!(
+                "signal_loss_rate {loss_rate} must be in [0, 1]"
+            )));
+        }
+        if let Some(threshold) = self.topology.feedback_growth_threshold
+            && !(threshold.is_finite() && threshold > 1.0)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "feedback_growth_threshold {threshold} must be greater than 1"
+            )));
+        }
+        for (lineage, multipliers) in &self.topology.lineage_economy.multipliers {
+            let EnergyMultipliers { recharge, drain } = *multipliers;
+            if !(recharge.is_finite() && drain.is_finite() && recharge >= 0.0 && drain >= 0.0) {
+                return Err(ConfigError::Invalid(format!(
+                    "lineage_economy multipliers for {lineage:?} must be non-negative"
+                )));
+            }
+        }
+        for (topic, adjustments) in &self.topology.adaptation_table.entries {
+            for adjustment in adjustments {
+                if !CellGenome::GENE_NAMES.contains(&adjustment.gene.as_str()) {
+                    return Err(ConfigError::Invalid(format!(
+                        "unknown gene `{}` in adaptation_table entry for `{topic}`",
+                        adjustment.gene
+                    )));
+                }
+                if !adjustment.delta.is_finite() {
+                    return Err(ConfigError::Invalid(format!(
+                        "adaptation_table delta for `{}` on `{topic}` must be finite",
+                        adjustment.gene
+                    )));
+                }
+            }
+        }
+        for (topic, budget) in &self.stimulus_budget {
+            if !budget.is_finite() || *budget < 0.0 {
+                return Err(ConfigError::Invalid(format!(
+                    "stimulus budget {budget} for topic `{topic}` must be non-negative"
+                )));
+            }
+        }
+        for fault in &self.fault_profile {
+            if !(0.0..=1.0).contains(&fault.kill_fraction) {
+                return Err(ConfigError::Invalid(format!(
+                    "fault kill_fraction {} at step {} is outside [0, 1]",
+                    fault.kill_fraction, fault.step
+                )));
+            }
+        }
+        for (cell_id, peers) in &self.initial_trust {
+            for (peer_id, trust) in peers {
+                if !(0.0..=1.0).contains(trust) {
+                    return Err(ConfigError::Invalid(format!(
+                        "initial trust {trust} from `{cell_id}` to `{peer_id}` is outside [0, 1]"
+                    )));
+                }
+            }
+        }
         Ok(())
     }
 
+    /// Build the initial population, honouring `seed_cells` when present.
+    pub fn build_population(&self) -> Vec<SecurityCell> {
+        let seeded = |id: String| {
+            let mut cell = SecurityCell::new(id);
+            cell.genome.reproduction_threshold = self.threat_profile.spike_threshold;
+            if self.cell_reproduction_rate > 0.0 {
+                cell.genome.reproduction_energy_cost /= self.cell_reproduction_rate;
+            }
+            cell
+        };
+
+        let mut cells: Vec<SecurityCell> = if self.seed_cells.is_empty() {
+            (0..self.initial_cell_count.max(1))
+                .map(|idx| seeded(format!("seed-{idx}")))
+                .collect()
+        } else {
+            self.seed_cells
+                .iter()
+                .map(|spec| {
+                    let mut cell = seeded(spec.id.clone());
+                    cell.state.lineage = spec.lineage.clone();
+                    for (gene, value) in &spec.genome_overrides {
+                        cell.genome.set_gene(gene, *value);
+                    }
+                    cell
+                })
+                .collect()
+        };
+
+        for cell in &mut cells {
+            if let Some(peers) = self.initial_trust.get(&cell.id) {
+                cell.state
+                    .neighbor_trust
+                    .extend(peers.iter().map(|(peer, trust)| (peer.clone(), *trust)));
+            }
+        }
+        cells
+    }
+
     #[allow(dead_code)]
     pub fn threat_level_for_step(&self, step: u32) -> f32 {
         let mut threat = self.threat_profile.background_threat;
@@ -182,9 +663,89 @@ impl ScenarioConfig {
                 threat += spike.intensity;
             }
         }
+        for burst in &self.bursts {
+            if burst.is_active(step) {
+                threat += burst.intensity;
+            }
+        }
         threat.max(0.0)
     }
 
+    /// Highest threat the schedule reaches within `simulation_steps`.
+    pub fn peak_threat_level(&self) -> f32 {
+        (0..self.simulation_steps.max(1))
+            .map(|step| self.threat_level_for_step(step))
+            .fold(0.0, f32::max)
+    }
+
+    /// Defense level at `step`, or `None` when no `defense_profile` is set.
+    pub fn defense_level_for_step(&self, step: u32) -> Option<f32> {
+        let profile = self.defense_profile.as_ref()?;
+        let mut level = profile.background_threat;
+        for spike in &self.defense_spikes {
+            if step >= spike.step && step < spike.step + spike.duration {
+                level += spike.intensity;
+            }
+        }
+        Some(level.max(0.0))
+    }
+
+    /// Signals the scenario itself injects at `step`: an `activator` when the
+    /// threat crosses its spike threshold and an `inhibitor` when the defense
+    /// profile does.
+    pub fn scheduled_signals(&self, step: u32) -> Vec<Signal> {
+        let broadcast = |topic: &str, value: f32| Signal::new(topic, value);
+        let mut signals = Vec::new();
+        let threat = self.threat_level_for_step(step);
+        if threat >= self.threat_profile.spike_threshold {
+            signals.push(broadcast("activator", threat));
+        }
+        if let (Some(profile), Some(defense)) =
+            (&self.defense_profile, self.defense_level_for_step(step))
+            && defense >= profile.spike_threshold
+        {
+            signals.push(broadcast("inhibitor", defense));
+        }
+        signals
+    }
+
+    /// Fault events scheduled for `step`.
+    pub fn faults_at(&self, step: u32) -> impl Iterator<Item = &FaultEvent> {
+        self.fault_profile
+            .iter()
+            .filter(move |fault| fault.step == step)
+    }
+
+    /// RNG that picks fault victims, seeded so runs are reproducible.
+    pub fn fault_rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.fault_seed.unwrap_or_default())
+    }
+
+    /// `initial_signals` with their topics canonicalised, ready to publish
+    /// before the first step.
+    pub fn initial_bus_signals(&self) -> impl Iterator<Item = Signal> + '_ {
+        self.initial_signals.iter().map(|signal| Signal {
+            topic: self.canonical_topic(&signal.topic).to_string(),
+            ..signal.clone()
+        })
+    }
+
+    /// Engine topic for an incoming stimulus topic; unmapped topics pass through.
+    pub fn canonical_topic<'a>(&'a self, topic: &'a str) -> &'a str {
+        self.topic_aliases
+            .get(topic)
+            .map(String::as_str)
+            .unwrap_or(topic)
+    }
+
+    /// Signal to publish for a stimulus command, with its topic canonicalised.
+    pub fn stimulus_signal(&self, command: &StimulusCommand) -> Signal {
+        Signal {
+            topic: self.canonical_topic(&command.topic).to_string(),
+            ..command.to_signal()
+        }
+    }
+
     #[allow(dead_code)]
     pub fn apply_mutation(&mut self, mutation: &crate::adversarial::Mutation) {
         use crate::adversarial::Mutation;
@@ -291,6 +852,351 @@ spikes:
         assert!((config.threat_level_for_step(3) - 0.7).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn seed_cells_define_ids_lineages_and_genome_overrides() {
+        let yaml = r#"
+initial_cell_count: 5
+threat_profile:
+  spike_threshold: 0.7
+seed_cells:
+  - id: edge-firewall
+    lineage: Firewall
+  - id: core-ids
+    lineage: IntrusionDetection
+    genome_overrides:
+      anomaly_sensitivity: 0.2
+"#;
+        let config = load_from_reader(yaml.as_bytes()).expect("config should parse");
+        let cells = config.build_population();
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells[0].id, "edge-firewall");
+        assert_eq!(cells[0].state.lineage, CellLineage::Firewall);
+        assert_eq!(cells[1].id, "core-ids");
+        assert_eq!(cells[1].state.lineage, CellLineage::IntrusionDetection);
+        assert!((cells[1].genome.anomaly_sensitivity - 0.2).abs() < f32::EPSILON);
+        assert!((cells[0].genome.reproduction_threshold - 0.7).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn initial_trust_seeds_pairwise_priors() {
+        let yaml = r#"
+seed_cells:
+  - id: alpha
+    genome_overrides:
+      initial_trust: 0.3
+  - id: beta
+initial_trust:
+  alpha:
+    beta: 0.9
+"#;
+        let config = load_from_reader(yaml.as_bytes()).expect("config should parse");
+        let cells = config.build_population();
+        assert!((cells[0].genome.initial_trust - 0.3).abs() < f32::EPSILON);
+        assert_eq!(cells[0].state.neighbor_trust.get("beta"), Some(&0.9));
+        assert!(cells[1].state.neighbor_trust.is_empty());
+
+        let out_of_range = r#"
+initial_trust:
+  alpha:
+    beta: 1.5
+"#;
+        assert!(matches!(
+            load_from_reader(out_of_range.as_bytes()),
+            Err(ConfigError::Invalid(reason)) if reason.contains("beta")
+        ));
+    }
+
+    #[test]
+    fn seed_cells_reject_duplicate_ids_and_unknown_genes() {
+        let duplicate = r#"
+seed_cells:
+  - id: twin
+  - id: twin
+"#;
+        assert!(matches!(
+            load_from_reader(duplicate.as_bytes()),
+            Err(ConfigError::Invalid(reason)) if reason.contains("twin")
+        ));
+
+        let unknown_gene = r#"
+seed_cells:
+  - id: solo
+    genome_overrides:
+      not_a_gene: 1.0
+"#;
+        assert!(matches!(
+            load_from_reader(unknown_gene.as_bytes()),
+            Err(ConfigError::Invalid(reason)) if reason.contains("not_a_gene")
+        ));
+    }
+
+    #[test]
+    fn seed_cell_stress_floor_must_not_exceed_differentiation_threshold() {
+        let with_floor = |floor: f32| {
+            load_from_reader(
+                format!(
+                    "seed_cells:\n  - id: jittery\n    genome_overrides:\n      stress_floor: {floor}\n      stress_differentiation_threshold: 0.6\n"
+                )
+                .as_bytes(),
+            )
+        };
+        assert!(matches!(
+            with_floor(0.8),
+            Err(ConfigError::Invalid(reason)) if reason.contains("stress_floor")
+        ));
+        assert!(with_floor(0.6).is_ok());
+    }
+
+    #[test]
+    fn seed_cell_overrides_outside_gene_range_are_rejected() {
+        let with_override = |value: &str| {
+            load_from_reader(
+                format!("seed_cells:\n  - id: solo\n    genome_overrides:\n      stress_decay: {value}\n")
+                    .as_bytes(),
+            )
+        };
+        assert!(matches!(
+            with_override("1.5"),
+            Err(ConfigError::Invalid(reason)) if reason.contains("stress_decay")
+        ));
+        assert!(matches!(
+            with_override(".nan"),
+            Err(ConfigError::Invalid(_))
+        ));
+
+        let config = with_override("1.0").expect("upper bound is inclusive");
+        assert_eq!(config.build_population()[0].genome.stress_decay, 1.0);
+    }
+
+    #[test]
+    fn diff_reports_only_changed_fields() {
+        let base = "\
+scenario_name: base
+simulation_steps: 10
+threat_profile:
+  background_threat: 0.3
+spikes:
+  - step: 2
+    intensity: 0.5
+";
+        // Same config with keys reordered and one value changed.
+        let longer = "\
+spikes:
+  - intensity: 0.5
+    step: 2
+threat_profile:
+  background_threat: 0.3
+simulation_steps: 20
+scenario_name: base
+";
+        let a = load_from_reader(base.as_bytes()).expect("base parses");
+        let b = load_from_reader(longer.as_bytes()).expect("variant parses");
+        assert_eq!(
+            diff(&a, &b),
+            vec![FieldDiff {
+                path: "simulation_steps".into(),
+                left: Some("10".into()),
+                right: Some("20".into()),
+            }]
+        );
+        assert!(diff(&a, &a).is_empty());
+
+        let mut c = a.clone();
+        c.threat_profile.background_threat = 0.5;
+        c.spikes.push(ThreatSpike {
+            step: 4,
+            intensity: 0.9,
+            duration: 1,
+        });
+        let diffs = diff(&a, &c);
+        let paths: Vec<&str> = diffs.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "spikes[1].duration",
+                "spikes[1].intensity",
+                "spikes[1].step",
+                "threat_profile.background_threat",
+            ]
+        );
+        assert_eq!(diffs[1].left, None);
+        assert_eq!(diffs[1].right.as_deref(), Some("0.9"));
+        assert_eq!(
+            diffs[3].to_string(),
+            "threat_profile.background_threat: 0.3 -> 0.5"
+        );
+    }
+
+    #[test]
+    fn aliased_stimulus_topic_reaches_cells_as_canonical_topic() {
+        use crate::orchestration::MorphogeneticApp;
+        use crate::telemetry::InMemorySink;
+
+        let command = StimulusCommand {
+            step: 0,
+            topic: "attack.ddos".to_string(),
+            value: 0.8,
+            target: None,
+            source: None,
+            duration: 1,
+        };
+        let stress_after = |config: &ScenarioConfig| {
+            let signal = config.stimulus_signal(&command);
+            let mut app = MorphogeneticApp::new(
+                config.build_population(),
+                InMemorySink::default(),
+                config.topology.clone(),
+            );
+            app.inject_signal(signal);
+            app.step(0, 0.0);
+            app.cells()[0].state.stress_level
+        };
+
+        let aliased =
+            load_from_reader("topic_aliases:\n  attack.ddos: activator\n".as_bytes()).unwrap();
+        assert_eq!(aliased.stimulus_signal(&command).topic, "activator");
+        assert_eq!(aliased.canonical_topic("inhibitor"), "inhibitor");
+        assert!(stress_after(&aliased) > 0.0);
+
+        let plain = ScenarioConfig::default();
+        assert_eq!(plain.stimulus_signal(&command).topic, "attack.ddos");
+        assert_eq!(stress_after(&plain), 0.0);
+    }
+
+    #[test]
+    fn targeted_stimulus_reaches_only_the_named_cell_in_graph_mode() {
+        use crate::orchestration::MorphogeneticApp;
+        use crate::stimulus::StimulusSchedule;
+        use crate::telemetry::InMemorySink;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let schedule_path = dir.path().join("schedule.jsonl");
+        std::fs::write(
+            &schedule_path,
+            r#"{"step":0,"topic":"inhibitor","value":0.9,"target":"seed-1"}"#,
+        )
+        .expect("write schedule");
+        let schedule = StimulusSchedule::load(&schedule_path).expect("load schedule");
+
+        let config = load_from_reader(
+            "initial_cell_count: 3
+topology:
+  strategy: Graph
+"
+            .as_bytes(),
+        )
+        .unwrap();
+        let mut app = MorphogeneticApp::new(
+            config.build_population(),
+            InMemorySink::default(),
+            config.topology.clone(),
+        );
+        for command in &schedule.commands[&0] {
+            app.inject_signal(config.stimulus_signal(command));
+        }
+        app.step(0, 0.0);
+
+        let energy = |id: &str| {
+            app.cells()
+                .iter()
+                .find(|cell| cell.id == id)
+                .map(|cell| cell.state.energy)
+                .expect("cell present")
+        };
+        assert!(energy("seed-1") < energy("seed-0"));
+        assert_eq!(energy("seed-0"), energy("seed-2"));
+    }
+
+    #[test]
+    fn warmup_must_leave_at_least_one_measured_step() {
+        let config = load_from_reader("simulation_steps: 5\nwarmup_steps: 3\n".as_bytes())
+            .expect("warmup shorter than run");
+        assert_eq!(config.warmup_steps, 3);
+        assert!(matches!(
+            load_from_reader("simulation_steps: 3\nwarmup_steps: 3\n".as_bytes()),
+            Err(ConfigError::Invalid(reason)) if reason.contains("warmup_steps")
+        ));
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn json_schema_lists_scenario_properties() {
+        let schema = json_schema();
+        let properties = &schema["properties"];
+        assert!(properties.get("scenario_name").is_some());
+        assert!(properties.get("spikes").is_some());
+        let definitions = &schema["definitions"];
+        assert!(definitions.get("ThreatProfile").is_some());
+        assert!(definitions.get("ThreatSpike").is_some());
+    }
+
+    fn burst_config() -> ScenarioConfig {
+        let yaml = r#"
+threat_profile:
+  background_threat: 0.1
+bursts:
+  - start_step: 2
+    duration: 3
+    intensity: 0.6
+  - start_step: 4
+    duration: 2
+    intensity: 0.2
+"#;
+        load_from_reader(yaml.as_bytes()).expect("config should parse")
+    }
+
+    #[test]
+    fn defense_spike_injects_inhibitor_at_its_step() {
+        let yaml = r#"
+threat_profile:
+  background_threat: 0.1
+  spike_threshold: 0.8
+defense_profile:
+  background_threat: 0.0
+  spike_threshold: 0.5
+defense_spikes:
+  - step: 3
+    intensity: 0.7
+"#;
+        let config = load_from_reader(yaml.as_bytes()).expect("config should parse");
+
+        assert!(config.scheduled_signals(2).is_empty());
+        let signals = config.scheduled_signals(3);
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].topic, "inhibitor");
+        assert!((signals[0].value - 0.7).abs() < 1e-6);
+        assert!(config.scheduled_signals(4).is_empty());
+
+        let without_defense = ScenarioConfig {
+            defense_profile: None,
+            ..config
+        };
+        assert_eq!(without_defense.defense_level_for_step(3), None);
+        assert!(without_defense.scheduled_signals(3).is_empty());
+    }
+
+    #[test]
+    fn burst_contributes_inside_window() {
+        let config = burst_config();
+        assert!((config.threat_level_for_step(1) - 0.1).abs() < f32::EPSILON);
+        assert!((config.threat_level_for_step(2) - 0.7).abs() < 1e-6);
+        assert!((config.threat_level_for_step(3) - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn burst_applies_on_last_step_and_sums_overlaps() {
+        let config = burst_config();
+        // Step 4 is the last step of the first burst and the first of the second.
+        assert!((config.threat_level_for_step(4) - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn burst_drops_to_silence_immediately_after() {
+        let config = burst_config();
+        assert!((config.threat_level_for_step(5) - 0.3).abs() < 1e-6);
+        assert!((config.threat_level_for_step(6) - 0.1).abs() < 1e-6);
+    }
+
     #[test]
     fn test_change_initial_cell_count_mutation() {
         let mut scenario_config = ScenarioConfig::default();
@@ -0,0 +1,233 @@
+//! Single-step inspection of a running scenario.
+
+use crate::cellular::CellLineage;
+use crate::config::ScenarioConfig;
+use crate::orchestration::MorphogeneticApp;
+use crate::telemetry::{InMemorySink, TelemetryEvent, TelemetryPipeline, TelemetrySink};
+use rand::rngs::StdRng;
+use std::time::SystemTime;
+
+/// Per-cell state captured after a step.
+#[derive(Debug, Clone)]
+pub struct CellSnapshot {
+    pub id: String,
+    pub lineage: CellLineage,
+    pub energy: f32,
+    pub stress_level: f32,
+}
+
+/// Everything observed while executing one step.
+#[derive(Debug, Clone)]
+pub struct StepReport {
+    pub step: u32,
+    pub threat: f32,
+    pub cells: Vec<CellSnapshot>,
+    pub events: Vec<TelemetryEvent>,
+}
+
+/// Why a multi-step run returned control to the caller.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopReason {
+    ReachedStep,
+    Breakpoint(String),
+    Finished,
+}
+
+/// Drives a scenario one step at a time, mirroring the main binary's loop.
+pub struct DebugSession {
+    app: MorphogeneticApp<TelemetryPipeline>,
+    config: ScenarioConfig,
+    fault_rng: StdRng,
+    next_step: u32,
+    breakpoint: Option<String>,
+}
+
+impl DebugSession {
+    pub fn new(config: ScenarioConfig) -> Self {
+        let mut telemetry = TelemetryPipeline::new(InMemorySink::default(), None);
+        telemetry.record(
+            SystemTime::now(),
+            TelemetryEvent::Scenario {
+                name: config.scenario_name.clone(),
+            },
+        );
+        let mut app = MorphogeneticApp::new(
+            config.build_population(),
+            telemetry,
+            config.topology.clone(),
+        );
+        for signal in config.initial_bus_signals() {
+            app.inject_signal(signal);
+        }
+        Self {
+            app,
+            fault_rng: config.fault_rng(),
+            config,
+            next_step: 0,
+            breakpoint: None,
+        }
+    }
+
+    pub fn next_step(&self) -> u32 {
+        self.next_step
+    }
+
+    pub fn total_steps(&self) -> u32 {
+        self.config.simulation_steps.max(1)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_step >= self.total_steps()
+    }
+
+    /// Stop multi-step runs after any step that records an event of `kind`
+    /// (a `TelemetryEvent` variant name such as `AnomalyDetected`).
+    pub fn set_breakpoint(&mut self, kind: Option<String>) {
+        self.breakpoint = kind;
+    }
+
+    pub fn breakpoint(&self) -> Option<&str> {
+        self.breakpoint.as_deref()
+    }
+
+    pub fn app(&self) -> &MorphogeneticApp<TelemetryPipeline> {
+        &self.app
+    }
+
+    /// Execute a single step, or return `None` once the scenario is done.
+    pub fn step(&mut self) -> Option<StepReport> {
+        if self.is_finished() {
+            return None;
+        }
+        let step = self.next_step;
+        let threat = self.config.threat_level_for_step(step);
+        for signal in self.config.scheduled_signals(step) {
+            self.app.inject_signal(signal);
+        }
+
+        let before = self.app.telemetry().events().len();
+        for fault in self.config.faults_at(step) {
+            self.app
+                .inject_fault(step, fault.kill_fraction, &mut self.fault_rng);
+        }
+        self.app.step(step, threat);
+        self.next_step += 1;
+
+        let events = self.app.telemetry().events()[before..]
+            .iter()
+            .map(|snapshot| snapshot.event.clone())
+            .collect();
+        let cells = self
+            .app
+            .cells()
+            .iter()
+            .map(|cell| CellSnapshot {
+                id: cell.id.clone(),
+                lineage: cell.state.lineage.clone(),
+                energy: cell.state.energy,
+                stress_level: cell.state.stress_level,
+            })
+            .collect();
+
+        Some(StepReport {
+            step,
+            threat,
+            cells,
+            events,
+        })
+    }
+
+    /// Step until `target` has executed, the breakpoint fires, or the scenario
+    /// ends. Returns the last report alongside the reason for stopping.
+    pub fn run_to(&mut self, target: u32) -> (Option<StepReport>, StopReason) {
+        let mut last = None;
+        while self.next_step <= target {
+            let Some(report) = self.step() else {
+                return (last, StopReason::Finished);
+            };
+            let hit = self.breakpoint.as_ref().and_then(|kind| {
+                report
+                    .events
+                    .iter()
+                    .any(|event| event.kind() == kind)
+                    .then(|| kind.clone())
+            });
+            last = Some(report);
+            if let Some(kind) = hit {
+                return (last, StopReason::Breakpoint(kind));
+            }
+        }
+        let reason = if self.is_finished() {
+            StopReason::Finished
+        } else {
+            StopReason::ReachedStep
+        };
+        (last, reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SeedCellSpec;
+    use std::collections::BTreeMap;
+
+    fn tiny_scenario() -> ScenarioConfig {
+        let mut genome_overrides = BTreeMap::new();
+        genome_overrides.insert("signal_emission_threshold".to_string(), 0.1);
+        ScenarioConfig {
+            simulation_steps: 5,
+            seed_cells: vec![
+                SeedCellSpec {
+                    id: "alpha".to_string(),
+                    lineage: CellLineage::Stem,
+                    genome_overrides: genome_overrides.clone(),
+                },
+                SeedCellSpec {
+                    id: "beta".to_string(),
+                    lineage: CellLineage::Firewall,
+                    genome_overrides,
+                },
+            ],
+            ..ScenarioConfig::default()
+        }
+    }
+
+    #[test]
+    fn session_steps_tiny_scenario_one_tick_at_a_time() {
+        let mut session = DebugSession::new(tiny_scenario());
+
+        let first = session.step().expect("first step runs");
+        assert_eq!(first.step, 0);
+        assert_eq!(first.cells.len(), 2);
+        assert_eq!(first.cells[1].lineage, CellLineage::Firewall);
+        assert!(
+            first
+                .events
+                .iter()
+                .any(|event| matches!(event, TelemetryEvent::StepSummary { step: 0, .. }))
+        );
+        assert_eq!(session.next_step(), 1);
+
+        let (report, reason) = session.run_to(2);
+        assert_eq!(report.map(|r| r.step), Some(2));
+        assert_eq!(reason, StopReason::ReachedStep);
+
+        let (report, reason) = session.run_to(100);
+        assert_eq!(report.map(|r| r.step), Some(4));
+        assert_eq!(reason, StopReason::Finished);
+        assert!(session.step().is_none());
+    }
+
+    #[test]
+    fn breakpoint_stops_on_first_matching_event() {
+        let mut session = DebugSession::new(tiny_scenario());
+        session.set_breakpoint(Some("SignalEmitted".to_string()));
+
+        let (report, reason) = session.run_to(100);
+        let report = report.expect("a step executed");
+        assert_eq!(reason, StopReason::Breakpoint("SignalEmitted".to_string()));
+        assert_eq!(report.step, 0);
+        assert_eq!(session.next_step(), 1);
+    }
+}
@@ -5,7 +5,7 @@ use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock};
 
 /// A recorded threat event in a cell's local memory.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +18,7 @@ pub struct ThreatEvent {
 
 /// Simulated cryptographic attestation token.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Attestation {
     pub cell_id: String,
     pub step: u64,
@@ -46,14 +47,7 @@ impl Serialize for TPM {
         state.serialize_field("compromised", &self.compromised)?;
 
         // Obfuscate secret using cell_id as salt (simulation security)
-        let salt = md5::compute(&self.cell_id).0;
-        let encrypted: Vec<u8> = self
-            .secret_bytes
-            .iter()
-            .enumerate()
-            .map(|(i, b)| b ^ salt[i % 16])
-            .collect();
-
+        let encrypted = obfuscate(&self.cell_id, &self.secret_bytes);
         state.serialize_field("secret_bytes", &encrypted)?;
         state.end()
     }
@@ -74,32 +68,72 @@ impl<'de> Deserialize<'de> for TPM {
         let def = TPMDef::deserialize(deserializer)?;
 
         // De-obfuscate
-        let salt = md5::compute(&def.cell_id).0;
-        let secret_bytes: Vec<u8> = def
-            .secret_bytes
-            .iter()
-            .enumerate()
-            .map(|(i, b)| b ^ salt[i % 16])
-            .collect();
-
-        // Re-register public key in PKI
-        if !def.compromised
-            && !secret_bytes.is_empty()
-            && let Ok(bytes) = secret_bytes.as_slice().try_into()
-        {
-            let signing_key = SigningKey::from_bytes(bytes);
-            let verifying_key = signing_key.verifying_key();
-            get_pki()
-                .lock()
-                .unwrap()
-                .insert(def.cell_id.clone(), verifying_key.to_bytes().to_vec());
-        }
+        let secret_bytes = obfuscate(&def.cell_id, &def.secret_bytes);
 
-        Ok(TPM {
+        let tpm = TPM {
             cell_id: def.cell_id,
             compromised: def.compromised,
             secret_bytes,
-        })
+        };
+        // Re-register public key in the default PKI
+        if !tpm.compromised {
+            Pki::global().register(&tpm);
+        }
+        Ok(tpm)
+    }
+}
+
+/// XOR `bytes` with a salt derived from `cell_id`; applying it twice
+/// restores the input.
+fn obfuscate(cell_id: &str, bytes: &[u8]) -> Vec<u8> {
+    let salt = md5::compute(cell_id).0;
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(i, b)| b ^ salt[i % 16])
+        .collect()
+}
+
+/// Persistable form of a [`TPM`]. [`TPM::snapshot`] keeps only the published
+/// verification key, so restoring issues a fresh key pair; secrets are only
+/// carried by [`TPM::snapshot_with_keys`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TpmSnapshot {
+    pub cell_id: String,
+    pub compromised: bool,
+    /// Verification key at snapshot time, for auditing which key was live.
+    pub public_key: Option<Vec<u8>>,
+    /// Obfuscated signing key; present only in snapshots taken with keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    secret_bytes: Option<Vec<u8>>,
+}
+
+impl TpmSnapshot {
+    /// Whether restoring reuses the original signing key.
+    pub fn has_keys(&self) -> bool {
+        self.secret_bytes.is_some()
+    }
+
+    /// Rebuild the TPM and publish its key to `pki`, replacing the entry for
+    /// this cell id. Snapshots without keys get a freshly generated key pair,
+    /// so attestations signed before the snapshot stop verifying.
+    /// Compromised TPMs are never published.
+    pub fn restore(self, pki: &Pki) -> TPM {
+        let tpm = match self.secret_bytes {
+            Some(secret) => TPM {
+                secret_bytes: obfuscate(&self.cell_id, &secret),
+                cell_id: self.cell_id,
+                compromised: self.compromised,
+            },
+            None => TPM {
+                compromised: self.compromised,
+                ..TPM::generate(self.cell_id)
+            },
+        };
+        if !tpm.compromised {
+            pki.register(&tpm);
+        }
+        tpm
     }
 }
 
@@ -113,27 +147,137 @@ impl std::fmt::Debug for TPM {
     }
 }
 
-// Registry stores only PUBLIC verification keys.
-static PKI_REGISTRY: OnceLock<Mutex<HashMap<String, Vec<u8>>>> = OnceLock::new();
+/// Registry of cell verification keys, keyed by cell id. It stores only
+/// PUBLIC keys. Clones share the same underlying registry, so a handle can be
+/// passed to every cell of one simulation while another simulation in the
+/// same process keeps its own.
+#[derive(Clone, Default)]
+pub struct Pki {
+    keys: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+static PKI_REGISTRY: OnceLock<Pki> = OnceLock::new();
+
+impl Pki {
+    /// An empty registry, isolated from every other `Pki`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handle to the legacy process-global registry. [`TPM::new`] publishes
+    /// here, and cells ticked outside a [`crate::MorphogeneticApp`] verify
+    /// against it. Prefer an owned `Pki` so runs cannot see each other's keys.
+    pub fn global() -> Pki {
+        PKI_REGISTRY.get_or_init(Pki::new).clone()
+    }
+
+    /// Publish `tpm`'s verification key, replacing any earlier key for the
+    /// same cell id. TPMs without a usable key are ignored.
+    pub fn register(&self, tpm: &TPM) {
+        if let Some(key) = tpm.verifying_key_bytes() {
+            self.keys.lock().unwrap().insert(tpm.cell_id.clone(), key);
+        }
+    }
+
+    /// Whether a key is published for `cell_id`.
+    pub fn contains(&self, cell_id: &str) -> bool {
+        self.keys.lock().unwrap().contains_key(cell_id)
+    }
+
+    /// Number of cell ids with a published key.
+    pub fn len(&self) -> usize {
+        self.keys.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every published key. Attestations issued before the clear no
+    /// longer verify.
+    pub fn clear(&self) {
+        self.keys.lock().unwrap().clear();
+    }
+
+    /// Check `attestation` for freshness (at most one step old), payload
+    /// integrity, and a signature matching the key published for its cell.
+    pub fn verify(&self, attestation: &Attestation, current_step: u64, payload: &str) -> bool {
+        if !attestation.valid {
+            return false;
+        }
+        // Freshness check (allow 1 step delay)
+        if attestation.step > current_step || (current_step - attestation.step) > 1 {
+            return false;
+        }
+        // Integrity check
+        let mut hasher = Sha256::new();
+        hasher.update(payload.as_bytes());
+        let expected_hash = format!("{:x}", hasher.finalize());
+        if attestation.payload_hash != expected_hash {
+            return false;
+        }
 
-fn get_pki() -> &'static Mutex<HashMap<String, Vec<u8>>> {
-    PKI_REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+        // Retrieve PUBLIC key from registry
+        let keys = self.keys.lock().unwrap();
+        if let Some(pub_bytes) = keys.get(&attestation.cell_id) {
+            let verifying_key_bytes: [u8; 32] = match pub_bytes.as_slice().try_into() {
+                Ok(bytes) => bytes,
+                Err(_) => return false,
+            };
+
+            if let Ok(verifying_key) = VerifyingKey::from_bytes(&verifying_key_bytes) {
+                let message = format!("{}:{}", attestation.step, expected_hash);
+                let signature_bytes: [u8; 64] = match attestation.signature.as_slice().try_into() {
+                    Ok(bytes) => bytes,
+                    Err(_) => return false,
+                };
+
+                let signature = Signature::from_bytes(&signature_bytes);
+                return verifying_key.verify(message.as_bytes(), &signature).is_ok();
+            }
+        }
+        false
+    }
+}
+
+impl std::fmt::Debug for Pki {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pki").field("keys", &self.len()).finish()
+    }
+}
+
+/// Number of cell ids in the process-global registry ([`Pki::global`]).
+///
+/// Every [`TPM::new`] (and TPM deserialisation) publishes a key there that
+/// outlives the TPM, and re-using a cell id replaces its key. Long-running
+/// services should watch this and call [`pki_clear`] between independent runs.
+pub fn pki_len() -> usize {
+    Pki::global().len()
+}
+
+/// Empty the process-global registry. Attestations issued before the clear no
+/// longer verify against it, so only call this between runs, never while a
+/// simulation relying on the global registry is still stepping.
+pub fn pki_clear() {
+    Pki::global().clear();
 }
 
 impl TPM {
+    /// Generate a key pair and publish it to the process-global registry.
+    /// Use [`Self::generate`] plus [`Pki::register`] to keep it private.
     pub fn new(cell_id: String) -> Self {
+        let tpm = Self::generate(cell_id);
+        Pki::global().register(&tpm);
+        tpm
+    }
+
+    /// Generate a key pair without publishing it anywhere.
+    pub fn generate(cell_id: String) -> Self {
         let mut csprng = OsRng;
         let mut bytes = [0u8; 32];
         use rand::RngCore;
         csprng.fill_bytes(&mut bytes);
         let signing_key = SigningKey::from_bytes(&bytes);
-        let verifying_key: VerifyingKey = signing_key.verifying_key();
-
-        // Publish public key to PKI
-        get_pki()
-            .lock()
-            .unwrap()
-            .insert(cell_id.clone(), verifying_key.to_bytes().to_vec());
 
         Self {
             cell_id,
@@ -142,6 +286,34 @@ impl TPM {
         }
     }
 
+    /// Snapshot holding only the public verification key. This is the mode
+    /// to persist: the signing key never leaves the process.
+    pub fn snapshot(&self) -> TpmSnapshot {
+        TpmSnapshot {
+            cell_id: self.cell_id.clone(),
+            compromised: self.compromised,
+            public_key: self.verifying_key_bytes(),
+            secret_bytes: None,
+        }
+    }
+
+    /// Snapshot that also carries the (obfuscated, not encrypted) signing key,
+    /// so a restored TPM keeps its identity. Only use this when earlier
+    /// attestations must keep verifying after a restore.
+    pub fn snapshot_with_keys(&self) -> TpmSnapshot {
+        TpmSnapshot {
+            secret_bytes: Some(obfuscate(&self.cell_id, &self.secret_bytes)),
+            ..self.snapshot()
+        }
+    }
+
+    /// Public verification key, or `None` if the secret is missing or malformed.
+    pub fn verifying_key_bytes(&self) -> Option<Vec<u8>> {
+        let bytes: [u8; 32] = self.secret_bytes.as_slice().try_into().ok()?;
+        let verifying_key: VerifyingKey = SigningKey::from_bytes(&bytes).verifying_key();
+        Some(verifying_key.to_bytes().to_vec())
+    }
+
     pub fn attest(&self, step: u64, payload: &str) -> Option<Attestation> {
         if self.compromised || self.secret_bytes.is_empty() {
             None
@@ -169,41 +341,9 @@ impl TPM {
         }
     }
 
+    /// Verify against the process-global registry.
+    #[deprecated(note = "verify against an explicit registry with `Pki::verify`")]
     pub fn verify(attestation: &Attestation, current_step: u64, payload: &str) -> bool {
-        if !attestation.valid {
-            return false;
-        }
-        // Freshness check (allow 1 step delay)
-        if attestation.step > current_step || (current_step - attestation.step) > 1 {
-            return false;
-        }
-        // Integrity check
-        let mut hasher = Sha256::new();
-        hasher.update(payload.as_bytes());
-        let expected_hash = format!("{:x}", hasher.finalize());
-        if attestation.payload_hash != expected_hash {
-            return false;
-        }
-
-        // Retrieve PUBLIC key from registry
-        let pki = get_pki().lock().unwrap();
-        if let Some(pub_bytes) = pki.get(&attestation.cell_id) {
-            let verifying_key_bytes: [u8; 32] = match pub_bytes.as_slice().try_into() {
-                Ok(bytes) => bytes,
-                Err(_) => return false,
-            };
-
-            if let Ok(verifying_key) = VerifyingKey::from_bytes(&verifying_key_bytes) {
-                let message = format!("{}:{}", attestation.step, expected_hash);
-                let signature_bytes: [u8; 64] = match attestation.signature.as_slice().try_into() {
-                    Ok(bytes) => bytes,
-                    Err(_) => return false,
-                };
-
-                let signature = Signature::from_bytes(&signature_bytes);
-                return verifying_key.verify(message.as_bytes(), &signature).is_ok();
-            }
-        }
-        false
+        Pki::global().verify(attestation, current_step, payload)
     }
 }
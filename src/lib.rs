@@ -3,15 +3,18 @@
 pub mod adversarial;
 pub mod cellular;
 pub mod config;
+pub mod debugger;
 pub mod immune;
 pub mod orchestration;
 pub mod signaling;
 pub mod stimulus;
 pub mod telemetry;
+pub mod util;
 
 pub use adversarial::{
-    AdversarialHarness, AttackCandidate, AttackOutcome, EvaluatedCandidate, EvolutionConfig,
-    ExecutionReport, HarnessAnalysis, HarnessError, HarnessState, RunStatistics, StepMetrics,
+    AdversarialHarness, AggregatedStatistics, AttackCandidate, AttackOutcome, EvaluatedCandidate,
+    EvolutionConfig, ExecutionReport, HarnessAnalysis, HarnessError, HarnessState, MetricSummary,
+    RunMetadata, RunStatistics, StepMetrics,
 };
-pub use config::{ConfigError, ScenarioConfig, ThreatSpike};
-pub use orchestration::MorphogeneticApp;
+pub use config::{ConfigError, ScenarioConfig, SeedCellSpec, ThreatBurst, ThreatSpike};
+pub use orchestration::{AppMetrics, MorphogeneticApp};
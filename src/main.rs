@@ -1,30 +1,22 @@
-use morphogenetic_security::cellular::SecurityCell;
+use morphogenetic_security::adversarial::{StepMetrics, write_step_metrics_csv};
 use morphogenetic_security::config;
-use morphogenetic_security::signaling::Signal;
 use morphogenetic_security::stimulus::{StimulusCommand, StimulusSchedule};
 use morphogenetic_security::telemetry::{
-    InMemorySink, TelemetryEvent, TelemetryPipeline, TelemetrySink,
+    InMemorySink, RunSummary, TelemetryEvent, TelemetryPipeline, TelemetrySink,
 };
 use morphogenetic_security::{MorphogeneticApp, ScenarioConfig};
 use std::cmp::max;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::path::PathBuf;
 use std::process;
 
 fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
     let runtime = resolve_runtime();
     let config = runtime.config;
 
-    let cell_count = max(1, config.initial_cell_count);
-    let mut cells = Vec::with_capacity(cell_count);
-    for idx in 0..cell_count {
-        let mut cell = SecurityCell::new(format!("seed-{idx}"));
-        cell.genome.reproduction_threshold = config.threat_profile.spike_threshold;
-        if config.cell_reproduction_rate > 0.0 {
-            cell.genome.reproduction_energy_cost /= config.cell_reproduction_rate;
-        }
-        cells.push(cell);
-    }
+    let cells = config.build_population();
 
     let mut telemetry_pipeline = runtime
         .telemetry_path
@@ -32,7 +24,7 @@ fn main() {
         .map(TelemetryPipeline::with_file)
         .transpose()
         .unwrap_or_else(|err| {
-            eprintln!("Failed to initialize telemetry sink: {err}");
+            log::error!("Failed to initialize telemetry sink: {err}");
             process::exit(1);
         })
         .unwrap_or_else(|| TelemetryPipeline::new(InMemorySink::default(), None));
@@ -45,30 +37,37 @@ fn main() {
     );
 
     let mut app = MorphogeneticApp::new(cells, telemetry_pipeline, config.topology.clone());
+    for signal in config.initial_bus_signals() {
+        app.inject_signal(signal);
+    }
 
     let mut stimulus_schedule = runtime
         .stimulus_path
         .as_ref()
-        .map(StimulusSchedule::load)
+        .map(StimulusSchedule::load_auto)
         .transpose()
         .unwrap_or_else(|err| {
-            eprintln!("Failed to load stimulus schedule: {err}");
+            log::error!("Failed to load stimulus schedule: {err}");
             process::exit(1);
         });
 
     let steps = max(1, config.simulation_steps);
     let mut active_stimuli: Vec<StimulusCommand> = Vec::new();
+    let mut stimulus_ledger: HashMap<u32, BTreeMap<String, f32>> = HashMap::new();
+    let mut fault_rng = config.fault_rng();
 
     for step in 0..steps {
         let threat = config.threat_level_for_step(step);
-        if threat >= config.threat_profile.spike_threshold {
-            app.inject_signal(Signal {
-                topic: "activator".to_string(),
-                value: threat,
-                source: None,
-                target: None,
-                attestation: None,
-            });
+        for fault in config.faults_at(step) {
+            app.inject_fault(step, fault.kill_fraction, &mut fault_rng);
+        }
+        for signal in config.scheduled_signals(step) {
+            *stimulus_ledger
+                .entry(step)
+                .or_default()
+                .entry(signal.topic.clone())
+                .or_insert(0.0) += signal.value;
+            app.inject_signal(signal);
         }
 
         if let Some(schedule) = stimulus_schedule.as_mut() {
@@ -80,13 +79,13 @@ fn main() {
 
         // Inject all active stimuli and prune expired ones
         active_stimuli.retain(|command| {
-            app.inject_signal(Signal {
-                topic: command.topic.clone(),
-                value: command.value,
-                source: command.source.clone(),
-                target: command.target.clone(),
-                attestation: None,
-            });
+            let signal = config.stimulus_signal(command);
+            *stimulus_ledger
+                .entry(step)
+                .or_default()
+                .entry(signal.topic.clone())
+                .or_insert(0.0) += signal.value;
+            app.inject_signal(signal);
             // Keep if not expired. Duration is 1-based (1 means only the start step).
             step < command.step + command.duration - 1
         });
@@ -95,25 +94,45 @@ fn main() {
     }
 
     let events = app.telemetry().events();
+
+    let mut step_metrics = StepMetrics::from_telemetry(events);
+    if let Some(metrics_path) = &runtime.metrics_path {
+        for row in &mut step_metrics {
+            if let Some(stimulus) = stimulus_ledger.remove(&row.step) {
+                row.stimulus_total = stimulus.values().sum();
+                row.stimulus_by_topic = stimulus;
+            }
+        }
+        if let Err(err) = write_step_metrics_csv(metrics_path, &step_metrics) {
+            log::error!("Failed to write step metrics: {err}");
+            process::exit(1);
+        }
+    }
+
     println!(
         "Scenario `{}` executed {} step(s); recorded {} telemetry event(s).",
         config.scenario_name,
         steps,
         events.len()
     );
+    if runtime.summary {
+        print!("{}", RunSummary::new(&app.metrics(), &step_metrics, events));
+    }
 }
 
 struct RuntimeContext {
     config: ScenarioConfig,
     telemetry_path: Option<PathBuf>,
     stimulus_path: Option<PathBuf>,
+    metrics_path: Option<PathBuf>,
+    summary: bool,
 }
 
 fn resolve_runtime() -> RuntimeContext {
     match parse_cli() {
         Ok(context) => context,
         Err(err) => {
-            eprintln!("{err}");
+            log::error!("{err}");
             process::exit(1);
         }
     }
@@ -124,6 +143,9 @@ fn parse_cli() -> Result<RuntimeContext, String> {
     let mut config_path: Option<PathBuf> = None;
     let mut telemetry_path: Option<PathBuf> = None;
     let mut stimulus_path: Option<PathBuf> = None;
+    let mut metrics_path: Option<PathBuf> = None;
+    let mut summary = false;
+    let mut allow_long_run = false;
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
@@ -139,6 +161,25 @@ fn parse_cli() -> Result<RuntimeContext, String> {
                     .ok_or_else(|| "Missing value for --telemetry".to_string())?;
                 telemetry_path = Some(PathBuf::from(value));
             }
+            "--metrics" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "Missing value for --metrics".to_string())?;
+                metrics_path = Some(PathBuf::from(value));
+            }
+            "--summary" => summary = true,
+            "--allow-long-run" => allow_long_run = true,
+            "--emit-schema" => {
+                emit_schema()?;
+                process::exit(0);
+            }
+            "--diff" => {
+                let (Some(left), Some(right)) = (args.next(), args.next()) else {
+                    return Err("--diff requires two config paths".to_string());
+                };
+                print_config_diff(&left, &right)?;
+                process::exit(0);
+            }
             "--stimulus" => {
                 let value = args
                     .next()
@@ -163,10 +204,42 @@ fn parse_cli() -> Result<RuntimeContext, String> {
     } else {
         ScenarioConfig::default()
     };
+    config
+        .check_step_cap(allow_long_run)
+        .map_err(|err| err.to_string())?;
 
     Ok(RuntimeContext {
         config,
         telemetry_path,
         stimulus_path,
+        metrics_path,
+        summary,
     })
 }
+
+fn print_config_diff(left: &str, right: &str) -> Result<(), String> {
+    let load = |path: &str| {
+        config::load_from_path(path).map_err(|err| format!("Failed to load `{path}`: {err}"))
+    };
+    let diffs = config::diff(&load(left)?, &load(right)?);
+    if diffs.is_empty() {
+        println!("Configs `{left}` and `{right}` are equivalent.");
+    }
+    for entry in diffs {
+        println!("{entry}");
+    }
+    Ok(())
+}
+
+#[cfg(feature = "schema")]
+fn emit_schema() -> Result<(), String> {
+    let schema = config::json_schema();
+    let rendered = serde_json::to_string_pretty(&schema).map_err(|err| err.to_string())?;
+    println!("{rendered}");
+    Ok(())
+}
+
+#[cfg(not(feature = "schema"))]
+fn emit_schema() -> Result<(), String> {
+    Err("--emit-schema requires building with `--features schema`".to_string())
+}
@@ -1,12 +1,34 @@
 //! High-level orchestration for the morphogenetic security system.
 
-use crate::cellular::{CellAction, CellEnvironment, PopulationStats, SecurityCell};
+use crate::cellular::{
+    AdaptationTable, CellAction, CellEnvironment, CellGenome, CellLineage, CellSnapshot,
+    LineageEconomy, PopulationStats, SecurityCell, attestation_payload,
+};
 use crate::config::{TopologyConfig, TopologyStrategy};
-use crate::signaling::{Signal, SignalBus};
-use crate::telemetry::{TelemetryEvent, TelemetrySink};
-use std::collections::HashMap;
+use crate::immune::Pki;
+use crate::signaling::{MAX_SIGNAL_PERSISTENCE, Signal, SignalBus, SignalChannel, TopicSignals};
+use crate::stimulus::StimulusCommand;
+use crate::telemetry::{TelemetryEvent, TelemetrySink, TopologyStats};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 use std::time::SystemTime;
 
+/// Hard population cap; replication requests beyond it are dropped.
+const MAX_POPULATION: usize = 100;
+
+/// Aggregate view of the live population between steps.
+#[derive(Debug, Clone)]
+pub struct AppMetrics {
+    pub cell_count: usize,
+    pub lineage_counts: BTreeMap<CellLineage, usize>,
+    pub avg_energy: f32,
+    pub avg_stress: f32,
+    pub topology: TopologyStats,
+}
+
 #[allow(dead_code)]
 pub struct MorphogeneticApp<TSink: TelemetrySink> {
     cells: Vec<SecurityCell>,
@@ -14,8 +36,56 @@ pub struct MorphogeneticApp<TSink: TelemetrySink> {
     telemetry: TSink,
     topology_config: TopologyConfig,
     neighbors: HashMap<String, Vec<String>>,
+    /// Running sum of live cells' adjacency list lengths.
+    total_degree: usize,
+    /// Running count of live cells with an empty adjacency list.
+    isolation_count: u32,
+    /// Live cell multiplicity per id (replication can reuse child ids).
+    live_id_counts: HashMap<String, usize>,
+    /// Step currently being executed, for actions that need a time reference.
+    current_step: u32,
+    /// Topics dropped from the bus before delivery.
+    muted_topics: HashSet<String>,
+    /// `(step, cell_id, action)` for recent steps when tracing is enabled.
+    action_trace: Option<VecDeque<(u32, String, CellAction)>>,
+    /// Decides which deliveries are dropped when `signal_loss_rate` is set.
+    loss_rng: StdRng,
+    /// Verification keys of this app's cells, private to the app unless
+    /// shared through [`Self::with_pki`].
+    pki: Pki,
+    /// Activator volume cells emitted on the previous step.
+    last_activator_volume: f32,
+    /// Consecutive steps on which activator volume grew past
+    /// `feedback_growth_threshold`.
+    activator_growth_streak: u32,
+    /// Config-derived tables shared with every cell's environment.
+    tables: EnvironmentTables,
+}
+
+/// Read-only lookup tables from [`TopologyConfig`], built once so each step
+/// only clones the `Arc`s into the cells' environments.
+struct EnvironmentTables {
+    recognized_topics: Option<Arc<HashMap<String, SignalChannel>>>,
+    lineage_economy: Option<Arc<LineageEconomy>>,
+    adaptation_table: Option<Arc<AdaptationTable>>,
+}
+
+impl EnvironmentTables {
+    fn from_config(config: &TopologyConfig) -> Self {
+        Self {
+            recognized_topics: (!config.recognized_topics.is_empty())
+                .then(|| Arc::new(config.recognized_topics.clone())),
+            lineage_economy: (!config.lineage_economy.is_default())
+                .then(|| Arc::new(config.lineage_economy.clone())),
+            adaptation_table: (!config.adaptation_table.is_default())
+                .then(|| Arc::new(config.adaptation_table.clone())),
+        }
+    }
 }
 
+/// Consecutive over-threshold growth steps before a feedback loop is reported.
+const FEEDBACK_LOOP_WINDOW: u32 = 3;
+
 impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
     #[allow(dead_code)]
     pub fn new(
@@ -48,12 +118,46 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
             }
         }
 
-        Self {
+        let mut app = Self {
             cells,
             telemetry,
-            topology_config,
             signal_bus: SignalBus::default(),
             neighbors,
+            total_degree: 0,
+            isolation_count: 0,
+            live_id_counts: HashMap::new(),
+            current_step: 0,
+            muted_topics: HashSet::new(),
+            action_trace: topology_config.trace_actions.then(VecDeque::new),
+            loss_rng: StdRng::seed_from_u64(topology_config.signal_loss_seed.unwrap_or_default()),
+            tables: EnvironmentTables::from_config(&topology_config),
+            topology_config,
+            pki: Pki::new(),
+            last_activator_volume: 0.0,
+            activator_growth_streak: 0,
+        };
+        app.register_keys();
+        app.rebuild_degree_ledger();
+        app
+    }
+
+    /// Verify attestations against `pki` instead of a private registry, e.g.
+    /// so several apps can trust each other's cells. The current cells' keys
+    /// are published to it.
+    pub fn with_pki(mut self, pki: Pki) -> Self {
+        self.pki = pki;
+        self.register_keys();
+        self
+    }
+
+    /// Registry this app's cells verify attestations against.
+    pub fn pki(&self) -> &Pki {
+        &self.pki
+    }
+
+    fn register_keys(&self) {
+        for cell in &self.cells {
+            self.pki.register(&cell.tpm);
         }
     }
 
@@ -79,14 +183,8 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                     // Connect to previous
                     if i > 0 {
                         let prev_id = self.cells[i - 1].id.clone();
-                        self.neighbors
-                            .entry(current_id.clone())
-                            .or_default()
-                            .push(prev_id.clone());
-                        self.neighbors
-                            .entry(prev_id.clone())
-                            .or_default()
-                            .push(current_id.clone());
+                        self.push_link(&current_id, &prev_id);
+                        self.push_link(&prev_id, &current_id);
 
                         self.telemetry.record(
                             SystemTime::now(),
@@ -99,9 +197,92 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                 }
             }
         }
+        self.rebuild_degree_ledger();
+    }
+
+    /// Population size at which replication stalls under a constant
+    /// `background_threat`, from the population-average genome: 0 if cells
+    /// cannot sustain energy, the current size if they never replicate.
+    pub fn estimated_carrying_capacity(&self, background_threat: f32) -> usize {
+        if self.cells.is_empty() {
+            return 0;
+        }
+        let count = self.cells.len() as f32;
+        let mean = |gene: fn(&CellGenome) -> f32| {
+            self.cells
+                .iter()
+                .map(|cell| gene(&cell.genome))
+                .sum::<f32>()
+                / count
+        };
+        let genome = CellGenome {
+            energy_recharge: mean(|g| g.energy_recharge),
+            energy_threat_drain: mean(|g| g.energy_threat_drain),
+            reproduction_threshold: mean(|g| g.reproduction_threshold),
+            reproduction_energy_min: mean(|g| g.reproduction_energy_min),
+            density_sensitivity: mean(|g| g.density_sensitivity),
+            ..CellGenome::default()
+        };
+
+        let energy = genome.equilibrium_energy(background_threat);
+        if energy <= 0.01 {
+            return 0;
+        }
+        let current = self.cells.len();
+        if energy < genome.reproduction_energy_min
+            || background_threat < genome.reproduction_threshold
+        {
+            return current;
+        }
+        if genome.density_sensitivity <= 0.0 {
+            return MAX_POPULATION;
+        }
+        let headroom =
+            (background_threat - genome.reproduction_threshold) / genome.density_sensitivity;
+        // The gate is inclusive; nudge past float rounding before flooring.
+        let density_cap = (headroom * MAX_POPULATION as f32 + 1e-3).floor() as usize;
+        density_cap.clamp(current, MAX_POPULATION)
+    }
+
+    /// Snapshot cell count, lineage histogram, mean energy/stress, and
+    /// topology stats in a single pass over the live cells.
+    pub fn metrics(&self) -> AppMetrics {
+        let mut energy_sum = 0.0;
+        let mut stress_sum = 0.0;
+        for cell in &self.cells {
+            energy_sum += cell.state.energy;
+            stress_sum += cell.state.stress_level;
+        }
+        let cell_count = self.cells.len();
+        let mean = |sum: f32| {
+            if cell_count == 0 {
+                0.0
+            } else {
+                sum / cell_count as f32
+            }
+        };
+        AppMetrics {
+            cell_count,
+            lineage_counts: lineage_histogram(&self.cells),
+            avg_energy: mean(energy_sum),
+            avg_stress: mean(stress_sum),
+            topology: self.calculate_topology_stats(),
+        }
     }
 
     fn calculate_topology_stats(&self) -> crate::telemetry::TopologyStats {
+        if cfg!(test) {
+            let (total_degree, isolation_count) = self.recompute_degree_totals();
+            debug_assert_eq!(
+                self.total_degree, total_degree,
+                "incremental degree drifted"
+            );
+            debug_assert_eq!(
+                self.isolation_count, isolation_count,
+                "incremental isolation count drifted"
+            );
+        }
+
         if self.cells.is_empty() {
             return crate::telemetry::TopologyStats {
                 avg_degree: 0.0,
@@ -109,6 +290,14 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
             };
         }
 
+        crate::telemetry::TopologyStats {
+            avg_degree: self.total_degree as f32 / self.cells.len() as f32,
+            isolation_count: self.isolation_count,
+        }
+    }
+
+    /// Full O(cells) recomputation of the degree totals.
+    fn recompute_degree_totals(&self) -> (usize, u32) {
         let mut total_degree = 0;
         let mut isolation_count = 0;
 
@@ -120,28 +309,118 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
             }
         }
 
-        crate::telemetry::TopologyStats {
-            avg_degree: total_degree as f32 / self.cells.len() as f32,
-            isolation_count,
+        (total_degree, isolation_count)
+    }
+
+    fn rebuild_degree_ledger(&mut self) {
+        self.live_id_counts.clear();
+        for cell in &self.cells {
+            *self.live_id_counts.entry(cell.id.clone()).or_insert(0) += 1;
+        }
+        let (total_degree, isolation_count) = self.recompute_degree_totals();
+        self.total_degree = total_degree;
+        self.isolation_count = isolation_count;
+    }
+
+    fn degree_of(&self, id: &str) -> usize {
+        self.neighbors.get(id).map(|n| n.len()).unwrap_or(0)
+    }
+
+    /// Apply an adjacency list length change for `id` to every live cell sharing that id.
+    fn record_degree_change(&mut self, id: &str, old: usize, new: usize) {
+        let copies = self.live_id_counts.get(id).copied().unwrap_or(0);
+        if copies == 0 || old == new {
+            return;
+        }
+        self.total_degree = self.total_degree + copies * new - copies * old;
+        if old == 0 {
+            self.isolation_count -= copies as u32;
+        } else if new == 0 {
+            self.isolation_count += copies as u32;
+        }
+    }
+
+    fn track_cell_added(&mut self, id: &str) {
+        *self.live_id_counts.entry(id.to_string()).or_insert(0) += 1;
+        let degree = self.degree_of(id);
+        self.total_degree += degree;
+        if degree == 0 {
+            self.isolation_count += 1;
+        }
+    }
+
+    fn track_cell_removed(&mut self, id: &str) {
+        if let Some(count) = self.live_id_counts.get_mut(id) {
+            *count -= 1;
+            if *count == 0 {
+                self.live_id_counts.remove(id);
+            }
+        }
+        let degree = self.degree_of(id);
+        self.total_degree -= degree;
+        if degree == 0 {
+            self.isolation_count -= 1;
         }
     }
 
+    /// Whether linking `a` and `b` keeps both within `max_degree`.
+    fn link_allowed(&self, a: &str, b: &str) -> bool {
+        self.topology_config
+            .max_degree
+            .is_none_or(|cap| self.degree_of(a) < cap && self.degree_of(b) < cap)
+    }
+
+    fn record_link_rejected(&mut self, source: &str, target: &str) {
+        self.telemetry.record(
+            SystemTime::now(),
+            TelemetryEvent::LinkRejected {
+                source: source.to_string(),
+                target: target.to_string(),
+            },
+        );
+    }
+
+    fn push_link(&mut self, from: &str, to: &str) {
+        let list = self.neighbors.entry(from.to_string()).or_default();
+        let old = list.len();
+        list.push(to.to_string());
+        self.record_degree_change(from, old, old + 1);
+    }
+
+    /// Remove the first `to` entry from `from`'s adjacency list, if any.
+    fn remove_link(&mut self, from: &str, to: &str) -> bool {
+        let Some(list) = self.neighbors.get_mut(from) else {
+            return false;
+        };
+        let Some(pos) = list.iter().position(|x| x == to) else {
+            return false;
+        };
+        let old = list.len();
+        list.remove(pos);
+        self.record_degree_change(from, old, old - 1);
+        true
+    }
+
     #[allow(dead_code)]
     pub fn step(&mut self, step_index: u32, threat_score: f32) {
-        let signals = self.signal_bus.drain();
-
-        let global_signals: Option<Vec<Signal>> =
-            if matches!(self.topology_config.strategy, TopologyStrategy::Global) {
-                // In Global, everyone sees everything.
-                Some(signals.clone())
-            } else {
-                None
-            };
+        self.current_step = step_index;
+        // Muted topics leave the bus before decay, so they never persist.
+        self.signal_bus.purge_topics(&self.muted_topics);
+        let persistence = self
+            .topology_config
+            .signal_persistence
+            .min(MAX_SIGNAL_PERSISTENCE);
+        let drained = if persistence > 0.0 {
+            self.signal_bus.decay(persistence)
+        } else {
+            self.signal_bus.drain()
+        };
+        let signals: Arc<[Signal]> = drained.into_iter().collect();
 
         // For Graph mode, index signals by source
         let mut signals_by_source: HashMap<String, Vec<&Signal>> = HashMap::new();
         if matches!(self.topology_config.strategy, TopologyStrategy::Graph) {
-            for signal in &signals {
+            for signal in signals.iter() {
                 if let Some(ref source) = signal.source {
                     signals_by_source
                         .entry(source.clone())
@@ -151,7 +430,18 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
             }
         }
 
+        // Global mode shares one topic index across every cell that sees the full buffer.
+        let shared_index = matches!(self.topology_config.strategy, TopologyStrategy::Global)
+            .then(|| Arc::new(TopicSignals::new(Arc::clone(&signals))));
+
         let mut actions = Vec::with_capacity(self.cells.len());
+        let relay_hops = match self.topology_config.strategy {
+            TopologyStrategy::Graph => self.topology_config.consensus_relay_hops as usize,
+            _ => 0,
+        };
+        let mut relays = Vec::new();
+        let loss_rate = self.topology_config.signal_loss_rate.clamp(0.0, 1.0);
+        let mut lost = 0;
 
         let global_neighbors: Vec<String> =
             if matches!(self.topology_config.strategy, TopologyStrategy::Global) {
@@ -160,62 +450,124 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                 Vec::new()
             };
 
-        for (index, cell) in self.cells.iter_mut().enumerate() {
-            let neighbor_signals: Vec<Signal> = if let Some(ref globals) = global_signals {
-                // In Global mode, we must still filter out signals from blacklisted sources per cell
-                globals
-                    .iter()
-                    .filter(|s| {
-                        if let Some(source) = &s.source {
-                            !cell.state.blacklist.contains(source)
-                        } else {
-                            true // Allow system signals
-                        }
-                    })
-                    .cloned()
-                    .collect()
-            } else {
-                let mut cell_signals = Vec::new();
-                // 1. Incorporate system signals (source == None)
-                for signal in signals.iter().filter(|s| s.source.is_none()) {
-                    if signal.target.as_ref().is_none_or(|t| t == &cell.id) {
-                        cell_signals.push(signal.clone());
-                    }
-                }
+        let deliver_self = self.topology_config.deliver_self_signals;
+        let population_density = self.cells.len() as f32 / MAX_POPULATION as f32;
 
-                // 2. Incorporate neighbor signals
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            let self_source = (!deliver_self).then_some(cell.id.as_str());
+            let neighbor_signals: Arc<[Signal]> =
                 if matches!(self.topology_config.strategy, TopologyStrategy::Global) {
-                    // Global Mode: Iterate all signals, filtering blacklisted sources
-                    for signal in signals.iter().filter(|s| s.source.is_some()) {
-                        let source_id = signal.source.as_ref().unwrap();
-                        if !cell.state.blacklist.contains(source_id)
-                            && signal.target.as_ref().is_none_or(|t| t == &cell.id)
-                        {
+                    // In Global, everyone sees everything except blacklisted sources
+                    // and, unless configured otherwise, their own emissions.
+                    global_signal_view(
+                        &signals,
+                        &cell.state.blacklist,
+                        cell.genome.peer_rehabilitation,
+                        self_source,
+                    )
+                } else {
+                    let mut cell_signals = Vec::new();
+                    // 1. Incorporate system signals (source == None)
+                    for signal in signals.iter().filter(|s| s.source.is_none()) {
+                        if signal.target.as_ref().is_none_or(|t| t == &cell.id) {
                             cell_signals.push(signal.clone());
                         }
                     }
-                } else {
-                    // Graph Mode: Only look at adjacency list neighbors
-                    if let Some(neighbors) = self.neighbors.get(&cell.id) {
-                        for neighbor_id in neighbors {
-                            // Extra check: ignore if blacklisted (redundant if link removed, but safe)
-                            if cell.state.blacklist.contains(neighbor_id) {
-                                continue;
+
+                    // 2. Graph Mode: Only look at adjacency list neighbors, plus the
+                    // cell itself when self-delivery is enabled. Self-loops in the
+                    // adjacency list are ignored so own signals arrive at most once.
+                    let own_id = deliver_self.then(|| cell.id.clone());
+                    let sources = self
+                        .neighbors
+                        .get(&cell.id)
+                        .into_iter()
+                        .flatten()
+                        .filter(|neighbor_id| *neighbor_id != &cell.id)
+                        .chain(own_id.iter());
+                    for neighbor_id in sources {
+                        // Extra check: ignore if blacklisted (redundant if link removed, but safe)
+                        if cell.state.blacklist.contains(neighbor_id) {
+                            continue;
+                        }
+                        if let Some(neighbor_signals) = signals_by_source.get(neighbor_id) {
+                            // Relayed signals never loop back to a cell on their path.
+                            for signal in
+                                neighbor_signals.iter().filter(|s| !s.has_visited(&cell.id))
+                            {
+                                if signal.target.as_ref().is_none_or(|t| t == &cell.id) {
+                                    cell_signals.push((*signal).clone());
+                                }
                             }
-                            if let Some(neighbor_signals) = signals_by_source.get(neighbor_id) {
-                                for signal in neighbor_signals {
-                                    if signal.target.as_ref().is_none_or(|t| t == &cell.id) {
-                                        cell_signals.push((*signal).clone());
-                                    }
+                        }
+                    }
+
+                    // 3. Quarantined peers stay unlinked, but cells open to
+                    // rehabilitation still see their attested signals.
+                    if cell.genome.peer_rehabilitation {
+                        for peer_id in &cell.state.blacklist {
+                            let attested = signals_by_source
+                                .get(peer_id)
+                                .into_iter()
+                                .flatten()
+                                .filter(|signal| signal.attestation.is_some());
+                            for signal in attested {
+                                if signal.target.as_ref().is_none_or(|t| t == &cell.id) {
+                                    cell_signals.push((*signal).clone());
                                 }
                             }
                         }
                     }
-                }
 
-                cell_signals
+                    cell_signals.into()
+                };
+            let neighbor_signals: Arc<[Signal]> = if loss_rate > 0.0 {
+                let before = neighbor_signals.len();
+                let kept: Arc<[Signal]> = neighbor_signals
+                    .iter()
+                    .filter(|_| !self.loss_rng.gen_bool(loss_rate as f64))
+                    .cloned()
+                    .collect();
+                lost += before - kept.len();
+                kept
+            } else {
+                neighbor_signals
+            };
+            let neighbor_signals = match self.topology_config.max_incoming_signals {
+                Some(cap) if neighbor_signals.len() > cap => {
+                    self.telemetry.record(
+                        SystemTime::now(),
+                        TelemetryEvent::SignalsTruncated {
+                            cell_id: cell.id.clone(),
+                            delivered: neighbor_signals.len(),
+                            kept: cap,
+                        },
+                    );
+                    strongest_signals(&neighbor_signals, cap)
+                }
+                _ => neighbor_signals,
             };
 
+            if relay_hops > 0 {
+                for signal in neighbor_signals.iter() {
+                    let Some(relay) =
+                        relay_consensus(cell, signal, step_index, relay_hops, &self.pki)
+                    else {
+                        continue;
+                    };
+                    self.telemetry.record(
+                        SystemTime::now(),
+                        TelemetryEvent::SignalRelayed {
+                            cell_id: cell.id.clone(),
+                            topic: relay.topic.clone(),
+                            origin: relay.origin.clone().unwrap_or_default(),
+                            hops: relay.path.len() - 1,
+                        },
+                    );
+                    relays.push(relay);
+                }
+            }
+
             let detected_neighbors =
                 if matches!(self.topology_config.strategy, TopologyStrategy::Global) {
                     global_neighbors
@@ -227,70 +579,101 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                     self.neighbors.get(&cell.id).cloned().unwrap_or_default()
                 };
 
+            // Cells holding a filtered copy fall back to scanning it directly.
+            let topic_index = shared_index
+                .as_ref()
+                .filter(|index| Arc::ptr_eq(index.signals(), &neighbor_signals))
+                .cloned();
+
             let environment = CellEnvironment {
                 step: step_index,
                 local_threat_score: threat_score,
                 neighbor_signals,
                 detected_neighbors,
+                topic_index,
+                population_density,
+                pki: Some(self.pki.clone()),
+                recognized_topics: self.tables.recognized_topics.clone(),
+                lineage_economy: self.tables.lineage_economy.clone(),
+                adaptation_table: self.tables.adaptation_table.clone(),
             };
             let action = cell.tick(&environment);
             actions.push((index, action));
         }
 
-        for (index, action) in actions {
-            self.handle_action(index, action);
+        if lost > 0 {
+            self.telemetry.record(
+                SystemTime::now(),
+                TelemetryEvent::SignalsLost {
+                    step: step_index,
+                    count: lost,
+                },
+            );
         }
 
-        // Remove dead cells
-        let dead_ids: Vec<String> = self
-            .cells
-            .iter()
-            .filter(|c| c.state.dead)
-            .map(|c| c.id.clone())
-            .collect();
-
-        if !dead_ids.is_empty() {
-            self.cells.retain(|c| !c.state.dead);
-
-            if matches!(self.topology_config.strategy, TopologyStrategy::Graph) {
-                for dead_id in dead_ids {
-                    if let Some(neighbors) = self.neighbors.remove(&dead_id) {
-                        for neighbor in neighbors {
-                            self.telemetry.record(
-                                SystemTime::now(),
-                                TelemetryEvent::LinkRemoved {
-                                    source: dead_id.clone(),
-                                    target: neighbor,
-                                },
-                            );
-                        }
-                    }
-                    for (neighbor_id, neighbors) in self.neighbors.iter_mut() {
-                        if let Some(pos) = neighbors.iter().position(|x| x == &dead_id) {
-                            neighbors.remove(pos);
-                            // We already logged the link removal from the dead cell's perspective.
-                            // Since it's an undirected graph (effectively), one event is enough to signify the break?
-                            // Or should we log both directions? Let's stick to one event per "logical link" break if possible,
-                            // but logging both is safer for reconstruction.
-                            // Actually, let's just rely on the first loop to catch the explicit connections.
-                            // But wait, if A is neighbor of B, B is neighbor of A.
-                            // Removing A from B's list is the other half.
-                            // Let's log it for completeness so the graph reconstruction is robust.
-                            self.telemetry.record(
-                                SystemTime::now(),
-                                TelemetryEvent::LinkRemoved {
-                                    source: neighbor_id.clone(),
-                                    target: dead_id.clone(),
-                                },
-                            );
-                        }
+        if let Some(cap) = self.topology_config.max_replications_per_step {
+            let mut deferred = 0;
+            let mut allowed = cap;
+            for (_, action) in actions.iter_mut() {
+                if matches!(action, CellAction::Replicate(_)) {
+                    if allowed > 0 {
+                        allowed -= 1;
+                    } else {
+                        *action = CellAction::Idle;
+                        deferred += 1;
                     }
                 }
             }
+            if deferred > 0 {
+                self.telemetry.record(
+                    SystemTime::now(),
+                    TelemetryEvent::ReplicationDeferred {
+                        step: step_index,
+                        deferred,
+                    },
+                );
+            }
+        }
+        if let Some(trace) = self.action_trace.as_mut() {
+            let cap = self.topology_config.action_trace_cap;
+            for (index, action) in &actions {
+                trace.push_back((step_index, self.cells[*index].id.clone(), action.clone()));
+            }
+            if trace.len() > cap {
+                trace.drain(..trace.len() - cap);
+            }
+        }
+        let activator_volume = actions
+            .iter()
+            .map(|(_, action)| match action {
+                CellAction::EmitSignal(topic, value) if topic == "activator" => *value,
+                _ => 0.0,
+            })
+            .sum();
+        self.probe_feedback_loop(step_index, activator_volume);
+        let idle_cells = self.topology_config.record_idle.then(|| {
+            actions
+                .iter()
+                .filter(|(_, action)| matches!(action, CellAction::Idle))
+                .count()
+        });
+        for (index, action) in actions {
+            self.handle_action(index, action);
+        }
+        for relay in relays {
+            self.signal_bus.publish(relay);
         }
 
+        self.remove_dead_cells();
+        self.record_trust_snapshots(step_index);
+
         let cell_count = self.cells.len();
-        let population_stats = if step_index.is_multiple_of(10) || cell_count < 500 {
+        let sample_population = match self.topology_config.population_stats_interval {
+            Some(0) => false,
+            Some(interval) => step_index.is_multiple_of(interval),
+            None => step_index.is_multiple_of(10) || cell_count < 500,
+        };
+        let population_stats = if sample_population {
             Some(PopulationStats::from_cells(&self.cells))
         } else {
             None
@@ -298,6 +681,8 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
 
         let topology_stats = Some(self.calculate_topology_stats());
 
+        let lineage_counts = lineage_histogram(&self.cells);
+
         self.telemetry.record(
             SystemTime::now(),
             TelemetryEvent::StepSummary {
@@ -306,18 +691,130 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                 cell_count,
                 population_stats,
                 topology_stats,
+                lineage_counts: Some(lineage_counts),
+                idle_cells,
+            },
+        );
+    }
+
+    /// Track emitted activator volume and report a `FeedbackLoopDetected`
+    /// once it has grown by `feedback_growth_threshold` or more for
+    /// [`FEEDBACK_LOOP_WINDOW`] consecutive steps. A sustained run is
+    /// reported once; the streak resets when growth falls below the threshold.
+    fn probe_feedback_loop(&mut self, step_index: u32, activator_volume: f32) {
+        let Some(threshold) = self.topology_config.feedback_growth_threshold else {
+            return;
+        };
+        let previous = std::mem::replace(&mut self.last_activator_volume, activator_volume);
+        if previous <= 0.0 {
+            self.activator_growth_streak = 0;
+            return;
+        }
+        let growth_rate = activator_volume / previous;
+        if growth_rate < threshold {
+            self.activator_growth_streak = 0;
+            return;
+        }
+        self.activator_growth_streak += 1;
+        if self.activator_growth_streak == FEEDBACK_LOOP_WINDOW {
+            self.telemetry.record(
+                SystemTime::now(),
+                TelemetryEvent::FeedbackLoopDetected {
+                    step: step_index,
+                    growth_rate,
+                },
+            );
+        }
+    }
+
+    /// Kill `kill_fraction` of the live cells, chosen with `rng`, and return
+    /// how many were removed.
+    pub fn inject_fault<R: Rng + ?Sized>(
+        &mut self,
+        step_index: u32,
+        kill_fraction: f32,
+        rng: &mut R,
+    ) -> usize {
+        let live: Vec<usize> = (0..self.cells.len())
+            .filter(|&index| !self.cells[index].state.dead)
+            .collect();
+        let killed = (live.len() as f32 * kill_fraction.clamp(0.0, 1.0)).round() as usize;
+        for &index in live.choose_multiple(rng, killed) {
+            self.cells[index].state.dead = true;
+        }
+        self.telemetry.record(
+            SystemTime::now(),
+            TelemetryEvent::FaultInjected {
+                step: step_index,
+                killed,
             },
         );
+        self.remove_dead_cells();
+        killed
+    }
+
+    fn remove_dead_cells(&mut self) {
+        let dead_ids: Vec<String> = self
+            .cells
+            .iter()
+            .filter(|c| c.state.dead)
+            .map(|c| c.id.clone())
+            .collect();
+
+        if dead_ids.is_empty() {
+            return;
+        }
+
+        self.cells.retain(|c| !c.state.dead);
+        for dead_id in &dead_ids {
+            self.track_cell_removed(dead_id);
+        }
+
+        if matches!(self.topology_config.strategy, TopologyStrategy::Graph) {
+            for dead_id in dead_ids {
+                if let Some(neighbors) = self.neighbors.remove(&dead_id) {
+                    self.record_degree_change(&dead_id, neighbors.len(), 0);
+                    for neighbor in neighbors {
+                        self.telemetry.record(
+                            SystemTime::now(),
+                            TelemetryEvent::LinkRemoved {
+                                source: dead_id.clone(),
+                                target: neighbor,
+                            },
+                        );
+                    }
+                }
+                // Log the reverse half of each broken link too so graph
+                // reconstruction from telemetry stays robust.
+                let holders: Vec<String> = self
+                    .neighbors
+                    .iter()
+                    .filter(|(_, neighbors)| neighbors.contains(&dead_id))
+                    .map(|(neighbor_id, _)| neighbor_id.clone())
+                    .collect();
+                for neighbor_id in holders {
+                    self.remove_link(&neighbor_id, &dead_id);
+                    self.telemetry.record(
+                        SystemTime::now(),
+                        TelemetryEvent::LinkRemoved {
+                            source: neighbor_id,
+                            target: dead_id.clone(),
+                        },
+                    );
+                }
+            }
+        }
     }
 
     fn handle_action(&mut self, index: usize, action: CellAction) {
         match action {
             CellAction::Idle => {}
             CellAction::Replicate(child_id) => {
-                if self.cells.len() >= 100 {
-                    return; // Cap population at 100
+                if self.cells.len() >= MAX_POPULATION {
+                    return; // Cap population at carrying capacity
                 }
                 let mut child = SecurityCell::new(child_id.clone());
+                self.pki.register(&child.tpm);
                 // Inherit genome and immune memory from parent
                 child.genome = self.cells[index].genome.clone();
                 child.state.immune_memory = self.cells[index].state.immune_memory.clone();
@@ -325,27 +822,46 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                 // Or should it inherit "reputation data"?
                 // Let's inherit it for now, assuming "gossip" is passed down.
                 child.state.neighbor_trust = self.cells[index].state.neighbor_trust.clone();
-                child.genome.mutate();
+                let changed_fields = child.genome.mutate_with(&mut rand::thread_rng());
+                if self.topology_config.record_genome_mutations && !changed_fields.is_empty() {
+                    self.telemetry.record(
+                        SystemTime::now(),
+                        TelemetryEvent::GenomeMutated {
+                            cell_id: child_id.clone(),
+                            changed_fields,
+                        },
+                    );
+                }
 
                 let parent_id = self.cells[index].id.clone();
+                let child_id_for_ledger = child_id.clone();
 
                 if matches!(self.topology_config.strategy, TopologyStrategy::Graph) {
-                    self.neighbors
-                        .entry(parent_id.clone())
-                        .or_default()
-                        .push(child_id.clone());
-                    self.neighbors
-                        .entry(child_id.clone())
-                        .or_default()
-                        .push(parent_id.clone());
+                    // Attach to the parent, or to the first of its neighbors
+                    // with spare degree when the parent is saturated.
+                    let anchor = if self.link_allowed(&parent_id, &child_id) {
+                        Some(parent_id.clone())
+                    } else {
+                        self.record_link_rejected(&parent_id, &child_id);
+                        self.neighbors
+                            .get(&parent_id)
+                            .into_iter()
+                            .flatten()
+                            .find(|neighbor| self.link_allowed(neighbor, &child_id))
+                            .cloned()
+                    };
+                    if let Some(anchor) = anchor {
+                        self.push_link(&anchor, &child_id);
+                        self.push_link(&child_id, &anchor);
 
-                    self.telemetry.record(
-                        SystemTime::now(),
-                        TelemetryEvent::LinkAdded {
-                            source: parent_id.clone(),
-                            target: child_id.clone(),
-                        },
-                    );
+                        self.telemetry.record(
+                            SystemTime::now(),
+                            TelemetryEvent::LinkAdded {
+                                source: anchor,
+                                target: child_id.clone(),
+                            },
+                        );
+                    }
                 }
 
                 self.telemetry.record(
@@ -356,11 +872,45 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                     },
                 );
                 self.cells.push(child);
+                self.track_cell_added(&child_id_for_ledger);
             }
             CellAction::Differentiate(lineage) => {
-                if let Some(cell) = self.cells.get_mut(index) {
-                    cell.state.lineage = lineage.clone();
+                let current = self.cells[index].state.lineage.clone();
+                if !self
+                    .topology_config
+                    .lineage_rules
+                    .permits(&current, &lineage)
+                {
+                    self.telemetry.record(
+                        SystemTime::now(),
+                        TelemetryEvent::DifferentiationBlocked {
+                            cell_id: self.cells[index].id.clone(),
+                            from: format!("{current:?}"),
+                            to: format!("{lineage:?}"),
+                        },
+                    );
+                    return;
+                }
+                let step = self.current_step;
+                let cell = &mut self.cells[index];
+                let cooldown = cell.genome.differentiation_cooldown;
+                if let Some(last) = cell.state.last_differentiation_step {
+                    let steps_since_last = step.saturating_sub(last);
+                    if steps_since_last < cooldown {
+                        let cell_id = cell.id.clone();
+                        self.telemetry.record(
+                            SystemTime::now(),
+                            TelemetryEvent::DifferentiationThrottled {
+                                cell_id,
+                                to: format!("{lineage:?}"),
+                                steps_since_last,
+                            },
+                        );
+                        return;
+                    }
                 }
+                cell.state.lineage = lineage.clone();
+                cell.state.last_differentiation_step = Some(step);
                 self.telemetry.record(
                     SystemTime::now(),
                     TelemetryEvent::LineageShift {
@@ -371,13 +921,9 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
             }
             CellAction::EmitSignal(topic, value) => {
                 let cell_id = self.cells[index].id.clone();
-                self.signal_bus.publish(Signal {
-                    topic: topic.clone(),
-                    value,
-                    source: Some(cell_id.clone()),
-                    target: None, // Broadcast by default
-                    attestation: None,
-                });
+                // Broadcast by default
+                self.signal_bus
+                    .publish(Signal::new(topic.clone(), value).with_source(cell_id.clone()));
                 self.telemetry.record(
                     SystemTime::now(),
                     TelemetryEvent::SignalEmitted {
@@ -405,21 +951,17 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                     && !cell.state.blacklist.contains(&target_id)
                 {
                     cell.state.blacklist.push(target_id.clone());
+                    // Rehabilitation has to be earned with fresh attestations.
+                    if cell.genome.peer_rehabilitation {
+                        cell.state.neighbor_trust.insert(target_id.clone(), 0.0);
+                    }
                 }
 
                 if matches!(self.topology_config.strategy, TopologyStrategy::Graph) {
                     // Remove forward link
-                    if let Some(neighbors) = self.neighbors.get_mut(&cell_id)
-                        && let Some(pos) = neighbors.iter().position(|x| x == &target_id)
-                    {
-                        neighbors.remove(pos);
-                    }
+                    self.remove_link(&cell_id, &target_id);
                     // Remove backward link (undirected graph assumption for now, or just symmetric)
-                    if let Some(neighbors) = self.neighbors.get_mut(&target_id)
-                        && let Some(pos) = neighbors.iter().position(|x| x == &cell_id)
-                    {
-                        neighbors.remove(pos);
-                    }
+                    self.remove_link(&target_id, &cell_id);
 
                     self.telemetry.record(
                         SystemTime::now(),
@@ -445,46 +987,73 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
                     );
                 }
             }
-            CellAction::Connect(target_id) => {
+            CellAction::Quarantine(target_id) => {
+                let cell_id = self.cells[index].id.clone();
+                self.handle_action(index, CellAction::Disconnect(target_id.clone()));
+                // Global disconnects already report the quarantine.
                 if matches!(self.topology_config.strategy, TopologyStrategy::Graph) {
-                    let cell_id = self.cells[index].id.clone();
-                    // Add forward link
-                    self.neighbors
-                        .entry(cell_id.clone())
-                        .or_default()
-                        .push(target_id.clone());
-                    // Add backward link
-                    self.neighbors
-                        .entry(target_id.clone())
-                        .or_default()
-                        .push(cell_id.clone());
-
                     self.telemetry.record(
                         SystemTime::now(),
-                        TelemetryEvent::LinkAdded {
-                            source: cell_id,
-                            target: target_id,
-                        },
+                        TelemetryEvent::PeerQuarantined { cell_id, target_id },
                     );
                 }
             }
-            CellAction::ReportAnomaly(topic, confidence, target, attestation) => {
-                let cell_id = self.cells[index].id.clone();
+            CellAction::Rehabilitate(target_id) => {
+                let cell = &mut self.cells[index];
+                if !cell.genome.peer_rehabilitation {
+                    return;
+                }
+                let blacklist = &mut cell.state.blacklist;
+                let Some(position) = blacklist.iter().position(|peer| *peer == target_id) else {
+                    return;
+                };
+                blacklist.remove(position);
                 self.telemetry.record(
                     SystemTime::now(),
-                    TelemetryEvent::AnomalyDetected {
-                        cell_id: cell_id.clone(),
+                    TelemetryEvent::PeerRehabilitated {
+                        cell_id: cell.id.clone(),
+                        peer_id: target_id.clone(),
+                    },
+                );
+                self.handle_action(index, CellAction::Connect(target_id));
+            }
+            CellAction::Connect(target_id) => {
+                let cell_id = self.cells[index].id.clone();
+                if matches!(self.topology_config.strategy, TopologyStrategy::Graph) {
+                    if !self.link_allowed(&cell_id, &target_id) {
+                        self.record_link_rejected(&cell_id, &target_id);
+                        return;
+                    }
+                    // Add forward link
+                    self.push_link(&cell_id, &target_id);
+                    // Add backward link
+                    self.push_link(&target_id, &cell_id);
+
+                    self.telemetry.record(
+                        SystemTime::now(),
+                        TelemetryEvent::LinkAdded {
+                            source: cell_id,
+                            target: target_id,
+                        },
+                    );
+                }
+            }
+            CellAction::ReportAnomaly(topic, confidence, target, attestation) => {
+                let cell_id = self.cells[index].id.clone();
+                self.telemetry.record(
+                    SystemTime::now(),
+                    TelemetryEvent::AnomalyDetected {
+                        cell_id: cell_id.clone(),
                         topic: topic.clone(),
                         confidence,
                     },
                 );
                 // Also publish a 'consensus' signal to neighbors
                 self.signal_bus.publish(Signal {
-                    topic: format!("consensus:{}", topic),
-                    value: confidence,
-                    source: Some(cell_id.clone()),
                     target,
                     attestation,
+                    ..Signal::new(format!("consensus:{}", topic), confidence)
+                        .with_source(cell_id.clone())
                 });
                 self.telemetry.record(
                     SystemTime::now(),
@@ -497,6 +1066,168 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
         }
     }
 
+    /// Build a fresh population from exported genomes, one cell per id.
+    /// Cells are created in id order so reseeded runs are reproducible.
+    pub fn seed_from_genomes(
+        genomes: HashMap<String, CellGenome>,
+        telemetry: TSink,
+        topology_config: TopologyConfig,
+    ) -> Self {
+        let mut genomes: Vec<(String, CellGenome)> = genomes.into_iter().collect();
+        genomes.sort_by(|a, b| a.0.cmp(&b.0));
+        let cells = genomes
+            .into_iter()
+            .map(|(id, genome)| {
+                let mut cell = SecurityCell::new(id);
+                cell.genome = genome;
+                cell
+            })
+            .collect();
+        Self::new(cells, telemetry, topology_config)
+    }
+
+    /// Current genome of every live cell, keyed by cell id.
+    pub fn export_genomes(&self) -> HashMap<String, CellGenome> {
+        self.cells
+            .iter()
+            .map(|cell| (cell.id.clone(), cell.export_genome()))
+            .collect()
+    }
+
+    /// Graphviz DOT snapshot of the current topology: one node per live cell
+    /// labelled with its lineage, and each undirected link exactly once.
+    pub fn export_topology_dot(&self) -> String {
+        let mut dot = String::from("graph topology {\n");
+        for (id, lineage) in self.topology_nodes() {
+            let id = dot_escape(id);
+            dot.push_str(&format!("  \"{id}\" [label=\"{id}\\n{lineage:?}\"];\n"));
+        }
+        for (a, b) in self.undirected_edges() {
+            dot.push_str(&format!(
+                "  \"{}\" -- \"{}\";\n",
+                dot_escape(a),
+                dot_escape(b)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// GraphML equivalent of [`Self::export_topology_dot`], with lineage
+    /// stored as a node data attribute.
+    pub fn export_topology_graphml(&self) -> String {
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"lineage\" for=\"node\" attr.name=\"lineage\" attr.type=\"string\"/>\n",
+            "  <graph id=\"topology\" edgedefault=\"undirected\">\n",
+        ));
+        for (id, lineage) in self.topology_nodes() {
+            xml.push_str(&format!(
+                "    <node id=\"{}\"><data key=\"lineage\">{lineage:?}</data></node>\n",
+                xml_escape(id)
+            ));
+        }
+        for (a, b) in self.undirected_edges() {
+            xml.push_str(&format!(
+                "    <edge source=\"{}\" target=\"{}\"/>\n",
+                xml_escape(a),
+                xml_escape(b)
+            ));
+        }
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+
+    /// Live cells in population order, one entry per distinct id.
+    fn topology_nodes(&self) -> Vec<(&str, &CellLineage)> {
+        let mut seen = HashSet::new();
+        self.cells
+            .iter()
+            .filter(|cell| seen.insert(cell.id.as_str()))
+            .map(|cell| (cell.id.as_str(), &cell.state.lineage))
+            .collect()
+    }
+
+    /// Adjacency links with both directions folded into one sorted pair.
+    fn undirected_edges(&self) -> BTreeSet<(&str, &str)> {
+        self.neighbors
+            .iter()
+            .flat_map(|(from, targets)| {
+                targets.iter().map(move |to| {
+                    if from.as_str() <= to.as_str() {
+                        (from.as_str(), to.as_str())
+                    } else {
+                        (to.as_str(), from.as_str())
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Periodically record trust tables for an evenly strided sample of cells
+    /// that hold any trust entries, capped to bound telemetry volume.
+    fn record_trust_snapshots(&mut self, step_index: u32) {
+        let interval = self.topology_config.trust_snapshot_interval;
+        let max_cells = self.topology_config.trust_snapshot_max_cells;
+        if interval == 0 || max_cells == 0 || !step_index.is_multiple_of(interval) {
+            return;
+        }
+
+        let candidates: Vec<&SecurityCell> = self
+            .cells
+            .iter()
+            .filter(|cell| !cell.state.neighbor_trust.is_empty())
+            .collect();
+        let stride = candidates.len().div_ceil(max_cells).max(1);
+        let snapshots: Vec<(String, BTreeMap<String, f32>)> = candidates
+            .into_iter()
+            .step_by(stride)
+            .take(max_cells)
+            .map(|cell| {
+                let trust = cell
+                    .state
+                    .neighbor_trust
+                    .iter()
+                    .map(|(peer, trust)| (peer.clone(), *trust))
+                    .collect();
+                (cell.id.clone(), trust)
+            })
+            .collect();
+
+        for (cell_id, trust) in snapshots {
+            self.telemetry.record(
+                SystemTime::now(),
+                TelemetryEvent::TrustSnapshot { cell_id, trust },
+            );
+        }
+    }
+
+    pub fn cells(&self) -> &[SecurityCell] {
+        &self.cells
+    }
+
+    /// Persistable copies of every cell, holding only public keys. Restore
+    /// each with [`CellSnapshot::restore`], which issues fresh key pairs.
+    pub fn snapshot_cells(&self) -> Vec<CellSnapshot> {
+        self.cells.iter().map(SecurityCell::snapshot).collect()
+    }
+
+    /// Like [`Self::snapshot_cells`], but carrying each cell's signing key so
+    /// restored cells keep their identities. The keys are only obfuscated.
+    pub fn snapshot_cells_with_keys(&self) -> Vec<CellSnapshot> {
+        self.cells
+            .iter()
+            .map(SecurityCell::snapshot_with_keys)
+            .collect()
+    }
+
+    /// Per-step actions, oldest first, when `trace_actions` is enabled.
+    /// Only the last `action_trace_cap` entries are kept.
+    pub fn action_trace(&self) -> Option<&VecDeque<(u32, String, CellAction)>> {
+        self.action_trace.as_ref()
+    }
+
     #[allow(dead_code)]
     pub fn telemetry(&self) -> &TSink {
         &self.telemetry
@@ -507,15 +1238,161 @@ impl<TSink: TelemetrySink> MorphogeneticApp<TSink> {
         &mut self.telemetry
     }
 
+    /// Drop every signal on `topic` before delivery until unmuted.
+    pub fn mute_topic(&mut self, topic: &str) {
+        if self.muted_topics.insert(topic.to_string()) {
+            self.telemetry.record(
+                SystemTime::now(),
+                TelemetryEvent::TopicMuted {
+                    topic: topic.to_string(),
+                },
+            );
+        }
+    }
+
+    /// Resume delivery of a topic previously silenced with [`Self::mute_topic`].
+    pub fn unmute_topic(&mut self, topic: &str) {
+        if self.muted_topics.remove(topic) {
+            self.telemetry.record(
+                SystemTime::now(),
+                TelemetryEvent::TopicUnmuted {
+                    topic: topic.to_string(),
+                },
+            );
+        }
+    }
+
     #[allow(dead_code)]
     pub fn inject_signal(&mut self, signal: Signal) {
         self.signal_bus.publish(signal);
     }
+
+    /// Publish a stimulus command so cells receive it on the next step.
+    ///
+    /// The command's `step` and `duration` are ignored: it is delivered once,
+    /// and interactive callers re-inject it to sustain it. Topic aliases are
+    /// not applied; use [`crate::config::ScenarioConfig::stimulus_signal`]
+    /// with [`Self::inject_signal`] when they matter.
+    pub fn inject_stimulus(&mut self, command: StimulusCommand) {
+        self.inject_signal(command.to_signal());
+    }
+
+    /// [`Self::inject_stimulus`] for every command in `commands`.
+    pub fn inject_stimulus_batch(&mut self, commands: impl IntoIterator<Item = StimulusCommand>) {
+        for command in commands {
+            self.inject_stimulus(command);
+        }
+    }
+}
+
+fn dot_escape(raw: &str) -> String {
+    raw.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn xml_escape(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Global-mode delivery view for a single cell.
+///
+/// Every cell shares the step's signal buffer; only cells that have
+/// blacklisted one of this step's senders pay for a filtered copy. With
+/// `admit_attested`, blacklisted senders' attested signals still get through
+/// so their trust can recover.
+fn global_signal_view(
+    signals: &Arc<[Signal]>,
+    blacklist: &[String],
+    admit_attested: bool,
+    self_source: Option<&str>,
+) -> Arc<[Signal]> {
+    let blocked = |signal: &Signal| {
+        signal.source.as_ref().is_some_and(|source| {
+            self_source == Some(source.as_str())
+                || (blacklist.contains(source) && !(admit_attested && signal.attestation.is_some()))
+        })
+    };
+    if !signals.iter().any(blocked) {
+        return Arc::clone(signals);
+    }
+    signals
+        .iter()
+        .filter(|signal| !blocked(signal))
+        .cloned()
+        .collect()
+}
+
+/// Re-broadcast a verified consensus signal from `cell`, extending its relay
+/// path. The copy is attested by `cell`, so the attestation stays bound to the
+/// immediate sender while `origin` keeps the original accuser. Returns `None`
+/// for anything unattested, already visited, or past `max_hops` relays.
+fn relay_consensus(
+    cell: &SecurityCell,
+    signal: &Signal,
+    step: u32,
+    max_hops: usize,
+    pki: &Pki,
+) -> Option<Signal> {
+    if !signal.topic.starts_with("consensus:") || signal.has_visited(&cell.id) {
+        return None;
+    }
+    let (source, attestation) = (signal.source.as_ref()?, signal.attestation.as_ref()?);
+    if source == &cell.id
+        || attestation.cell_id != *source
+        || !pki.verify(attestation, step as u64, &attestation_payload(signal))
+    {
+        return None;
+    }
+    let mut path = if signal.path.is_empty() {
+        vec![source.clone()]
+    } else {
+        signal.path.clone()
+    };
+    if path.len() > max_hops {
+        return None;
+    }
+    path.push(cell.id.clone());
+    let mut relay = Signal {
+        topic: signal.topic.clone(),
+        value: signal.value,
+        source: Some(cell.id.clone()),
+        target: signal.target.clone(),
+        attestation: None,
+        origin: signal.true_origin().map(str::to_string),
+        path,
+    };
+    // A cell whose TPM can no longer attest cannot vouch for the relay.
+    relay.attestation = Some(cell.tpm.attest(step as u64, &attestation_payload(&relay))?);
+    Some(relay)
+}
+
+/// The `cap` highest-value signals, kept in their original delivery order.
+fn strongest_signals(signals: &[Signal], cap: usize) -> Arc<[Signal]> {
+    let mut ranked: Vec<usize> = (0..signals.len()).collect();
+    ranked.sort_by(|&a, &b| signals[b].value.total_cmp(&signals[a].value));
+    ranked.truncate(cap);
+    ranked.sort_unstable();
+    ranked
+        .into_iter()
+        .map(|index| signals[index].clone())
+        .collect()
+}
+
+/// Live cells per lineage.
+fn lineage_histogram(cells: &[SecurityCell]) -> BTreeMap<CellLineage, usize> {
+    let mut counts = BTreeMap::new();
+    for cell in cells {
+        *counts.entry(cell.state.lineage.clone()).or_insert(0) += 1;
+    }
+    counts
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cellular::{CellLineage, LineageRules};
     use crate::telemetry::InMemorySink;
 
     #[test]
@@ -529,8 +1406,10 @@ mod tests {
         cell_a.genome.signal_emission_threshold = 0.4; // Low threshold
         let mut cell_b = SecurityCell::new("B");
         cell_b.genome.signal_emission_threshold = 0.4;
+        cell_b.state.neighbor_trust.insert("A".to_string(), 1.0);
         let mut cell_c = SecurityCell::new("C");
         cell_c.genome.signal_emission_threshold = 0.4;
+        cell_c.state.neighbor_trust.insert("A".to_string(), 1.0);
 
         cells.push(cell_a);
         cells.push(cell_b);
@@ -542,6 +1421,7 @@ mod tests {
                 vec!["A".to_string(), "B".to_string()],
                 vec!["B".to_string(), "C".to_string()],
             ]),
+            ..TopologyConfig::default()
         };
 
         let telemetry = InMemorySink::default();
@@ -549,13 +1429,7 @@ mod tests {
 
         // Inject signal "from A" (spoofed source)
         // Topic 'activator' increases effective threat.
-        app.inject_signal(Signal {
-            topic: "activator".to_string(),
-            value: 0.5,
-            source: Some("A".to_string()),
-            target: None,
-            attestation: None,
-        });
+        app.inject_signal(Signal::new("activator", 0.5).with_source("A"));
 
         // Step 1
         // B is neighbor of A, should receive 0.5. Effective threat = 0.5 >= 0.4. Should emit signal.
@@ -608,8 +1482,10 @@ mod tests {
         cell_a.genome.signal_emission_threshold = 0.4;
         let mut cell_b = SecurityCell::new("B");
         cell_b.genome.signal_emission_threshold = 0.4;
+        cell_b.state.neighbor_trust.insert("A".to_string(), 1.0);
         let mut cell_c = SecurityCell::new("C");
         cell_c.genome.signal_emission_threshold = 0.4;
+        cell_c.state.neighbor_trust.insert("A".to_string(), 1.0);
 
         cells.push(cell_a);
         cells.push(cell_b);
@@ -618,18 +1494,14 @@ mod tests {
         let topology_config = TopologyConfig {
             strategy: TopologyStrategy::Global,
             explicit_links: None,
+            ..TopologyConfig::default()
         };
 
         let telemetry = InMemorySink::default();
         let mut app = MorphogeneticApp::new(cells, telemetry, topology_config);
 
-        app.inject_signal(Signal {
-            topic: "activator".to_string(),
-            value: 0.5,
-            source: Some("A".to_string()), // Source shouldn't matter for Global, but we provide it
-            target: None,
-            attestation: None,
-        });
+        // Source shouldn't matter for Global, but we provide it
+        app.inject_signal(Signal::new("activator", 0.5).with_source("A"));
 
         app.step(0, 0.0);
 
@@ -653,4 +1525,1378 @@ mod tests {
         assert!(b_emitted, "Cell B should have received global signal");
         assert!(c_emitted, "Cell C should have received global signal");
     }
+
+    #[test]
+    fn thousand_cell_population_steps_in_both_topologies() {
+        for strategy in [TopologyStrategy::Global, TopologyStrategy::Graph] {
+            let cells: Vec<SecurityCell> = (0..1000)
+                .map(|idx| SecurityCell::new(format!("seed-{idx}")))
+                .collect();
+            let explicit_links = matches!(strategy, TopologyStrategy::Graph).then(|| {
+                (1..1000)
+                    .map(|idx| vec![format!("seed-{}", idx - 1), format!("seed-{idx}")])
+                    .collect()
+            });
+            let topology_config = TopologyConfig {
+                strategy,
+                explicit_links,
+                ..TopologyConfig::default()
+            };
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+
+            for idx in 0..100 {
+                app.inject_signal(
+                    Signal::new("cooperative", 0.01).with_source(format!("seed-{idx}")),
+                );
+            }
+            app.step(0, 0.0);
+
+            assert_eq!(app.cells.len(), 1000);
+            assert!(app.telemetry().events().iter().any(|snapshot| matches!(
+                snapshot.event,
+                TelemetryEvent::StepSummary {
+                    cell_count: 1000,
+                    ..
+                }
+            )));
+        }
+    }
+
+    #[test]
+    fn global_delivery_shares_signal_buffer_across_cells() {
+        let signals: Arc<[Signal]> = (0..100)
+            .map(|idx| Signal::new("activator", 0.1).with_source(format!("seed-{idx}")))
+            .collect();
+
+        let clean_blacklist: Vec<String> = Vec::new();
+        let views: Vec<Arc<[Signal]>> = (0..1000)
+            .map(|_| global_signal_view(&signals, &clean_blacklist, false, None))
+            .collect();
+        assert!(views.iter().all(|view| Arc::ptr_eq(view, &signals)));
+        assert_eq!(Arc::strong_count(&signals), 1001);
+
+        // A cell that blacklisted a sender gets its own filtered copy.
+        let filtered = global_signal_view(&signals, &["seed-3".to_string()], false, None);
+        assert!(!Arc::ptr_eq(&filtered, &signals));
+        assert_eq!(filtered.len(), 99);
+        assert!(
+            filtered
+                .iter()
+                .all(|signal| signal.source.as_deref() != Some("seed-3"))
+        );
+    }
+
+    #[test]
+    fn global_self_delivery_feeds_own_activator_into_next_step() {
+        let emitted_at_step_one = |deliver_self_signals: bool| {
+            let mut cell = SecurityCell::new("solo");
+            cell.genome.signal_emission_threshold = 0.2;
+            cell.genome.reproduction_threshold = 10.0;
+            cell.genome.stress_differentiation_threshold = 10.0;
+            let topology_config = TopologyConfig {
+                deliver_self_signals,
+                ..TopologyConfig::default()
+            };
+            let mut app =
+                MorphogeneticApp::new(vec![cell], InMemorySink::default(), topology_config);
+            app.step(0, 0.5);
+            let before = app.telemetry().events().len();
+            app.step(1, 0.0);
+            app.telemetry().events()[before..]
+                .iter()
+                .filter_map(|snapshot| match &snapshot.event {
+                    TelemetryEvent::SignalEmitted { topic, value, .. } if topic == "activator" => {
+                        Some(*value)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<f32>>()
+        };
+
+        // Default: the cell never hears its own step-0 emission and stays quiet.
+        assert!(emitted_at_step_one(false).is_empty());
+
+        // Self-delivery: the step-0 activator alone, weighted by the neutral
+        // trust the cell holds in itself, pushes it over threshold again.
+        let emitted = emitted_at_step_one(true);
+        assert_eq!(emitted.len(), 1);
+        assert!((emitted[0] - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn injected_stimulus_raises_stress_on_the_next_step() {
+        let run = |commands: Vec<StimulusCommand>| {
+            let mut cell = SecurityCell::new("listener");
+            cell.genome.reproduction_threshold = f32::MAX;
+            cell.genome.stress_differentiation_threshold = f32::MAX;
+            let mut app = MorphogeneticApp::new(
+                vec![cell],
+                InMemorySink::default(),
+                TopologyConfig::default(),
+            );
+            app.step(0, 0.0);
+            app.inject_stimulus_batch(commands);
+            app.step(1, 0.0);
+            app.cells()[0].state.stress_level
+        };
+        let activator = |value| StimulusCommand {
+            step: 0,
+            topic: "activator".to_string(),
+            value,
+            target: None,
+            source: None,
+            duration: 1,
+        };
+
+        let quiet = run(Vec::new());
+        let stimulated = run(vec![activator(0.5), activator(0.3)]);
+        assert!(
+            stimulated > quiet,
+            "stimulated stress {stimulated} should exceed quiet stress {quiet}"
+        );
+
+        let mut cell = SecurityCell::new("listener");
+        cell.genome.reproduction_threshold = f32::MAX;
+        cell.genome.stress_differentiation_threshold = f32::MAX;
+        let mut app = MorphogeneticApp::new(
+            vec![cell],
+            InMemorySink::default(),
+            TopologyConfig::default(),
+        );
+        app.step(0, 0.0);
+        app.inject_stimulus(activator(0.8));
+        app.step(1, 0.0);
+        assert!((app.cells()[0].state.stress_level - stimulated).abs() < 1e-6);
+    }
+
+    #[test]
+    fn runaway_activator_echo_reports_feedback_loop() {
+        let run = |feedback_growth_threshold: Option<f32>| {
+            let cells = ["a", "b", "c"]
+                .into_iter()
+                .map(|id| {
+                    let mut cell = SecurityCell::new(id);
+                    cell.genome.reproduction_threshold = f32::MAX;
+                    cell.genome.stress_differentiation_threshold = f32::MAX;
+                    cell.genome.isolation_threshold = f32::MAX;
+                    cell.genome.anomaly_sensitivity = f32::MAX;
+                    cell.genome.energy_threat_drain = 0.0;
+                    for peer in ["a", "b", "c"] {
+                        cell.state.neighbor_trust.insert(peer.to_string(), 1.0);
+                    }
+                    cell
+                })
+                .collect();
+            let topology_config = TopologyConfig {
+                feedback_growth_threshold,
+                ..TopologyConfig::default()
+            };
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            app.inject_signal(Signal::new("activator", 0.7));
+            for step in 0..6 {
+                app.step(step, 0.0);
+            }
+            app.telemetry()
+                .events()
+                .iter()
+                .filter_map(|snapshot| match snapshot.event {
+                    TelemetryEvent::FeedbackLoopDetected { step, growth_rate } => {
+                        Some((step, growth_rate))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Every cell echoes the other two, doubling activator each step.
+        let detections = run(Some(1.5));
+        assert_eq!(detections.len(), 1, "{detections:?}");
+        let (step, growth_rate) = detections[0];
+        assert_eq!(step, FEEDBACK_LOOP_WINDOW);
+        assert!((growth_rate - 2.0).abs() < 1e-3, "{growth_rate}");
+
+        assert!(run(Some(2.5)).is_empty());
+        assert!(run(None).is_empty());
+    }
+
+    #[test]
+    fn persistent_signals_fade_across_steps() {
+        let stress_trajectory = |signal_persistence: f32| {
+            let mut cell = SecurityCell::new("listener");
+            cell.genome.reproduction_threshold = f32::MAX;
+            cell.genome.stress_differentiation_threshold = f32::MAX;
+            cell.genome.signal_emission_threshold = f32::MAX;
+            cell.genome.isolation_threshold = f32::MAX;
+            cell.genome.anomaly_sensitivity = f32::MAX;
+            let topology_config = TopologyConfig {
+                signal_persistence,
+                ..TopologyConfig::default()
+            };
+            let mut app =
+                MorphogeneticApp::new(vec![cell], InMemorySink::default(), topology_config);
+            app.inject_signal(Signal::new("activator", 0.8));
+            (0..4)
+                .map(|step| {
+                    app.step(step, 0.0);
+                    app.cells[0].state.stress_level
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let drained = stress_trajectory(0.0);
+        let lingering = stress_trajectory(0.5);
+        assert!((drained[0] - lingering[0]).abs() < 1e-6);
+        // Without persistence stress only decays; with it, the echo tops it up.
+        assert!(lingering[1] > drained[1]);
+        assert!(lingering[2] > drained[2]);
+
+        // Each echo is weaker than the last until it drops below epsilon.
+        let mut bus = crate::signaling::SignalBus::default();
+        bus.publish(Signal::new("activator", 0.8));
+        let echoes: Vec<f32> = std::iter::from_fn(|| bus.decay(0.5).first().map(|s| s.value))
+            .take(20)
+            .collect();
+        assert!((echoes[1] - 0.4).abs() < 1e-6);
+        assert!(echoes.windows(2).all(|pair| pair[1] < pair[0]));
+        assert!(echoes.len() < 20);
+    }
+
+    #[test]
+    fn muted_topics_do_not_persist_on_the_bus() {
+        let topology_config = TopologyConfig {
+            signal_persistence: 0.5,
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(
+            vec![SecurityCell::new("listener")],
+            InMemorySink::default(),
+            topology_config,
+        );
+        app.inject_signal(Signal::new("inhibitor", 0.8));
+        app.mute_topic("inhibitor");
+        app.step(0, 0.0);
+        app.unmute_topic("inhibitor");
+
+        let carried = app.signal_bus.drain();
+        assert!(
+            carried.iter().all(|signal| signal.topic != "inhibitor"),
+            "{carried:?}"
+        );
+    }
+
+    #[test]
+    fn relayed_consensus_keeps_its_origin_across_three_hops() {
+        let ids = ["a", "b", "c", "d"];
+        let cells = ids
+            .iter()
+            .map(|id| {
+                let mut cell = SecurityCell::new(*id);
+                cell.genome.reproduction_threshold = 10.0;
+                cell
+            })
+            .collect();
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            explicit_links: Some(
+                ids.windows(2)
+                    .map(|pair| vec![pair[0].to_string(), pair[1].to_string()])
+                    .collect(),
+            ),
+            consensus_relay_hops: 3,
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+
+        let mut accusation = Signal::new("consensus:activator", 0.9).with_source("a");
+        accusation.attestation = app.cells[0]
+            .tpm
+            .attest(0, &attestation_payload(&accusation));
+        app.inject_signal(accusation);
+        for step in 0..6 {
+            app.step(step, 0.0);
+        }
+
+        let relays: Vec<(String, String, usize)> = app
+            .telemetry()
+            .events()
+            .iter()
+            .filter_map(|snapshot| match &snapshot.event {
+                TelemetryEvent::SignalRelayed {
+                    cell_id,
+                    origin,
+                    hops,
+                    ..
+                } => Some((cell_id.clone(), origin.clone(), *hops)),
+                _ => None,
+            })
+            .collect();
+        // Each hop re-attests as the immediate sender yet still names `a`,
+        // and nothing loops back to a cell already on the path.
+        assert_eq!(
+            relays,
+            vec![
+                ("b".to_string(), "a".to_string(), 1),
+                ("c".to_string(), "a".to_string(), 2),
+                ("d".to_string(), "a".to_string(), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn signal_loss_rate_drops_every_or_no_delivery() {
+        let run = |signals: usize, signal_loss_rate: f32| {
+            let cells = (0..4)
+                .map(|idx| {
+                    let mut cell = SecurityCell::new(format!("cell-{idx}"));
+                    cell.genome.reproduction_threshold = 10.0;
+                    cell
+                })
+                .collect();
+            let topology_config = TopologyConfig {
+                signal_loss_rate,
+                ..TopologyConfig::default()
+            };
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            for _ in 0..signals {
+                app.inject_signal(Signal::new("activator", 0.3));
+            }
+            app.step(0, 0.0);
+            let lost: usize = app
+                .telemetry()
+                .events()
+                .iter()
+                .filter_map(|snapshot| match snapshot.event {
+                    TelemetryEvent::SignalsLost { count, .. } => Some(count),
+                    _ => None,
+                })
+                .sum();
+            let energy: Vec<f32> = app.cells().iter().map(|c| c.state.energy).collect();
+            (lost, energy)
+        };
+
+        let (_, quiet_energy) = run(0, 0.0);
+        let (lost, energy) = run(3, 1.0);
+        assert_eq!(lost, 4 * 3);
+        assert_eq!(energy, quiet_energy, "no activator should have arrived");
+
+        let (lost, energy) = run(3, 0.0);
+        assert_eq!(lost, 0);
+        assert!(
+            energy.iter().zip(&quiet_energy).all(|(e, quiet)| e < quiet),
+            "every cell should feel the activator: {energy:?} vs {quiet_energy:?}"
+        );
+    }
+
+    #[test]
+    fn apps_with_the_same_cell_ids_keep_separate_key_registries() {
+        let build = || {
+            MorphogeneticApp::new(
+                vec![SecurityCell::new("shared-id")],
+                InMemorySink::default(),
+                TopologyConfig::default(),
+            )
+        };
+        let first = build();
+        let second = build();
+        let attestation = first.cells()[0]
+            .tpm
+            .attest(0, "heartbeat:0.0:none")
+            .expect("cell attests");
+        assert!(first.pki().verify(&attestation, 0, "heartbeat:0.0:none"));
+        assert!(!second.pki().verify(&attestation, 0, "heartbeat:0.0:none"));
+
+        let shared = Pki::new();
+        let first = first.with_pki(shared.clone());
+        assert!(shared.verify(&attestation, 0, "heartbeat:0.0:none"));
+        assert!(first.pki().contains("shared-id"));
+    }
+
+    #[test]
+    fn default_cell_snapshots_omit_secrets_and_rotate_keys_on_restore() {
+        let app = MorphogeneticApp::new(
+            vec![SecurityCell::new("keeper")],
+            InMemorySink::default(),
+            TopologyConfig::default(),
+        );
+        let payload = "heartbeat:0.0:none";
+        let attestation = app.cells()[0].tpm.attest(0, payload).expect("cell attests");
+
+        let snapshots = app.snapshot_cells();
+        let json = serde_json::to_string(&snapshots).expect("snapshot serializes");
+        assert!(!json.contains("secret_bytes"), "{json}");
+        assert!(!snapshots[0].tpm.has_keys());
+
+        let restored_pki = Pki::new();
+        let restored: Vec<SecurityCell> = serde_json::from_str::<Vec<CellSnapshot>>(&json)
+            .expect("snapshot deserializes")
+            .into_iter()
+            .map(|snapshot| snapshot.restore(&restored_pki))
+            .collect();
+        assert_eq!(restored[0].id, "keeper");
+        assert!(!restored_pki.verify(&attestation, 0, payload));
+        let fresh = restored[0].tpm.attest(0, payload).expect("new key attests");
+        assert!(restored_pki.verify(&fresh, 0, payload));
+
+        let with_keys = serde_json::to_string(&app.snapshot_cells_with_keys()).expect("serialize");
+        assert!(with_keys.contains("secret_bytes"));
+        let kept_pki = Pki::new();
+        let _kept: Vec<SecurityCell> = serde_json::from_str::<Vec<CellSnapshot>>(&with_keys)
+            .expect("snapshot deserializes")
+            .into_iter()
+            .map(|snapshot| snapshot.restore(&kept_pki))
+            .collect();
+        assert!(kept_pki.verify(&attestation, 0, payload));
+    }
+
+    #[test]
+    fn cooperative_emission_recruits_neighbor_into_encryption() {
+        let mut emitter = SecurityCell::new("emitter");
+        emitter.genome.cooperative_emission_threshold = 0.8;
+        let neighbor = SecurityCell::new("neighbor");
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            explicit_links: Some(vec![vec!["emitter".to_string(), "neighbor".to_string()]]),
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(
+            vec![emitter, neighbor],
+            InMemorySink::default(),
+            topology_config,
+        );
+        for step in 0..3 {
+            app.step(step, 0.0);
+        }
+
+        let events = app.telemetry().events();
+        assert!(events.iter().any(|snapshot| matches!(
+            &snapshot.event,
+            TelemetryEvent::SignalEmitted { cell_id, topic, .. }
+                if cell_id == "emitter" && topic == "cooperative"
+        )));
+        let neighbor = app
+            .cells()
+            .iter()
+            .find(|cell| cell.id == "neighbor")
+            .expect("neighbor alive");
+        assert_eq!(neighbor.state.lineage, CellLineage::Encryption);
+    }
+
+    #[test]
+    fn step_summary_lineage_counts_follow_differentiation() {
+        let cells = ["calm", "stressed-a", "stressed-b"]
+            .into_iter()
+            .map(|id| {
+                let mut cell = SecurityCell::new(id);
+                cell.genome.reproduction_threshold = f32::MAX;
+                cell
+            })
+            .collect::<Vec<_>>();
+        let mut app =
+            MorphogeneticApp::new(cells, InMemorySink::default(), TopologyConfig::default());
+        app.cells[1].genome.stress_differentiation_threshold = 0.0;
+        app.cells[2].genome.stress_differentiation_threshold = 0.0;
+        app.step(0, 0.0);
+
+        let counts = app
+            .telemetry()
+            .events()
+            .iter()
+            .rev()
+            .find_map(|snapshot| match &snapshot.event {
+                TelemetryEvent::StepSummary { lineage_counts, .. } => lineage_counts.clone(),
+                _ => None,
+            })
+            .expect("step summary carries lineage counts");
+        assert_eq!(counts.get(&CellLineage::Stem), Some(&1));
+        assert_eq!(counts.get(&CellLineage::IntrusionDetection), Some(&2));
+        assert_eq!(counts.values().sum::<usize>(), app.cells().len());
+    }
+
+    #[test]
+    fn blocked_lineage_transition_leaves_lineage_unchanged() {
+        let run = |lineage_rules: LineageRules| {
+            let mut cell = SecurityCell::new("stem");
+            cell.genome.reproduction_threshold = 10.0;
+            cell.genome.stress_differentiation_threshold = 0.0;
+            let topology_config = TopologyConfig {
+                lineage_rules,
+                ..TopologyConfig::default()
+            };
+            let mut app =
+                MorphogeneticApp::new(vec![cell], InMemorySink::default(), topology_config);
+            app.step(0, 0.0);
+            app
+        };
+
+        let blocked = run(LineageRules {
+            allowed: Default::default(),
+        });
+        assert_eq!(blocked.cells[0].state.lineage, CellLineage::Stem);
+        let events = blocked.telemetry().events();
+        assert!(events.iter().any(|snapshot| matches!(
+            &snapshot.event,
+            TelemetryEvent::DifferentiationBlocked { from, to, .. }
+                if from == "Stem" && to == "IntrusionDetection"
+        )));
+        assert!(
+            !events
+                .iter()
+                .any(|snapshot| matches!(snapshot.event, TelemetryEvent::LineageShift { .. }))
+        );
+
+        let permitted = run(LineageRules::default());
+        assert_eq!(
+            permitted.cells[0].state.lineage,
+            CellLineage::IntrusionDetection
+        );
+    }
+
+    #[test]
+    fn trust_snapshot_reflects_trust_changed_by_signal_exchange() {
+        let cells = vec![SecurityCell::new("A"), SecurityCell::new("B")];
+        let topology_config = TopologyConfig {
+            trust_snapshot_interval: 2,
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+
+        // An unattested consensus signal from B costs B trust in A's eyes.
+        app.inject_signal(Signal::new("consensus:activator", 0.1).with_source("B"));
+        app.step(0, 0.0);
+        app.step(1, 0.0);
+
+        let snapshots: Vec<(u32, &String, &BTreeMap<String, f32>)> = {
+            let mut step = 0;
+            let mut found = Vec::new();
+            for snapshot in app.telemetry().events() {
+                match &snapshot.event {
+                    TelemetryEvent::TrustSnapshot { cell_id, trust } => {
+                        found.push((step, cell_id, trust));
+                    }
+                    TelemetryEvent::StepSummary { .. } => step += 1,
+                    _ => {}
+                }
+            }
+            found
+        };
+
+        // Only step 0 is on the snapshot interval, and only A holds trust entries.
+        assert_eq!(snapshots.len(), 1);
+        let (step, cell_id, trust) = snapshots[0];
+        assert_eq!(step, 0);
+        assert_eq!(cell_id, "A");
+        let expected = 0.5 - SecurityCell::new("probe").genome.trust_penalty;
+        assert!((trust["B"] - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn exported_genomes_reseed_a_new_population() {
+        let mut alpha = SecurityCell::new("alpha");
+        alpha.genome.reproduction_threshold = 0.42;
+        alpha.genome.density_sensitivity = 0.7;
+        let mut beta = SecurityCell::new("beta");
+        beta.genome.trust_penalty = 0.33;
+        let app = MorphogeneticApp::new(
+            vec![beta, alpha],
+            InMemorySink::default(),
+            TopologyConfig::default(),
+        );
+
+        let exported = app.export_genomes();
+        assert_eq!(exported.len(), 2);
+        let serialized = serde_json::to_string(&exported).expect("genomes serialize");
+        let restored: HashMap<String, CellGenome> =
+            serde_json::from_str(&serialized).expect("genomes deserialize");
+
+        let reseeded = MorphogeneticApp::seed_from_genomes(
+            restored,
+            InMemorySink::default(),
+            TopologyConfig::default(),
+        );
+        let ids: Vec<&str> = reseeded.cells().iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["alpha", "beta"]);
+        let alpha = &reseeded.cells()[0].genome;
+        assert!((alpha.reproduction_threshold - 0.42).abs() < f32::EPSILON);
+        assert!((alpha.density_sensitivity - 0.7).abs() < f32::EPSILON);
+        assert!((reseeded.cells()[1].genome.trust_penalty - 0.33).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn differentiation_cooldown_prevents_lineage_flapping() {
+        let run = |cooldown: u32| {
+            let mut cell = SecurityCell::new("flapper");
+            cell.genome.differentiation_cooldown = cooldown;
+            let mut app = MorphogeneticApp::new(
+                vec![cell],
+                InMemorySink::default(),
+                TopologyConfig::default(),
+            );
+
+            let mut lineages = Vec::new();
+            for step in 0..4 {
+                if step % 2 == 0 {
+                    // Stress spike pushes towards IntrusionDetection.
+                    app.cells[0].state.stress_level = 0.9;
+                    app.step(step, 0.6);
+                } else {
+                    // Calm plus inhibitor pushes towards Healer.
+                    app.cells[0].state.stress_level = 0.0;
+                    app.inject_signal(Signal::new("inhibitor", 0.7));
+                    app.step(step, 0.0);
+                }
+                lineages.push(app.cells[0].state.lineage.clone());
+            }
+            let throttled = app
+                .telemetry()
+                .events()
+                .iter()
+                .filter(|s| matches!(s.event, TelemetryEvent::DifferentiationThrottled { .. }))
+                .count();
+            (lineages, throttled)
+        };
+
+        let (flapping, throttled) = run(0);
+        assert_eq!(
+            flapping,
+            vec![
+                CellLineage::IntrusionDetection,
+                CellLineage::Healer,
+                CellLineage::IntrusionDetection,
+                CellLineage::Healer,
+            ]
+        );
+        assert_eq!(throttled, 0);
+
+        let (stable, throttled) = run(5);
+        assert!(
+            stable
+                .iter()
+                .all(|lineage| *lineage == CellLineage::IntrusionDetection)
+        );
+        assert!(throttled >= 1);
+    }
+
+    #[test]
+    fn topology_dot_lists_nodes_and_each_link_once() {
+        let mut firewall = SecurityCell::new("B");
+        firewall.state.lineage = CellLineage::Firewall;
+        let cells = vec![SecurityCell::new("A"), firewall, SecurityCell::new("C")];
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            explicit_links: Some(vec![
+                vec!["A".to_string(), "B".to_string()],
+                vec!["C".to_string(), "B".to_string()],
+            ]),
+            ..TopologyConfig::default()
+        };
+        let app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+
+        let dot = app.export_topology_dot();
+        assert!(dot.starts_with("graph topology {"));
+        assert!(dot.contains("  \"A\" [label=\"A\\nStem\"];"));
+        assert!(dot.contains("  \"B\" [label=\"B\\nFirewall\"];"));
+        assert_eq!(dot.matches("\"A\" -- \"B\";").count(), 1);
+        assert_eq!(dot.matches("\"B\" -- \"C\";").count(), 1);
+        assert_eq!(dot.matches(" -- ").count(), 2);
+
+        let graphml = app.export_topology_graphml();
+        assert_eq!(graphml.matches("<edge ").count(), 2);
+        assert!(graphml.contains("<node id=\"B\"><data key=\"lineage\">Firewall</data></node>"));
+    }
+
+    #[test]
+    fn reconstructed_topology_matches_live_neighbors_after_death() {
+        let cells = ["A", "B", "C", "D"]
+            .into_iter()
+            .map(SecurityCell::new)
+            .collect::<Vec<_>>();
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+
+        app.handle_action(0, CellAction::Connect("B".to_string()));
+        app.handle_action(1, CellAction::Connect("C".to_string()));
+        app.handle_action(2, CellAction::Connect("D".to_string()));
+        app.handle_action(0, CellAction::Connect("C".to_string()));
+        app.handle_action(3, CellAction::Connect("A".to_string()));
+        app.handle_action(0, CellAction::Disconnect("B".to_string()));
+        // C holds three links; its death logs both halves of each.
+        app.handle_action(2, CellAction::Die);
+        app.remove_dead_cells();
+
+        let normalize = |map: &HashMap<String, Vec<String>>| {
+            let mut pairs: Vec<(String, Vec<String>)> = map
+                .iter()
+                .filter(|(_, links)| !links.is_empty())
+                .map(|(id, links)| {
+                    let mut links = links.clone();
+                    links.sort();
+                    (id.clone(), links)
+                })
+                .collect();
+            pairs.sort();
+            pairs
+        };
+
+        let rebuilt = crate::telemetry::reconstruct_topology(app.telemetry().events());
+        assert_eq!(normalize(&rebuilt), normalize(&app.neighbors));
+        assert_eq!(
+            normalize(&rebuilt),
+            vec![
+                ("A".to_string(), vec!["D".to_string()]),
+                ("D".to_string(), vec!["A".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn muting_inhibitor_removes_its_suppression_mid_run() {
+        let run = |mute_from: Option<u32>| {
+            let mut cell = SecurityCell::new("solo");
+            cell.genome.reproduction_threshold = f32::MAX;
+            let mut app = MorphogeneticApp::new(
+                vec![cell],
+                InMemorySink::default(),
+                TopologyConfig::default(),
+            );
+            for step in 0..6 {
+                if mute_from == Some(step) {
+                    app.mute_topic("inhibitor");
+                    app.mute_topic("inhibitor");
+                }
+                app.inject_signal(Signal::new("inhibitor", 2.0));
+                app.step(step, 0.6);
+            }
+            let stress = app.cells()[0].state.stress_level;
+            (stress, app)
+        };
+
+        let (suppressed, _) = run(None);
+        let (muted, mut app) = run(Some(3));
+        assert!(
+            muted > suppressed,
+            "muted stress {muted} should exceed suppressed stress {suppressed}"
+        );
+        let mute_events = app
+            .telemetry()
+            .events()
+            .iter()
+            .filter(|snapshot| matches!(snapshot.event, TelemetryEvent::TopicMuted { .. }))
+            .count();
+        assert_eq!(mute_events, 1);
+
+        app.unmute_topic("inhibitor");
+        assert!(matches!(
+            app.telemetry().events().last().map(|snapshot| &snapshot.event),
+            Some(TelemetryEvent::TopicUnmuted { topic }) if topic == "inhibitor"
+        ));
+    }
+
+    #[test]
+    fn connections_stop_once_node_reaches_max_degree() {
+        let cells = ["hub", "a", "b", "c", "d"]
+            .into_iter()
+            .map(SecurityCell::new)
+            .collect::<Vec<_>>();
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            max_degree: Some(2),
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+
+        for target in ["a", "b", "c"] {
+            app.handle_action(0, CellAction::Connect(target.to_string()));
+        }
+        // The target side is capped too: "d" cannot link into the full hub.
+        app.handle_action(4, CellAction::Connect("hub".to_string()));
+
+        assert_eq!(app.degree_of("hub"), 2);
+        assert_eq!(app.degree_of("c"), 0);
+        assert_eq!(app.degree_of("d"), 0);
+        let rejected: Vec<(String, String)> = app
+            .telemetry()
+            .events()
+            .iter()
+            .filter_map(|snapshot| match &snapshot.event {
+                TelemetryEvent::LinkRejected { source, target } => {
+                    Some((source.clone(), target.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            rejected,
+            vec![
+                ("hub".to_string(), "c".to_string()),
+                ("d".to_string(), "hub".to_string()),
+            ]
+        );
+
+        // A saturated parent hands its child to a neighbor with spare degree.
+        app.handle_action(0, CellAction::Replicate("hub-child".to_string()));
+        assert_eq!(app.degree_of("hub"), 2);
+        assert_eq!(app.neighbors["hub-child"], vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn carrying_capacity_estimate_matches_simulated_equilibrium() {
+        // Below the replication gate the population holds. Above it, the
+        // first round of births fills the cap; later rounds are skipped
+        // because children mutate at random and may die off.
+        for (threat, seeds, steps) in [(0.5, 4, 30), (1.0, 60, 1)] {
+            let cells = (0..seeds)
+                .map(|idx| SecurityCell::new(format!("seed-{idx}")))
+                .collect();
+            let mut app =
+                MorphogeneticApp::new(cells, InMemorySink::default(), TopologyConfig::default());
+            let estimate = app.estimated_carrying_capacity(threat);
+            for step in 0..steps {
+                app.step(step, threat);
+            }
+            assert_eq!(app.cells().len(), estimate, "threat {threat}");
+        }
+
+        // Threat drain outpacing recharge wipes the population out.
+        let doomed = MorphogeneticApp::new(
+            vec![SecurityCell::new("doomed")],
+            InMemorySink::default(),
+            TopologyConfig::default(),
+        );
+        assert_eq!(doomed.estimated_carrying_capacity(2.0), 0);
+    }
+
+    #[test]
+    fn metrics_snapshot_reflects_live_population() {
+        let cells = ["A", "B", "C"]
+            .into_iter()
+            .map(|id| {
+                let mut cell = SecurityCell::new(id);
+                cell.genome.reproduction_threshold = f32::MAX;
+                cell
+            })
+            .collect::<Vec<_>>();
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            explicit_links: Some(vec![vec!["A".to_string(), "B".to_string()]]),
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+        app.cells[2].state.lineage = CellLineage::Firewall;
+        for step in 0..3 {
+            app.step(step, 0.1);
+        }
+
+        let metrics = app.metrics();
+        assert_eq!(metrics.cell_count, app.cells().len());
+        assert_eq!(metrics.cell_count, 3);
+        let mut expected = BTreeMap::new();
+        for cell in app.cells() {
+            *expected.entry(cell.state.lineage.clone()).or_insert(0) += 1;
+        }
+        assert_eq!(metrics.lineage_counts, expected);
+        assert_eq!(metrics.lineage_counts.get(&CellLineage::Firewall), Some(&1));
+        let mean_energy = app
+            .cells()
+            .iter()
+            .map(|cell| cell.state.energy)
+            .sum::<f32>()
+            / 3.0;
+        assert!((metrics.avg_energy - mean_energy).abs() < 1e-6);
+        assert!((metrics.topology.avg_degree - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(metrics.topology.isolation_count, 1);
+    }
+
+    #[test]
+    fn healer_inhibitor_lowers_neighbor_threat_next_step() {
+        let neighbor_stress_after_two_steps = |healer_output: f32| {
+            let mut healer = SecurityCell::new("H");
+            healer.state.lineage = CellLineage::Healer;
+            healer.genome.healer_inhibitor_output = healer_output;
+            let mut cells = vec![healer, SecurityCell::new("N")];
+            for cell in &mut cells {
+                cell.genome.reproduction_threshold = f32::MAX;
+                cell.genome.stress_differentiation_threshold = f32::MAX;
+                cell.genome.healer_inhibitor_threshold = f32::MAX;
+            }
+            // Without the response the healer echoes activator like any
+            // other cell; the neighbor stays silent throughout.
+            cells[0].genome.signal_emission_threshold = 0.3;
+            cells[1].genome.signal_emission_threshold = f32::MAX;
+            let topology_config = TopologyConfig {
+                strategy: TopologyStrategy::Graph,
+                explicit_links: Some(vec![vec!["H".to_string(), "N".to_string()]]),
+                ..TopologyConfig::default()
+            };
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            for step in 0..2 {
+                app.inject_signal(Signal::new("activator", 0.8));
+                app.step(step, 0.0);
+            }
+            let stress = app.cells()[1].state.stress_level;
+            let healer_emitted_inhibitor = app.telemetry().events().iter().any(|snapshot| {
+                matches!(
+                    &snapshot.event,
+                    TelemetryEvent::SignalEmitted { cell_id, topic, .. }
+                        if cell_id == "H" && topic == "inhibitor"
+                )
+            });
+            (stress, healer_emitted_inhibitor)
+        };
+
+        let (calm_stress, calmed) = neighbor_stress_after_two_steps(0.5);
+        let (baseline_stress, baseline_emitted) = neighbor_stress_after_two_steps(0.0);
+        assert!(calmed);
+        assert!(!baseline_emitted);
+        assert!(
+            calm_stress < baseline_stress - 0.05,
+            "healer inhibitor barely registered: {calm_stress} vs {baseline_stress}"
+        );
+    }
+
+    #[test]
+    fn incoming_signal_cap_keeps_only_the_strongest_signals() {
+        let stress_after_flood = |cap: Option<usize>| {
+            let topology_config = TopologyConfig {
+                max_incoming_signals: cap,
+                ..TopologyConfig::default()
+            };
+            let mut app = MorphogeneticApp::new(
+                vec![SecurityCell::new("A")],
+                InMemorySink::default(),
+                topology_config,
+            );
+            // Fifteen weak and five strong activators: 20 delivered signals.
+            for index in 0..20 {
+                app.inject_signal(Signal::new(
+                    "activator",
+                    if index % 4 == 0 { 0.02 } else { 0.01 },
+                ));
+            }
+            app.step(0, 0.0);
+            let truncations: Vec<(usize, usize)> = app
+                .telemetry()
+                .events()
+                .iter()
+                .filter_map(|snapshot| match &snapshot.event {
+                    TelemetryEvent::SignalsTruncated {
+                        delivered, kept, ..
+                    } => Some((*delivered, *kept)),
+                    _ => None,
+                })
+                .collect();
+            (app.cells()[0].state.stress_level, truncations)
+        };
+
+        let (uncapped_stress, uncapped_truncations) = stress_after_flood(None);
+        let (capped_stress, capped_truncations) = stress_after_flood(Some(5));
+        assert!(uncapped_truncations.is_empty());
+        assert_eq!(capped_truncations, vec![(20, 5)]);
+        // Only the five 0.02 signals count: 0.10 of the uncapped 0.25.
+        assert!((capped_stress / uncapped_stress - 0.4).abs() < 1e-4);
+    }
+
+    #[test]
+    fn replication_budget_defers_excess_replications() {
+        let run = |cap: Option<usize>| {
+            let topology_config = TopologyConfig {
+                max_replications_per_step: cap,
+                ..TopologyConfig::default()
+            };
+            let cells = (0..8)
+                .map(|idx| {
+                    let mut cell = SecurityCell::new(format!("seed-{idx}"));
+                    cell.genome.reproduction_threshold = 0.0;
+                    cell
+                })
+                .collect();
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            app.step(0, 0.5);
+            let deferred: Vec<usize> = app
+                .telemetry()
+                .events()
+                .iter()
+                .filter_map(|snapshot| match &snapshot.event {
+                    TelemetryEvent::ReplicationDeferred { deferred, .. } => Some(*deferred),
+                    _ => None,
+                })
+                .collect();
+            (app.cells().len(), deferred)
+        };
+
+        let (unbounded, unbounded_deferred) = run(None);
+        assert_eq!(unbounded, 16, "every eager cell replicates without a cap");
+        assert!(unbounded_deferred.is_empty());
+
+        let (capped, capped_deferred) = run(Some(3));
+        assert_eq!(capped, 8 + 3);
+        assert_eq!(capped_deferred, vec![5]);
+    }
+
+    #[test]
+    fn replication_records_genome_mutations_when_enabled() {
+        let run = |record_genome_mutations: bool| {
+            let topology_config = TopologyConfig {
+                record_genome_mutations,
+                ..TopologyConfig::default()
+            };
+            let mut parent = SecurityCell::new("parent");
+            parent.genome.reproduction_threshold = 0.0;
+            // Out of range, so every child's clamp pass is guaranteed to change it.
+            parent.genome.density_sensitivity = 50.0;
+            let mut app =
+                MorphogeneticApp::new(vec![parent], InMemorySink::default(), topology_config);
+            app.step(0, 0.5);
+            let mutations: Vec<(String, Vec<String>)> = app
+                .telemetry()
+                .events()
+                .iter()
+                .filter_map(|snapshot| match &snapshot.event {
+                    TelemetryEvent::GenomeMutated {
+                        cell_id,
+                        changed_fields,
+                    } => Some((cell_id.clone(), changed_fields.clone())),
+                    _ => None,
+                })
+                .collect();
+            (app, mutations)
+        };
+
+        let (app, mutations) = run(true);
+        assert_eq!(app.cells().len(), 2);
+        assert_eq!(mutations.len(), 1);
+        let (cell_id, changed_fields) = &mutations[0];
+        assert_eq!(cell_id, "parent::child");
+        assert!(changed_fields.contains(&"density_sensitivity".to_string()));
+        assert_eq!(app.cells()[1].genome.density_sensitivity, 10.0);
+
+        let (_, mutations) = run(false);
+        assert!(mutations.is_empty());
+    }
+
+    #[test]
+    fn traced_run_records_each_cells_actions() {
+        let traced_app = |cap: usize| {
+            let topology_config = TopologyConfig {
+                trace_actions: true,
+                action_trace_cap: cap,
+                ..TopologyConfig::default()
+            };
+            let mut calm = SecurityCell::new("calm");
+            calm.genome.reproduction_threshold = f32::MAX;
+            let mut drained = SecurityCell::new("drained");
+            drained.state.energy = 0.0;
+            drained.genome.energy_recharge = 0.0;
+            let mut app = MorphogeneticApp::new(
+                vec![calm, drained],
+                InMemorySink::default(),
+                topology_config,
+            );
+            app.step(0, 0.0);
+            app.step(1, 0.0);
+            app
+        };
+
+        let app = traced_app(100);
+        let trace: Vec<_> = app
+            .action_trace()
+            .expect("tracing enabled")
+            .iter()
+            .collect();
+        assert_eq!(trace.len(), 3);
+        assert!(matches!(trace[0], (0, id, CellAction::Idle) if id == "calm"));
+        assert!(matches!(trace[1], (0, id, CellAction::Die) if id == "drained"));
+        assert!(matches!(trace[2], (1, id, CellAction::Idle) if id == "calm"));
+
+        let capped = traced_app(2);
+        let steps: Vec<u32> = capped
+            .action_trace()
+            .expect("tracing enabled")
+            .iter()
+            .map(|(step, _, _)| *step)
+            .collect();
+        assert_eq!(steps, vec![0, 1]);
+
+        let untraced = MorphogeneticApp::new(
+            vec![SecurityCell::new("A")],
+            InMemorySink::default(),
+            TopologyConfig::default(),
+        );
+        assert!(untraced.action_trace().is_none());
+    }
+
+    #[test]
+    fn record_idle_reports_idle_cells_in_step_summary() {
+        let idle_counts = |record_idle: bool| {
+            let topology_config = TopologyConfig {
+                record_idle,
+                ..TopologyConfig::default()
+            };
+            // A calm population with replication disabled has nothing to do.
+            let cells = (0..3)
+                .map(|idx| {
+                    let mut cell = SecurityCell::new(format!("seed-{idx}"));
+                    cell.genome.reproduction_threshold = f32::MAX;
+                    cell
+                })
+                .collect();
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            app.step(0, 0.0);
+            app.telemetry()
+                .events()
+                .iter()
+                .find_map(|snapshot| match &snapshot.event {
+                    TelemetryEvent::StepSummary { idle_cells, .. } => Some(*idle_cells),
+                    _ => None,
+                })
+                .expect("step summary recorded")
+        };
+
+        assert_eq!(idle_counts(true), Some(3));
+        assert_eq!(idle_counts(false), None);
+    }
+
+    #[test]
+    fn population_stats_interval_overrides_the_size_heuristic() {
+        let summaries_with_stats = |interval: Option<u32>| {
+            let topology_config = TopologyConfig {
+                population_stats_interval: interval,
+                ..TopologyConfig::default()
+            };
+            let cells = (0..3)
+                .map(|idx| SecurityCell::new(format!("seed-{idx}")))
+                .collect();
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            for step in 0..6 {
+                app.step(step, 0.0);
+            }
+            app.telemetry()
+                .events()
+                .iter()
+                .filter_map(|snapshot| match &snapshot.event {
+                    TelemetryEvent::StepSummary {
+                        step,
+                        population_stats,
+                        ..
+                    } => Some((*step, population_stats.is_some())),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let every_step = summaries_with_stats(Some(1));
+        assert_eq!(every_step.len(), 6);
+        assert!(every_step.iter().all(|(_, sampled)| *sampled));
+
+        // A sparse interval applies even though the population is tiny.
+        let sampled_steps: Vec<u32> = summaries_with_stats(Some(4))
+            .into_iter()
+            .filter(|(_, sampled)| *sampled)
+            .map(|(step, _)| step)
+            .collect();
+        assert_eq!(sampled_steps, vec![0, 4]);
+    }
+
+    #[test]
+    fn fault_event_kills_the_configured_fraction_reproducibly() {
+        let config = crate::config::load_from_reader(
+            "fault_profile:\n  - step: 3\n    kill_fraction: 0.25\nfault_seed: 11\n".as_bytes(),
+        )
+        .expect("config");
+        let survivors_after_run = || {
+            let cells = (0..20)
+                .map(|idx| {
+                    let mut cell = SecurityCell::new(format!("seed-{idx}"));
+                    cell.genome.reproduction_threshold = f32::MAX;
+                    cell
+                })
+                .collect();
+            let mut app =
+                MorphogeneticApp::new(cells, InMemorySink::default(), config.topology.clone());
+            let mut rng = config.fault_rng();
+            let mut populations = Vec::new();
+            for step in 0..5 {
+                for fault in config.faults_at(step) {
+                    app.inject_fault(step, fault.kill_fraction, &mut rng);
+                }
+                app.step(step, 0.0);
+                populations.push(app.cells().len());
+            }
+            let recorded: Vec<(u32, usize)> = app
+                .telemetry()
+                .events()
+                .iter()
+                .filter_map(|snapshot| match &snapshot.event {
+                    TelemetryEvent::FaultInjected { step, killed } => Some((*step, *killed)),
+                    _ => None,
+                })
+                .collect();
+            let ids: Vec<String> = app.cells().iter().map(|cell| cell.id.clone()).collect();
+            (populations, recorded, ids)
+        };
+
+        let (populations, recorded, survivors) = survivors_after_run();
+        assert_eq!(populations, vec![20, 20, 20, 15, 15]);
+        assert_eq!(recorded, vec![(3, 5)]);
+
+        let (_, _, replayed) = survivors_after_run();
+        assert_eq!(survivors, replayed);
+    }
+
+    #[test]
+    fn quarantined_peer_is_reconnected_after_sustained_valid_attestations() {
+        let mut observer = SecurityCell::new("A");
+        observer.genome.peer_rehabilitation = true;
+        // Binary-exact values so accumulated trust lands exactly on the threshold.
+        observer.genome.min_trust_threshold = 0.25;
+        observer.genome.trust_reward = 0.125;
+        let cells = vec![observer, SecurityCell::new("B")];
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            explicit_links: Some(vec![vec!["A".to_string(), "B".to_string()]]),
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+        app.handle_action(0, CellAction::Disconnect("B".to_string()));
+        assert_eq!(app.cells()[0].state.blacklist, vec!["B".to_string()]);
+
+        let rehabilitated = |app: &MorphogeneticApp<InMemorySink>| {
+            app.telemetry().events().iter().any(|snapshot| {
+                matches!(
+                    &snapshot.event,
+                    TelemetryEvent::PeerRehabilitated { cell_id, peer_id }
+                        if cell_id == "A" && peer_id == "B"
+                )
+            })
+        };
+        let genome = app.cells()[0].genome.clone();
+        // Trust restarts at zero; reaching the threshold that quarantined B
+        // is enough to lift the quarantine.
+        let attestations_needed = (genome.min_trust_threshold / genome.trust_reward).ceil() as u32;
+
+        for step in 0..attestations_needed {
+            assert!(!rehabilitated(&app), "reconnected early at step {step}");
+            let attestation = app.cells()[1].tpm.attest(step as u64, "heartbeat:0.0:none");
+            app.inject_signal(Signal {
+                attestation,
+                ..Signal::new("heartbeat", 0.0).with_source("B")
+            });
+            app.step(step, 0.0);
+        }
+
+        assert!(rehabilitated(&app));
+        assert!(app.cells()[0].state.blacklist.is_empty());
+        assert!(app.neighbors["A"].contains(&"B".to_string()));
+        assert!(app.neighbors["B"].contains(&"A".to_string()));
+    }
+
+    #[test]
+    fn consensus_quarantine_is_reported_in_both_topologies() {
+        let quarantines = |strategy: TopologyStrategy, action: CellAction| {
+            let cells = vec![SecurityCell::new("A"), SecurityCell::new("B")];
+            let topology_config = TopologyConfig {
+                strategy,
+                explicit_links: Some(vec![vec!["A".to_string(), "B".to_string()]]),
+                ..TopologyConfig::default()
+            };
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            app.handle_action(0, action);
+            assert_eq!(app.cells()[0].state.blacklist, vec!["B".to_string()]);
+            app.telemetry()
+                .events()
+                .iter()
+                .filter(|snapshot| matches!(snapshot.event, TelemetryEvent::PeerQuarantined { .. }))
+                .count()
+        };
+        let quarantine = || CellAction::Quarantine("B".to_string());
+        let disconnect = || CellAction::Disconnect("B".to_string());
+
+        assert_eq!(quarantines(TopologyStrategy::Graph, quarantine()), 1);
+        assert_eq!(quarantines(TopologyStrategy::Graph, disconnect()), 0);
+        assert_eq!(quarantines(TopologyStrategy::Global, quarantine()), 1);
+        assert_eq!(quarantines(TopologyStrategy::Global, disconnect()), 1);
+    }
+
+    #[test]
+    fn only_opted_in_rehabilitation_lifts_a_quarantine() {
+        let run = |peer_rehabilitation: bool, action: CellAction| {
+            let mut observer = SecurityCell::new("A");
+            observer.genome.peer_rehabilitation = peer_rehabilitation;
+            let cells = vec![observer, SecurityCell::new("B")];
+            let topology_config = TopologyConfig {
+                strategy: TopologyStrategy::Graph,
+                explicit_links: Some(vec![vec!["A".to_string(), "B".to_string()]]),
+                ..TopologyConfig::default()
+            };
+            let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+            app.handle_action(0, CellAction::Disconnect("B".to_string()));
+            app.handle_action(0, action);
+            let rehabilitated =
+                app.telemetry().events().iter().any(|snapshot| {
+                    matches!(snapshot.event, TelemetryEvent::PeerRehabilitated { .. })
+                });
+            (app.cells()[0].state.blacklist.clone(), rehabilitated)
+        };
+        let quarantined = (vec!["B".to_string()], false);
+
+        // A plain connect never clears the blacklist, even for opted-in cells.
+        assert_eq!(run(true, CellAction::Connect("B".to_string())), quarantined);
+        assert_eq!(
+            run(false, CellAction::Rehabilitate("B".to_string())),
+            quarantined
+        );
+        assert_eq!(
+            run(true, CellAction::Rehabilitate("B".to_string())),
+            (Vec::new(), true)
+        );
+    }
+
+    #[test]
+    fn incremental_degree_stats_track_connects_disconnects_and_deaths() {
+        let cells = vec![
+            SecurityCell::new("A"),
+            SecurityCell::new("B"),
+            SecurityCell::new("C"),
+            SecurityCell::new("D"),
+        ];
+        let topology_config = TopologyConfig {
+            strategy: TopologyStrategy::Graph,
+            explicit_links: Some(vec![vec!["A".to_string(), "B".to_string()]]),
+            ..TopologyConfig::default()
+        };
+        let mut app = MorphogeneticApp::new(cells, InMemorySink::default(), topology_config);
+
+        let assert_matches_recompute = |app: &MorphogeneticApp<InMemorySink>| {
+            assert_eq!(
+                (app.total_degree, app.isolation_count),
+                app.recompute_degree_totals()
+            );
+        };
+        assert_eq!((app.total_degree, app.isolation_count), (2, 2));
+
+        app.handle_action(2, CellAction::Connect("D".to_string()));
+        app.handle_action(1, CellAction::Connect("C".to_string()));
+        assert_matches_recompute(&app);
+        assert_eq!((app.total_degree, app.isolation_count), (6, 0));
+
+        app.handle_action(0, CellAction::Disconnect("B".to_string()));
+        assert_matches_recompute(&app);
+        assert_eq!((app.total_degree, app.isolation_count), (4, 1));
+
+        // Links to ids that are not live cells only count on the live side.
+        app.handle_action(0, CellAction::Connect("ghost".to_string()));
+        assert_matches_recompute(&app);
+        assert_eq!((app.total_degree, app.isolation_count), (5, 0));
+
+        app.handle_action(2, CellAction::Die);
+        app.remove_dead_cells();
+        assert_matches_recompute(&app);
+        assert_eq!((app.total_degree, app.isolation_count), (1, 2));
+
+        let stats = app.calculate_topology_stats();
+        assert!((stats.avg_degree - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(stats.isolation_count, 2);
+    }
 }
@@ -2,18 +2,103 @@
 
 use crate::immune::Attestation;
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Signal {
     pub topic: String,
     pub value: f32,
     pub source: Option<String>,
     pub target: Option<String>,
     pub attestation: Option<Attestation>,
+    /// Cell that first raised a relayed signal; `None` until it is relayed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+    /// Cells the signal has passed through, oldest first, ending with the
+    /// current `source`. Empty for signals that were never relayed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<String>,
 }
 
+/// Internal tally a delivered topic feeds into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum SignalChannel {
+    Activator,
+    Inhibitor,
+    Cooperative,
+}
+
+impl SignalChannel {
+    /// Channel for one of the built-in topic names.
+    pub fn builtin(topic: &str) -> Option<Self> {
+        match topic {
+            "activator" => Some(SignalChannel::Activator),
+            "inhibitor" => Some(SignalChannel::Inhibitor),
+            "cooperative" => Some(SignalChannel::Cooperative),
+            _ => None,
+        }
+    }
+
+    /// Channel for `topic`, consulting the built-in names first and then
+    /// `recognized` custom names. Unrecognized topics map to `None`.
+    pub fn resolve(topic: &str, recognized: &HashMap<String, SignalChannel>) -> Option<Self> {
+        Self::builtin(topic).or_else(|| recognized.get(topic).copied())
+    }
+}
+
+impl Signal {
+    /// An unsourced, untargeted, unattested broadcast of `value` on `topic`.
+    pub fn new(topic: impl Into<String>, value: f32) -> Self {
+        Self {
+            topic: topic.into(),
+            value,
+            source: None,
+            target: None,
+            attestation: None,
+            origin: None,
+            path: Vec::new(),
+        }
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn with_target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn with_attestation(mut self, attestation: Attestation) -> Self {
+        self.attestation = Some(attestation);
+        self
+    }
+
+    /// The cell that originally raised this signal: `origin` for relayed
+    /// signals, otherwise the immediate `source`.
+    pub fn true_origin(&self) -> Option<&str> {
+        self.origin.as_deref().or(self.source.as_deref())
+    }
+
+    /// Whether `cell_id` raised or already relayed this signal.
+    pub fn has_visited(&self, cell_id: &str) -> bool {
+        self.origin.as_deref() == Some(cell_id) || self.path.iter().any(|hop| hop == cell_id)
+    }
+}
+
+/// Attenuated signals weaker than this are dropped by [`SignalBus::decay`].
+pub const DECAY_EPSILON: f32 = 1e-3;
+
+/// Highest `signal_persistence` the runtime honours, so echoes always fade
+/// out.
+pub const MAX_SIGNAL_PERSISTENCE: f32 = 0.99;
+
 #[allow(dead_code)]
 #[derive(Debug, Default)]
 pub struct SignalBus {
@@ -31,6 +116,34 @@ impl SignalBus {
         self.queue.drain(..).collect()
     }
 
+    /// Like [`Self::drain`], but keeps an attenuated copy of each signal
+    /// (value scaled by `factor`) queued for the next step. Copies that fall
+    /// below [`DECAY_EPSILON`] are dropped. Attested signals are delivered
+    /// only once, since their attestation binds the original value.
+    pub fn decay(&mut self, factor: f32) -> Vec<Signal> {
+        let delivered: Vec<Signal> = self.queue.drain(..).collect();
+        if factor > 0.0 {
+            self.queue.extend(
+                delivered
+                    .iter()
+                    .filter(|signal| signal.attestation.is_none())
+                    .filter(|signal| (signal.value * factor).abs() >= DECAY_EPSILON)
+                    .map(|signal| Signal {
+                        value: signal.value * factor,
+                        ..signal.clone()
+                    }),
+            );
+        }
+        delivered
+    }
+
+    /// Drop every queued signal on one of `topics`.
+    pub fn purge_topics(&mut self, topics: &HashSet<String>) {
+        if !topics.is_empty() {
+            self.queue.retain(|signal| !topics.contains(&signal.topic));
+        }
+    }
+
     #[allow(dead_code)]
     pub fn purge_from(&mut self, source_id: &str, _target_id: &str) {
         self.queue.retain(|s| {
@@ -47,3 +160,68 @@ impl SignalBus {
         });
     }
 }
+
+/// Drained signals grouped by topic once per step.
+///
+/// Cells consult the index instead of re-categorising the flat buffer. Only
+/// "actionable" signals can change a cell's state: the activator, inhibitor,
+/// and cooperative morphogens, consensus votes, and any attested signal (whose
+/// attestation moves sender trust). Everything else is inert to `tick`.
+#[derive(Debug, Clone, Default)]
+pub struct TopicSignals {
+    signals: Arc<[Signal]>,
+    by_topic: HashMap<String, Vec<usize>>,
+    actionable: Vec<usize>,
+}
+
+impl TopicSignals {
+    pub fn new(signals: Arc<[Signal]>) -> Self {
+        let mut by_topic: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut actionable = Vec::new();
+        for (idx, signal) in signals.iter().enumerate() {
+            by_topic.entry(signal.topic.clone()).or_default().push(idx);
+            if is_actionable(signal) {
+                actionable.push(idx);
+            }
+        }
+        Self {
+            signals,
+            by_topic,
+            actionable,
+        }
+    }
+
+    /// The indexed signal buffer, in delivery order.
+    pub fn signals(&self) -> &Arc<[Signal]> {
+        &self.signals
+    }
+
+    /// Signals published on `topic`, in delivery order.
+    pub fn topic<'a>(&'a self, topic: &str) -> impl Iterator<Item = &'a Signal> + 'a {
+        self.by_topic
+            .get(topic)
+            .into_iter()
+            .flatten()
+            .map(|idx| &self.signals[*idx])
+    }
+
+    /// Distinct topics present in the buffer.
+    pub fn topics(&self) -> impl Iterator<Item = &str> {
+        self.by_topic.keys().map(String::as_str)
+    }
+
+    /// Signals that can affect a cell's state, in delivery order.
+    pub fn actionable(&self) -> impl Iterator<Item = &Signal> {
+        self.actionable.iter().map(|idx| &self.signals[*idx])
+    }
+
+    pub fn actionable_len(&self) -> usize {
+        self.actionable.len()
+    }
+}
+
+pub(crate) fn is_actionable(signal: &Signal) -> bool {
+    SignalChannel::builtin(&signal.topic).is_some()
+        || signal.topic.starts_with("consensus:")
+        || (signal.source.is_some() && signal.attestation.is_some())
+}
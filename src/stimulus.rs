@@ -1,7 +1,9 @@
 //! Stimulus scheduling utilities for injecting signals during simulation.
 
+use crate::signaling::Signal;
+use crate::util::write_atomic;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::OpenOptions;
 use std::io::{self, BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
@@ -16,7 +18,10 @@ pub struct StimulusCommand {
     pub target: Option<String>,
     #[serde(default)]
     pub source: Option<String>,
-    #[serde(default = "default_duration")]
+    #[serde(
+        default = "default_duration",
+        deserialize_with = "deserialize_duration"
+    )]
     pub duration: u32,
 }
 
@@ -24,6 +29,26 @@ fn default_duration() -> u32 {
     1
 }
 
+/// An empty CSV cell (or JSON `null`) means the default duration.
+fn deserialize_duration<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<u32>::deserialize(deserializer)?.unwrap_or_else(default_duration))
+}
+
+impl StimulusCommand {
+    /// Bus signal carrying this command's topic, value, source, and target,
+    /// with the topic taken verbatim.
+    pub fn to_signal(&self) -> Signal {
+        Signal {
+            source: self.source.clone(),
+            target: self.target.clone(),
+            ..Signal::new(self.topic.clone(), self.value)
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub struct StimulusSchedule {
     pub commands: BTreeMap<u32, Vec<StimulusCommand>>,
@@ -55,20 +80,102 @@ impl StimulusSchedule {
         })
     }
 
+    /// Load a CSV schedule with columns `step,topic,value` and optional
+    /// `target`, `source`, and `duration`; empty cells take the defaults.
+    pub fn load_csv<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut reader = csv::Reader::from_path(path.as_ref())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let mut commands: BTreeMap<u32, Vec<StimulusCommand>> = BTreeMap::new();
+        for row in reader.deserialize() {
+            let command: StimulusCommand =
+                row.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            commands.entry(command.step).or_default().push(command);
+        }
+
+        Ok(Self {
+            commands,
+            source: Some(path.as_ref().to_path_buf()),
+        })
+    }
+
+    /// [`Self::load_csv`] for `.csv` paths, [`Self::load`] for anything else.
+    pub fn load_auto<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        if path.as_ref().extension().is_some_and(|ext| ext == "csv") {
+            Self::load_csv(path)
+        } else {
+            Self::load(path)
+        }
+    }
+
+    /// Like [`Self::load_auto`], but rejects files containing more than one
+    /// command for the same `(step, topic)` pair and target.
+    pub fn load_strict<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let schedule = Self::load_auto(path)?;
+        let duplicates = schedule.duplicate_entries();
+        if duplicates.is_empty() {
+            return Ok(schedule);
+        }
+        let listed = duplicates
+            .iter()
+            .map(|(step, topic)| format!("(step {step}, topic `{topic}`)"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("duplicate stimulus entries: {listed}"),
+        ))
+    }
+
+    /// `(step, topic)` pairs that appear in more than one command aimed at the
+    /// same target. A broadcast and a targeted command do not collide.
+    pub fn duplicate_entries(&self) -> Vec<(u32, String)> {
+        let mut duplicates = Vec::new();
+        for (step, commands) in &self.commands {
+            let mut seen: BTreeMap<(&str, Option<&str>), usize> = BTreeMap::new();
+            for command in commands {
+                *seen
+                    .entry((command.topic.as_str(), command.target.as_deref()))
+                    .or_insert(0) += 1;
+            }
+            duplicates.extend(
+                seen.into_iter()
+                    .filter(|(_, count)| *count > 1)
+                    .map(|((topic, _), _)| (*step, topic.to_string())),
+            );
+        }
+        duplicates.dedup();
+        duplicates
+    }
+
+    /// Collapse duplicate `(step, topic, target)` commands into one by summing
+    /// their values. The first occurrence supplies source and duration.
+    pub fn dedup_summed(&mut self) {
+        for commands in self.commands.values_mut() {
+            let mut merged: Vec<StimulusCommand> = Vec::with_capacity(commands.len());
+            for command in commands.drain(..) {
+                match merged
+                    .iter_mut()
+                    .find(|kept| kept.topic == command.topic && kept.target == command.target)
+                {
+                    Some(kept) => kept.value += command.value,
+                    None => merged.push(command),
+                }
+            }
+            *commands = merged;
+        }
+    }
+
     #[allow(dead_code)]
     pub fn save_to_path<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
-        let mut file = OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(path)?;
-        for commands_at_step in self.commands.values() {
-            for command in commands_at_step {
-                serde_json::to_writer(&mut file, command)?;
-                file.write_all(b"\n")?;
+        write_atomic(path, |file| {
+            for commands_at_step in self.commands.values() {
+                for command in commands_at_step {
+                    serde_json::to_writer(&mut *file, command)?;
+                    file.write_all(b"\n")?;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
     }
 
     #[allow(dead_code)]
@@ -81,9 +188,36 @@ impl StimulusSchedule {
         self.source.as_deref()
     }
 
+    /// Apply `mutation` with no known simulation horizon. See
+    /// [`Self::apply_mutation_within`].
     #[allow(dead_code)]
-    pub fn apply_mutation(&mut self, mutation: &crate::adversarial::Mutation) {
+    pub fn apply_mutation(&mut self, mutation: &crate::adversarial::Mutation) -> bool {
+        self.apply_mutation_within(mutation, None)
+    }
+
+    /// Apply `mutation`, clamping retimed events into `0..horizon` when the
+    /// simulation length is known. Returns `false`, leaving the schedule
+    /// untouched, when the mutation names an event index it does not have.
+    pub fn apply_mutation_within(
+        &mut self,
+        mutation: &crate::adversarial::Mutation,
+        horizon: Option<u32>,
+    ) -> bool {
         use crate::adversarial::Mutation;
+        let event_count: usize = self.commands.values().map(Vec::len).sum();
+        let in_range = match mutation {
+            Mutation::ChangeEventTiming { event_index, .. }
+            | Mutation::RemoveStimulus { event_index }
+            | Mutation::ShiftStimulusTime { event_index, .. } => *event_index < event_count,
+            Mutation::SwapStimulus {
+                event_index1,
+                event_index2,
+            } => (*event_index1).max(*event_index2) < event_count,
+            _ => true,
+        };
+        if !in_range {
+            return false;
+        }
         match mutation {
             Mutation::IncreaseStimulus { topic, factor } => {
                 for (_, commands_at_step) in self.commands.iter_mut() {
@@ -107,22 +241,21 @@ impl StimulusSchedule {
                 event_index,
                 new_step,
             } => {
-                let mut all_commands: Vec<StimulusCommand> = Vec::new();
-                for (_, commands_at_step) in self.commands.clone().into_iter() {
-                    // Use into_iter to consume and move
-                    all_commands.extend(commands_at_step);
-                }
-
-                if let Some(cmd) = all_commands.get_mut(*event_index) {
-                    cmd.step = *new_step;
+                let (step, position) = self
+                    .locate_event(*event_index)
+                    .expect("event index checked above");
+                let new_step = match horizon {
+                    Some(horizon) => (*new_step).min(horizon.saturating_sub(1)),
+                    None => *new_step,
+                };
+                let commands_at_step = self.commands.get_mut(&step).expect("located step");
+                let mut command = commands_at_step.remove(position);
+                if commands_at_step.is_empty() {
+                    self.commands.remove(&step);
                 }
-
-                // Rebuild the BTreeMap
-                let mut new_commands: BTreeMap<u32, Vec<StimulusCommand>> = BTreeMap::new();
-                for cmd in all_commands {
-                    new_commands.entry(cmd.step).or_default().push(cmd);
-                }
-                self.commands = new_commands;
+                // Appending keeps untouched commands in their original order.
+                command.step = new_step;
+                self.commands.entry(new_step).or_default().push(command);
             }
             Mutation::SwapStimulus {
                 event_index1,
@@ -133,9 +266,7 @@ impl StimulusSchedule {
                     all_commands.extend(commands_at_step);
                 }
 
-                if *event_index1 < all_commands.len() && *event_index2 < all_commands.len() {
-                    all_commands.swap(*event_index1, *event_index2);
-                }
+                all_commands.swap(*event_index1, *event_index2);
 
                 // Rebuild the BTreeMap
                 let mut new_commands: BTreeMap<u32, Vec<StimulusCommand>> = BTreeMap::new();
@@ -150,9 +281,7 @@ impl StimulusSchedule {
                     all_commands.extend(commands_at_step);
                 }
 
-                if *event_index < all_commands.len() {
-                    all_commands.remove(*event_index);
-                }
+                all_commands.remove(*event_index);
 
                 // Rebuild the BTreeMap
                 let mut new_commands: BTreeMap<u32, Vec<StimulusCommand>> = BTreeMap::new();
@@ -170,10 +299,9 @@ impl StimulusSchedule {
                     all_commands.extend(commands_at_step);
                 }
 
-                if let Some(cmd) = all_commands.get_mut(*event_index) {
-                    // Ensure step doesn't go below 0
-                    cmd.step = (cmd.step as i32 + time_delta).max(0) as u32;
-                }
+                let cmd = &mut all_commands[*event_index];
+                // Ensure step doesn't go below 0
+                cmd.step = (cmd.step as i32 + time_delta).max(0) as u32;
 
                 // Rebuild the BTreeMap
                 let mut new_commands: BTreeMap<u32, Vec<StimulusCommand>> = BTreeMap::new();
@@ -186,6 +314,55 @@ impl StimulusSchedule {
                 // Other mutations are handled by config or other types
             }
         }
+        true
+    }
+
+    /// `(step, position)` of the `event_index`-th command in step order.
+    fn locate_event(&self, event_index: usize) -> Option<(u32, usize)> {
+        let mut remaining = event_index;
+        for (step, commands) in &self.commands {
+            if remaining < commands.len() {
+                return Some((*step, remaining));
+            }
+            remaining -= commands.len();
+        }
+        None
+    }
+}
+
+/// Running per-topic totals checked against the scenario's stimulus caps.
+#[derive(Debug, Clone, Default)]
+pub struct StimulusBudget {
+    limits: HashMap<String, f32>,
+    spent: HashMap<String, f32>,
+}
+
+impl StimulusBudget {
+    pub fn new(limits: HashMap<String, f32>) -> Self {
+        Self {
+            limits,
+            spent: HashMap::new(),
+        }
+    }
+
+    /// Cap configured for `topic`, if any.
+    pub fn limit(&self, topic: &str) -> Option<f32> {
+        self.limits.get(topic).copied()
+    }
+
+    /// Charge an injection against the budget and return the portion that
+    /// still fits. Uncapped topics and non-positive values pass through.
+    pub fn admit(&mut self, topic: &str, value: f32) -> f32 {
+        let Some(limit) = self.limit(topic) else {
+            return value;
+        };
+        if value <= 0.0 {
+            return value;
+        }
+        let spent = self.spent.entry(topic.to_string()).or_insert(0.0);
+        let admitted = value.min((limit - *spent).max(0.0));
+        *spent += admitted;
+        admitted
     }
 }
 
@@ -203,6 +380,119 @@ mod tests {
     use std::fs::{read_to_string, remove_file};
     use std::time::{SystemTime, UNIX_EPOCH};
 
+    fn timing_schedule() -> StimulusSchedule {
+        let command = |step: u32, topic: &str| StimulusCommand {
+            step,
+            topic: topic.into(),
+            value: 1.0,
+            target: None,
+            source: None,
+            duration: 1,
+        };
+        let mut schedule = StimulusSchedule::new(BTreeMap::new(), None);
+        for (step, topic) in [(2, "a"), (5, "b"), (5, "c"), (9, "d")] {
+            schedule
+                .commands
+                .entry(step)
+                .or_default()
+                .push(command(step, topic));
+        }
+        schedule
+    }
+
+    fn topics_by_step(schedule: &StimulusSchedule) -> Vec<(u32, Vec<&str>)> {
+        schedule
+            .commands
+            .iter()
+            .map(|(step, commands)| {
+                assert!(commands.iter().all(|command| command.step == *step));
+                (*step, commands.iter().map(|c| c.topic.as_str()).collect())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn change_event_timing_moves_in_range_event() {
+        use crate::adversarial::Mutation;
+        let mut schedule = timing_schedule();
+        let moved = schedule.apply_mutation_within(
+            &Mutation::ChangeEventTiming {
+                event_index: 1,
+                new_step: 7,
+            },
+            Some(20),
+        );
+        assert!(moved);
+        assert_eq!(
+            topics_by_step(&schedule),
+            vec![
+                (2, vec!["a"]),
+                (5, vec!["c"]),
+                (7, vec!["b"]),
+                (9, vec!["d"])
+            ]
+        );
+
+        // Targets past the simulation horizon land on its last step.
+        schedule.apply_mutation_within(
+            &Mutation::ChangeEventTiming {
+                event_index: 0,
+                new_step: 500,
+            },
+            Some(20),
+        );
+        assert_eq!(schedule.commands[&19][0].topic, "a");
+    }
+
+    #[test]
+    fn event_index_mutations_reject_out_of_range_events() {
+        use crate::adversarial::Mutation;
+        let mut schedule = timing_schedule();
+        let before = schedule.commands.clone();
+        for mutation in [
+            Mutation::ChangeEventTiming {
+                event_index: 4,
+                new_step: 1,
+            },
+            Mutation::SwapStimulus {
+                event_index1: 0,
+                event_index2: 4,
+            },
+            Mutation::RemoveStimulus { event_index: 4 },
+            Mutation::ShiftStimulusTime {
+                event_index: 4,
+                time_delta: 1,
+            },
+        ] {
+            assert!(!schedule.apply_mutation(&mutation), "{mutation:?}");
+            assert_eq!(schedule.commands, before, "{mutation:?}");
+        }
+    }
+
+    #[test]
+    fn change_event_timing_merges_onto_occupied_step() {
+        use crate::adversarial::Mutation;
+        let mut schedule = timing_schedule();
+        schedule.apply_mutation(&Mutation::ChangeEventTiming {
+            event_index: 3,
+            new_step: 5,
+        });
+        assert_eq!(
+            topics_by_step(&schedule),
+            vec![(2, vec!["a"]), (5, vec!["b", "c", "d"])]
+        );
+    }
+
+    #[test]
+    fn budget_clamps_injections_past_topic_cap() {
+        let mut budget = StimulusBudget::new(HashMap::from([("activator".to_string(), 1.0)]));
+        assert!((budget.admit("activator", 0.6) - 0.6).abs() < 1e-6);
+        assert!((budget.admit("activator", 0.6) - 0.4).abs() < 1e-6);
+        assert_eq!(budget.admit("activator", 0.5), 0.0);
+        assert!((budget.admit("inhibitor", 5.0) - 5.0).abs() < 1e-6);
+        assert_eq!(budget.limit("inhibitor"), None);
+    }
+
     #[test]
     fn take_for_step_consumes_commands() {
         let mut schedule = StimulusSchedule {
@@ -236,6 +526,140 @@ mod tests {
         assert!(schedule.take_for_step(2).is_empty());
     }
 
+    fn write_duplicate_schedule(tag: &str) -> PathBuf {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("stimulus_dup_{tag}_{timestamp}.jsonl"));
+        let lines = [
+            r#"{"step":4,"topic":"activator","value":0.5}"#,
+            r#"{"step":1,"topic":"inhibitor","value":0.2}"#,
+            r#"{"step":4,"topic":"activator","value":0.25}"#,
+            r#"{"step":4,"topic":"inhibitor","value":0.1}"#,
+        ];
+        std::fs::write(&path, lines.join("\n")).expect("write schedule");
+        path
+    }
+
+    #[test]
+    fn load_strict_reports_duplicate_step_topic_pairs() {
+        let path = write_duplicate_schedule("strict");
+
+        let err = match StimulusSchedule::load_strict(&path) {
+            Ok(_) => panic!("duplicates should be rejected"),
+            Err(err) => err,
+        };
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("(step 4, topic `activator`)"));
+        assert!(!err.to_string().contains("inhibitor"));
+
+        // The lenient loader still accepts the file unchanged.
+        let lenient = StimulusSchedule::load(&path).expect("lenient load");
+        assert_eq!(lenient.commands[&4].len(), 3);
+
+        remove_file(&path).ok();
+    }
+
+    #[test]
+    fn dedup_summed_collapses_duplicates() {
+        let path = write_duplicate_schedule("dedup");
+        let mut schedule = StimulusSchedule::load(&path).expect("lenient load");
+
+        schedule.dedup_summed();
+        assert!(schedule.duplicate_entries().is_empty());
+        let step_four = &schedule.commands[&4];
+        assert_eq!(step_four.len(), 2);
+        assert_eq!(step_four[0].topic, "activator");
+        assert!((step_four[0].value - 0.75).abs() < 1e-6);
+        assert_eq!(schedule.commands[&1].len(), 1);
+
+        remove_file(&path).ok();
+    }
+
+    #[test]
+    fn targeted_commands_do_not_collide_with_broadcasts() {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("stimulus_targeted_{timestamp}.jsonl"));
+        let lines = [
+            r#"{"step":2,"topic":"inhibitor","value":0.5}"#,
+            r#"{"step":2,"topic":"inhibitor","value":0.4,"target":"cell-1"}"#,
+            r#"{"step":2,"topic":"inhibitor","value":0.1,"target":"cell-1"}"#,
+        ];
+        std::fs::write(&path, lines.join("\n")).expect("write schedule");
+
+        let mut schedule = StimulusSchedule::load(&path).expect("load");
+        assert_eq!(
+            schedule.duplicate_entries(),
+            vec![(2, "inhibitor".to_string())]
+        );
+        schedule.dedup_summed();
+        let step_two = &schedule.commands[&2];
+        assert_eq!(step_two.len(), 2);
+        assert_eq!(step_two[0].target, None);
+        assert!((step_two[0].value - 0.5).abs() < 1e-6);
+        assert_eq!(step_two[1].target.as_deref(), Some("cell-1"));
+        assert!((step_two[1].value - 0.5).abs() < 1e-6);
+
+        remove_file(&path).ok();
+    }
+
+    #[test]
+    fn csv_and_jsonl_schedules_load_identically() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let jsonl_path = dir.path().join("schedule.jsonl");
+        let csv_path = dir.path().join("schedule.csv");
+        std::fs::write(
+            &jsonl_path,
+            [
+                r#"{"step":1,"topic":"activator","value":0.5}"#,
+                r#"{"step":3,"topic":"inhibitor","value":0.25,"target":"seed-2"}"#,
+                r#"{"step":3,"topic":"activator","value":1.0}"#,
+            ]
+            .join("\n"),
+        )
+        .expect("write jsonl");
+        std::fs::write(
+            &csv_path,
+            "step,topic,value,target\n1,activator,0.5,\n3,inhibitor,0.25,seed-2\n3,activator,1.0,\n",
+        )
+        .expect("write csv");
+
+        let from_jsonl = StimulusSchedule::load_auto(&jsonl_path).expect("jsonl loads");
+        let from_csv = StimulusSchedule::load_auto(&csv_path).expect("csv loads");
+        assert_eq!(from_csv.commands, from_jsonl.commands);
+        assert_eq!(from_csv.source.as_deref(), Some(csv_path.as_path()));
+
+        std::fs::write(
+            &csv_path,
+            "step,topic,value\n2,activator,0.5\n2,activator,0.5\n",
+        )
+        .expect("rewrite csv");
+        let err = match StimulusSchedule::load_strict(&csv_path) {
+            Ok(_) => panic!("duplicates should be rejected"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("(step 2, topic `activator`)"));
+    }
+
+    #[test]
+    fn csv_empty_duration_cell_takes_the_default() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("schedule.csv");
+        std::fs::write(
+            &path,
+            "step,topic,value,duration\n1,activator,0.5,\n2,activator,0.5,4\n",
+        )
+        .expect("write csv");
+
+        let schedule = StimulusSchedule::load_csv(&path).expect("empty duration loads");
+        assert_eq!(schedule.commands[&1][0].duration, default_duration());
+        assert_eq!(schedule.commands[&2][0].duration, 4);
+    }
+
     #[test]
     fn append_command_writes_json_line() {
         let timestamp = SystemTime::now()
@@ -1,9 +1,13 @@
 //! Telemetry plumbing for observing morphogenetic dynamics.
 
-use crate::cellular::PopulationStats;
+use crate::adversarial::StepMetrics;
+use crate::cellular::{CellLineage, PopulationStats};
+use crate::orchestration::AppMetrics;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
 use std::fs::{File, OpenOptions};
-use std::io::{self, BufWriter, Write};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
 use std::path::Path;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -21,6 +25,16 @@ pub enum TelemetryEvent {
         cell_id: String,
         lineage: String,
     },
+    DifferentiationBlocked {
+        cell_id: String,
+        from: String,
+        to: String,
+    },
+    DifferentiationThrottled {
+        cell_id: String,
+        to: String,
+        steps_since_last: u32,
+    },
     SignalEmitted {
         cell_id: String,
         topic: String,
@@ -37,15 +51,34 @@ pub enum TelemetryEvent {
         source: String,
         target: String,
     },
+    /// A link was refused because an endpoint is at `max_degree`.
+    LinkRejected {
+        source: String,
+        target: String,
+    },
     PeerQuarantined {
         cell_id: String,
         target_id: String,
     },
+    PeerRehabilitated {
+        cell_id: String,
+        peer_id: String,
+    },
     TrustScoreUpdated {
         cell_id: String,
         target_id: String,
         new_score: f32,
     },
+    TopicMuted {
+        topic: String,
+    },
+    TopicUnmuted {
+        topic: String,
+    },
+    TrustSnapshot {
+        cell_id: String,
+        trust: BTreeMap<String, f32>,
+    },
     AnomalyDetected {
         cell_id: String,
         topic: String,
@@ -55,6 +88,45 @@ pub enum TelemetryEvent {
         cell_id: String,
         target_topic: String,
     },
+    StimulusBudgetExceeded {
+        topic: String,
+        requested: f32,
+        injected: f32,
+        budget: f32,
+    },
+    FaultInjected {
+        step: u32,
+        killed: usize,
+    },
+    SignalsTruncated {
+        cell_id: String,
+        delivered: usize,
+        kept: usize,
+    },
+    ReplicationDeferred {
+        step: u32,
+        deferred: usize,
+    },
+    SignalRelayed {
+        cell_id: String,
+        topic: String,
+        origin: String,
+        hops: usize,
+    },
+    SignalsLost {
+        step: u32,
+        count: usize,
+    },
+    /// Emitted activator volume has grown by at least the configured factor
+    /// on consecutive steps; `growth_rate` is this step's factor.
+    FeedbackLoopDetected {
+        step: u32,
+        growth_rate: f32,
+    },
+    GenomeMutated {
+        cell_id: String,
+        changed_fields: Vec<String>,
+    },
     StepSummary {
         step: u32,
         threat_score: f32,
@@ -62,9 +134,234 @@ pub enum TelemetryEvent {
         population_stats: Option<PopulationStats>,
         #[serde(default)]
         topology_stats: Option<TopologyStats>,
+        #[serde(default)]
+        lineage_counts: Option<BTreeMap<CellLineage, usize>>,
+        /// Cells whose action this step was `Idle`; only set when the
+        /// topology enables `record_idle`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        idle_cells: Option<usize>,
     },
 }
 
+impl TelemetryEvent {
+    /// Variant name, as used in serialized telemetry and debugger breakpoints.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TelemetryEvent::Scenario { .. } => "Scenario",
+            TelemetryEvent::CellReplicated { .. } => "CellReplicated",
+            TelemetryEvent::LineageShift { .. } => "LineageShift",
+            TelemetryEvent::DifferentiationBlocked { .. } => "DifferentiationBlocked",
+            TelemetryEvent::DifferentiationThrottled { .. } => "DifferentiationThrottled",
+            TelemetryEvent::SignalEmitted { .. } => "SignalEmitted",
+            TelemetryEvent::CellDied { .. } => "CellDied",
+            TelemetryEvent::LinkAdded { .. } => "LinkAdded",
+            TelemetryEvent::LinkRemoved { .. } => "LinkRemoved",
+            TelemetryEvent::LinkRejected { .. } => "LinkRejected",
+            TelemetryEvent::PeerQuarantined { .. } => "PeerQuarantined",
+            TelemetryEvent::PeerRehabilitated { .. } => "PeerRehabilitated",
+            TelemetryEvent::TrustScoreUpdated { .. } => "TrustScoreUpdated",
+            TelemetryEvent::TopicMuted { .. } => "TopicMuted",
+            TelemetryEvent::TopicUnmuted { .. } => "TopicUnmuted",
+            TelemetryEvent::TrustSnapshot { .. } => "TrustSnapshot",
+            TelemetryEvent::AnomalyDetected { .. } => "AnomalyDetected",
+            TelemetryEvent::VoteCast { .. } => "VoteCast",
+            TelemetryEvent::StimulusBudgetExceeded { .. } => "StimulusBudgetExceeded",
+            TelemetryEvent::FaultInjected { .. } => "FaultInjected",
+            TelemetryEvent::SignalsTruncated { .. } => "SignalsTruncated",
+            TelemetryEvent::ReplicationDeferred { .. } => "ReplicationDeferred",
+            TelemetryEvent::SignalRelayed { .. } => "SignalRelayed",
+            TelemetryEvent::SignalsLost { .. } => "SignalsLost",
+            TelemetryEvent::FeedbackLoopDetected { .. } => "FeedbackLoopDetected",
+            TelemetryEvent::GenomeMutated { .. } => "GenomeMutated",
+            TelemetryEvent::StepSummary { .. } => "StepSummary",
+        }
+    }
+}
+
+/// Rebuild the adjacency map by replaying `LinkAdded`/`LinkRemoved` events.
+///
+/// Links are undirected: an add records both directions and a remove drops
+/// one entry from each side. Removes for links that no longer exist (such as
+/// the reverse half logged when a cell dies) are ignored, and cells left
+/// without links are omitted from the result.
+pub fn reconstruct_topology(events: &[TelemetrySnapshot]) -> HashMap<String, Vec<String>> {
+    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+    let unlink = |adjacency: &mut HashMap<String, Vec<String>>, from: &str, to: &str| {
+        if let Some(list) = adjacency.get_mut(from)
+            && let Some(pos) = list.iter().position(|id| id == to)
+        {
+            list.remove(pos);
+        }
+    };
+    for snapshot in events {
+        match &snapshot.event {
+            TelemetryEvent::LinkAdded { source, target } => {
+                adjacency
+                    .entry(source.clone())
+                    .or_default()
+                    .push(target.clone());
+                adjacency
+                    .entry(target.clone())
+                    .or_default()
+                    .push(source.clone());
+            }
+            TelemetryEvent::LinkRemoved { source, target } => {
+                unlink(&mut adjacency, source, target);
+                unlink(&mut adjacency, target, source);
+            }
+            _ => {}
+        }
+    }
+    adjacency.retain(|_, links| !links.is_empty());
+    adjacency
+}
+
+/// Activator traffic around one quarantine, from [`quarantine_effectiveness`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuarantineReport {
+    pub step: u32,
+    pub cell_id: String,
+    pub target_id: String,
+    /// Mean per-step `activator` volume emitted in the window before `step`.
+    pub activator_before: f32,
+    /// Mean per-step `activator` volume emitted in the window after `step`.
+    pub activator_after: f32,
+    /// `(before - after) / before`: positive when propagation fell, 0 when
+    /// either window is empty or there was no activator traffic beforehand.
+    pub effectiveness: f32,
+}
+
+/// Compare network-wide `activator` emissions in the `window` steps before
+/// and after each `PeerQuarantined` event.
+///
+/// Events are attributed to the step whose `StepSummary` closes them; events
+/// after the final summary are ignored. Graph-mode disconnects are logged as
+/// plain `LinkRemoved` events and are not reported here.
+pub fn quarantine_effectiveness(
+    events: &[TelemetrySnapshot],
+    window: u32,
+) -> Vec<QuarantineReport> {
+    let mut volume_by_step: BTreeMap<u32, f32> = BTreeMap::new();
+    let mut quarantines: Vec<(u32, String, String)> = Vec::new();
+    let mut pending_volume = 0.0;
+    let mut pending_quarantines: Vec<(String, String)> = Vec::new();
+    for snapshot in events {
+        match &snapshot.event {
+            TelemetryEvent::SignalEmitted { topic, value, .. } if topic == "activator" => {
+                pending_volume += value;
+            }
+            TelemetryEvent::PeerQuarantined { cell_id, target_id } => {
+                pending_quarantines.push((cell_id.clone(), target_id.clone()));
+            }
+            TelemetryEvent::StepSummary { step, .. } => {
+                volume_by_step.insert(*step, std::mem::take(&mut pending_volume));
+                quarantines.extend(
+                    pending_quarantines
+                        .drain(..)
+                        .map(|(cell_id, target_id)| (*step, cell_id, target_id)),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let mean_volume = |from: u32, to: u32| {
+        if from > to {
+            return None;
+        }
+        let volumes: Vec<f32> = volume_by_step.range(from..=to).map(|(_, v)| *v).collect();
+        (!volumes.is_empty()).then(|| volumes.iter().sum::<f32>() / volumes.len() as f32)
+    };
+    quarantines
+        .into_iter()
+        .map(|(step, cell_id, target_id)| {
+            let before = step
+                .checked_sub(1)
+                .and_then(|last| mean_volume(step.saturating_sub(window), last));
+            let after = mean_volume(step + 1, step.saturating_add(window));
+            let effectiveness = match (before, after) {
+                (Some(before), Some(after)) if before > 0.0 => (before - after) / before,
+                _ => 0.0,
+            };
+            QuarantineReport {
+                step,
+                cell_id,
+                target_id,
+                activator_before: before.unwrap_or_default(),
+                activator_after: after.unwrap_or_default(),
+                effectiveness,
+            }
+        })
+        .collect()
+}
+
+/// Whole-run totals and peaks for a finished run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunSummary {
+    /// Live cells when the run ended.
+    pub final_population: usize,
+    /// Lineage histogram when the run ended.
+    pub final_lineages: BTreeMap<CellLineage, usize>,
+    pub replications: usize,
+    pub deaths: usize,
+    pub anomalies: usize,
+    /// `PeerQuarantined` events: every Global-mode disconnect, and Graph-mode
+    /// disconnects driven by consensus votes.
+    pub quarantines: usize,
+    pub peak_threat: f32,
+    pub peak_population: usize,
+    pub steps: usize,
+}
+
+impl RunSummary {
+    /// Summarise a run from the app's end state and its per-step metrics.
+    /// `events` only supplies the anomaly and quarantine counts, which step
+    /// metrics don't carry.
+    pub fn new(
+        final_state: &AppMetrics,
+        steps: &[StepMetrics],
+        events: &[TelemetrySnapshot],
+    ) -> Self {
+        let count = |matches: fn(&TelemetryEvent) -> bool| {
+            events
+                .iter()
+                .filter(|snapshot| matches(&snapshot.event))
+                .count()
+        };
+        RunSummary {
+            final_population: final_state.cell_count,
+            final_lineages: final_state.lineage_counts.clone(),
+            replications: steps.iter().map(|row| row.replications as usize).sum(),
+            deaths: steps.iter().map(|row| row.deaths as usize).sum(),
+            anomalies: count(|event| matches!(event, TelemetryEvent::AnomalyDetected { .. })),
+            quarantines: count(|event| matches!(event, TelemetryEvent::PeerQuarantined { .. })),
+            peak_threat: steps.iter().map(|row| row.threat_score).fold(0.0, f32::max),
+            peak_population: steps
+                .iter()
+                .map(|row| row.cell_count as usize)
+                .max()
+                .unwrap_or_default(),
+            steps: steps.len(),
+        }
+    }
+}
+
+impl fmt::Display for RunSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Run summary over {} step(s):", self.steps)?;
+        writeln!(f, "  final population   {}", self.final_population)?;
+        for (lineage, count) in &self.final_lineages {
+            writeln!(f, "    {:<18} {count}", format!("{lineage:?}"))?;
+        }
+        writeln!(f, "  peak population    {}", self.peak_population)?;
+        writeln!(f, "  peak threat        {:.3}", self.peak_threat)?;
+        writeln!(f, "  replications       {}", self.replications)?;
+        writeln!(f, "  deaths             {}", self.deaths)?;
+        writeln!(f, "  anomalies          {}", self.anomalies)?;
+        writeln!(f, "  quarantines        {}", self.quarantines)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopologyStats {
     pub avg_degree: f32,
@@ -113,7 +410,7 @@ impl InMemorySink {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct PersistedRecord {
     timestamp_ms: u128,
     event: TelemetryEvent,
@@ -126,9 +423,12 @@ fn system_time_to_millis(timestamp: SystemTime) -> u128 {
         .unwrap_or(0)
 }
 
+/// Append-only event log. Records stream in as the run progresses, so unlike
+/// the snapshot writers in [`crate::util`] it is not replaced atomically; each
+/// record is flushed as a whole line.
 #[allow(dead_code)]
 pub struct JsonlSink {
-    writer: BufWriter<File>,
+    writer: BufWriter<Box<dyn Write + Send>>,
 }
 
 impl JsonlSink {
@@ -136,27 +436,129 @@ impl JsonlSink {
     pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
         let file = OpenOptions::new().create(true).append(true).open(path)?;
         Ok(Self {
-            writer: BufWriter::new(file),
+            writer: BufWriter::new(Box::new(file)),
         })
     }
 
+    /// Gzip-compressed variant of [`Self::create`]. Appending to an existing
+    /// file adds a new gzip member, which [`read_jsonl`] reads through.
+    #[cfg(feature = "flate2")]
+    pub fn create_gzip<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        Ok(Self {
+            writer: BufWriter::new(Box::new(encoder)),
+        })
+    }
+
+    /// Open a sink for `path`, compressing when it ends in `.jsonl.gz`.
+    pub fn create_for_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        if !is_gzip_path(path) {
+            return Self::create(path);
+        }
+        #[cfg(feature = "flate2")]
+        return Self::create_gzip(path);
+        #[cfg(not(feature = "flate2"))]
+        Err(gzip_disabled(path))
+    }
+
+    /// Buffer one record, flushing once per step (after its `StepSummary`) so
+    /// a crashed run loses at most its unfinished step.
     fn write_record(&mut self, timestamp: SystemTime, event: TelemetryEvent) -> io::Result<()> {
+        let step_boundary = matches!(event, TelemetryEvent::StepSummary { .. });
         let record = PersistedRecord {
             timestamp_ms: system_time_to_millis(timestamp),
             event,
         };
         serde_json::to_writer(&mut self.writer, &record)?;
         self.writer.write_all(b"\n")?;
-        self.writer.flush()
+        if step_boundary {
+            self.writer.flush()?;
+        }
+        Ok(())
     }
 }
 
 impl TelemetrySink for JsonlSink {
     fn record(&mut self, timestamp: SystemTime, event: TelemetryEvent) {
         if let Err(err) = self.write_record(timestamp, event) {
-            eprintln!("Failed to write telemetry record: {err}");
+            log::error!("Failed to write telemetry record: {err}");
+        }
+    }
+}
+
+impl Drop for JsonlSink {
+    // Dropping the boxed writer afterwards finishes a gzip stream, so the
+    // trailer is written even when the sink is never explicitly closed.
+    fn drop(&mut self) {
+        if let Err(err) = self.writer.flush() {
+            log::error!("Failed to flush telemetry log: {err}");
+        }
+    }
+}
+
+/// Read back a telemetry log written by [`JsonlSink`], decompressing
+/// `.jsonl.gz` files. A gzip log cut off mid-stream (e.g. by a crash before
+/// the trailer was written) yields every complete record before the cut.
+pub fn read_jsonl<P: AsRef<Path>>(path: P) -> io::Result<Vec<TelemetrySnapshot>> {
+    let path = path.as_ref();
+    let file = File::open(path)?;
+    let gzip = is_gzip_path(path);
+    let reader: Box<dyn Read> = if gzip {
+        #[cfg(feature = "flate2")]
+        {
+            Box::new(flate2::read::MultiGzDecoder::new(file))
+        }
+        #[cfg(not(feature = "flate2"))]
+        {
+            drop(file);
+            return Err(gzip_disabled(path));
+        }
+    } else {
+        Box::new(file)
+    };
+
+    let mut snapshots = Vec::new();
+    for line in BufReader::new(reader).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) if gzip && err.kind() == io::ErrorKind::UnexpectedEof => {
+                log::warn!(
+                    "`{}` ends mid-stream; keeping {} complete records",
+                    path.display(),
+                    snapshots.len()
+                );
+                break;
+            }
+            Err(err) => return Err(err),
+        };
+        if line.trim().is_empty() {
+            continue;
         }
+        let record: PersistedRecord = serde_json::from_str(&line)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        snapshots.push(TelemetrySnapshot {
+            timestamp: UNIX_EPOCH + Duration::from_millis(record.timestamp_ms as u64),
+            event: record.event,
+        });
     }
+    Ok(snapshots)
+}
+
+fn is_gzip_path(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(".jsonl.gz")
+}
+
+#[cfg(not(feature = "flate2"))]
+fn gzip_disabled(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "`{}` needs gzip telemetry; rebuild with `--features flate2`",
+            path.display()
+        ),
+    )
 }
 
 #[allow(dead_code)]
@@ -173,7 +575,7 @@ impl TelemetryPipeline {
 
     #[allow(dead_code)]
     pub fn with_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
-        let file = JsonlSink::create(path)?;
+        let file = JsonlSink::create_for_path(path)?;
         Ok(Self {
             memory: InMemorySink::default(),
             file: Some(file),
@@ -201,3 +603,426 @@ impl TelemetrySink for TelemetryPipeline {
         }
     }
 }
+
+/// Telemetry pipeline that keeps only a rolling per-step aggregate.
+///
+/// Raw events are forwarded to the optional JSONL sink and dropped from
+/// memory; each `StepSummary` closes the pending aggregate into a
+/// [`StepMetrics`] row retrievable via [`StreamingPipeline::take_step_metrics`].
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct StreamingPipeline {
+    file: Option<JsonlSink>,
+    pending: PendingStep,
+    completed: VecDeque<StepMetrics>,
+}
+
+#[derive(Default)]
+struct PendingStep {
+    replications: u32,
+    deaths: u32,
+    signals_by_topic: BTreeMap<String, u32>,
+    lineage_shifts_by_lineage: BTreeMap<String, u32>,
+    stimulus_by_topic: BTreeMap<String, f32>,
+}
+
+impl StreamingPipeline {
+    #[allow(dead_code)]
+    pub fn new(file: Option<JsonlSink>) -> Self {
+        Self {
+            file,
+            ..Self::default()
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn with_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::new(Some(JsonlSink::create_for_path(path)?)))
+    }
+
+    /// Attribute injected stimulus to the step currently being aggregated.
+    #[allow(dead_code)]
+    pub fn record_stimulus(&mut self, topic: &str, value: f32) {
+        *self
+            .pending
+            .stimulus_by_topic
+            .entry(topic.to_string())
+            .or_insert(0.0) += value;
+    }
+
+    /// Drain the metrics of every step completed since the last call.
+    #[allow(dead_code)]
+    pub fn take_step_metrics(&mut self) -> Vec<StepMetrics> {
+        self.completed.drain(..).collect()
+    }
+
+    fn aggregate(&mut self, event: &TelemetryEvent) {
+        match event {
+            TelemetryEvent::CellReplicated { .. } => self.pending.replications += 1,
+            TelemetryEvent::CellDied { .. } => self.pending.deaths += 1,
+            TelemetryEvent::SignalEmitted { topic, .. } => {
+                *self
+                    .pending
+                    .signals_by_topic
+                    .entry(topic.clone())
+                    .or_insert(0) += 1;
+            }
+            TelemetryEvent::LineageShift { lineage, .. } => {
+                *self
+                    .pending
+                    .lineage_shifts_by_lineage
+                    .entry(lineage.clone())
+                    .or_insert(0) += 1;
+            }
+            TelemetryEvent::StepSummary {
+                step,
+                threat_score,
+                cell_count,
+                population_stats,
+                topology_stats,
+                ..
+            } => {
+                let pending = std::mem::take(&mut self.pending);
+                self.completed.push_back(StepMetrics {
+                    step: *step,
+                    threat_score: *threat_score,
+                    cell_count: *cell_count as u32,
+                    replications: pending.replications,
+                    deaths: pending.deaths,
+                    signals_total: pending.signals_by_topic.values().sum(),
+                    lineage_shifts_total: pending.lineage_shifts_by_lineage.values().sum(),
+                    stimulus_total: pending.stimulus_by_topic.values().sum(),
+                    signals_by_topic: pending.signals_by_topic,
+                    lineage_shifts_by_lineage: pending.lineage_shifts_by_lineage,
+                    stimulus_by_topic: pending.stimulus_by_topic,
+                    population_stats: population_stats.clone(),
+                    topology_stats: topology_stats.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+impl TelemetrySink for StreamingPipeline {
+    fn record(&mut self, timestamp: SystemTime, event: TelemetryEvent) {
+        self.aggregate(&event);
+        if let Some(file) = &mut self.file {
+            file.record(timestamp, event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MorphogeneticApp;
+    use crate::cellular::SecurityCell;
+    use crate::config::TopologyConfig;
+    use crate::signaling::Signal;
+
+    /// Buffered derivation: slice the retained events between step summaries.
+    fn buffered_step_metrics(events: &[TelemetrySnapshot]) -> Vec<StepMetrics> {
+        let mut rows = Vec::new();
+        let mut start = 0;
+        for (idx, snapshot) in events.iter().enumerate() {
+            let TelemetryEvent::StepSummary {
+                step,
+                threat_score,
+                cell_count,
+                population_stats,
+                topology_stats,
+                ..
+            } = &snapshot.event
+            else {
+                continue;
+            };
+            let window = &events[start..idx];
+            let count = |pred: fn(&TelemetryEvent) -> bool| {
+                window.iter().filter(|s| pred(&s.event)).count() as u32
+            };
+            rows.push(StepMetrics {
+                step: *step,
+                threat_score: *threat_score,
+                cell_count: *cell_count as u32,
+                replications: count(|e| matches!(e, TelemetryEvent::CellReplicated { .. })),
+                deaths: count(|e| matches!(e, TelemetryEvent::CellDied { .. })),
+                signals_total: count(|e| matches!(e, TelemetryEvent::SignalEmitted { .. })),
+                lineage_shifts_total: count(|e| matches!(e, TelemetryEvent::LineageShift { .. })),
+                stimulus_total: 0.0,
+                signals_by_topic: BTreeMap::new(),
+                lineage_shifts_by_lineage: BTreeMap::new(),
+                stimulus_by_topic: BTreeMap::new(),
+                population_stats: population_stats.clone(),
+                topology_stats: topology_stats.clone(),
+            });
+            start = idx + 1;
+        }
+        rows
+    }
+
+    #[test]
+    fn streaming_aggregate_matches_buffered_derivation() {
+        let cells: Vec<SecurityCell> = (0..4)
+            .map(|idx| {
+                let mut cell = SecurityCell::new(format!("seed-{idx}"));
+                cell.genome.reproduction_threshold = 0.5;
+                cell.genome.signal_emission_threshold = 0.3;
+                cell
+            })
+            .collect();
+        let mut app = MorphogeneticApp::new(
+            cells,
+            TelemetryPipeline::new(InMemorySink::default(), None),
+            TopologyConfig::default(),
+        );
+        for step in 0..6 {
+            app.inject_signal(Signal::new("activator", 0.2 * step as f32));
+            app.step(step, 0.1 * step as f32);
+        }
+
+        let buffered = buffered_step_metrics(app.telemetry().events());
+        let mut streaming = StreamingPipeline::default();
+        for snapshot in app.telemetry().events() {
+            streaming.record(snapshot.timestamp, snapshot.event.clone());
+        }
+        let streamed = streaming.take_step_metrics();
+
+        assert_eq!(streamed.len(), 6);
+        assert_eq!(streamed.len(), buffered.len());
+        for (streamed, buffered) in streamed.iter().zip(&buffered) {
+            assert_eq!(streamed.step, buffered.step);
+            assert_eq!(streamed.cell_count, buffered.cell_count);
+            assert_eq!(streamed.replications, buffered.replications);
+            assert_eq!(streamed.deaths, buffered.deaths);
+            assert_eq!(streamed.signals_total, buffered.signals_total);
+            assert_eq!(streamed.lineage_shifts_total, buffered.lineage_shifts_total);
+            assert!((streamed.threat_score - buffered.threat_score).abs() < f32::EPSILON);
+        }
+        assert!(streamed.iter().any(|row| row.signals_total > 0));
+        assert!(streaming.take_step_metrics().is_empty());
+    }
+
+    #[test]
+    fn streaming_pipeline_attributes_stimulus_to_pending_step() {
+        let mut streaming = StreamingPipeline::default();
+        streaming.record_stimulus("activator", 0.4);
+        streaming.record_stimulus("activator", 0.1);
+        streaming.record(
+            SystemTime::now(),
+            TelemetryEvent::StepSummary {
+                step: 0,
+                threat_score: 0.0,
+                cell_count: 1,
+                population_stats: None,
+                topology_stats: None,
+                lineage_counts: None,
+                idle_cells: None,
+            },
+        );
+        let rows = streaming.take_step_metrics();
+        assert_eq!(rows.len(), 1);
+        assert!((rows[0].stimulus_total - 0.5).abs() < 1e-6);
+        assert!((rows[0].stimulus_by_topic["activator"] - 0.5).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn gzip_telemetry_round_trips_through_read_jsonl() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("telemetry.jsonl.gz");
+        {
+            let mut pipeline = TelemetryPipeline::with_file(&path).expect("gzip sink");
+            pipeline.record(
+                SystemTime::now(),
+                TelemetryEvent::CellDied {
+                    cell_id: "gone".to_string(),
+                },
+            );
+            pipeline.record(
+                SystemTime::now(),
+                TelemetryEvent::TopicMuted {
+                    topic: "activator".to_string(),
+                },
+            );
+        }
+
+        let raw = std::fs::read(&path).unwrap();
+        assert_eq!(&raw[..2], &[0x1f, 0x8b], "file is gzip encoded");
+
+        let events = read_jsonl(&path).expect("read gzip telemetry");
+        let kinds: Vec<_> = events
+            .iter()
+            .map(|snapshot| snapshot.event.kind())
+            .collect();
+        assert_eq!(kinds, vec!["CellDied", "TopicMuted"]);
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn truncated_gzip_telemetry_keeps_flushed_steps() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("telemetry.jsonl.gz");
+        {
+            let pipeline = TelemetryPipeline::with_file(&path).expect("gzip sink");
+            let mut app = MorphogeneticApp::new(
+                vec![SecurityCell::new("solo")],
+                pipeline,
+                TopologyConfig::default(),
+            );
+            for step in 0..3 {
+                app.step(step, 0.2);
+            }
+        }
+        let complete = read_jsonl(&path).expect("read gzip telemetry");
+
+        // Drop the gzip trailer and final block, as a killed process would.
+        let raw = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &raw[..raw.len() - 10]).unwrap();
+
+        let recovered = read_jsonl(&path).expect("truncated tail is tolerated");
+        assert_eq!(recovered.len(), complete.len());
+        assert_eq!(
+            recovered.last().map(|snapshot| snapshot.event.kind()),
+            Some("StepSummary")
+        );
+    }
+
+    #[test]
+    fn quarantine_that_cuts_activator_traffic_scores_positive() {
+        let snapshot = |event| TelemetrySnapshot {
+            timestamp: UNIX_EPOCH,
+            event,
+        };
+        let activator = || {
+            snapshot(TelemetryEvent::SignalEmitted {
+                cell_id: "traitor".to_string(),
+                topic: "activator".to_string(),
+                value: 1.0,
+            })
+        };
+        let summary = |step| {
+            snapshot(TelemetryEvent::StepSummary {
+                step,
+                threat_score: 0.0,
+                cell_count: 4,
+                population_stats: None,
+                topology_stats: None,
+                lineage_counts: None,
+                idle_cells: None,
+            })
+        };
+
+        let mut events = Vec::new();
+        for step in 0..3 {
+            events.extend((0..3).map(|_| activator()));
+            events.push(summary(step));
+        }
+        events.push(snapshot(TelemetryEvent::PeerQuarantined {
+            cell_id: "guard".to_string(),
+            target_id: "traitor".to_string(),
+        }));
+        events.push(summary(3));
+        for step in 4..7 {
+            events.push(activator());
+            // Inhibitor traffic is not propagation and must not count.
+            events.push(snapshot(TelemetryEvent::SignalEmitted {
+                cell_id: "guard".to_string(),
+                topic: "inhibitor".to_string(),
+                value: 5.0,
+            }));
+            events.push(summary(step));
+        }
+
+        let reports = quarantine_effectiveness(&events, 3);
+        assert_eq!(reports.len(), 1);
+        let report = &reports[0];
+        assert_eq!(report.step, 3);
+        assert_eq!(report.target_id, "traitor");
+        assert!((report.activator_before - 3.0).abs() < 1e-6);
+        assert!((report.activator_after - 1.0).abs() < 1e-6);
+        assert!(report.effectiveness > 0.0);
+        assert!((report.effectiveness - 2.0 / 3.0).abs() < 1e-6);
+
+        assert_eq!(quarantine_effectiveness(&events, 0)[0].effectiveness, 0.0);
+    }
+
+    #[test]
+    fn run_summary_counts_known_telemetry_stream() {
+        let snapshot = |event| TelemetrySnapshot {
+            timestamp: UNIX_EPOCH,
+            event,
+        };
+        let summary = |step, threat_score, cell_count, lineage_counts| {
+            snapshot(TelemetryEvent::StepSummary {
+                step,
+                threat_score,
+                cell_count,
+                population_stats: None,
+                topology_stats: None,
+                lineage_counts,
+                idle_cells: None,
+            })
+        };
+        let lineages: BTreeMap<CellLineage, usize> =
+            [(CellLineage::Stem, 2), (CellLineage::IntrusionDetection, 1)]
+                .into_iter()
+                .collect();
+        let final_state = AppMetrics {
+            cell_count: 3,
+            lineage_counts: lineages.clone(),
+            avg_energy: 0.0,
+            avg_stress: 0.0,
+            topology: TopologyStats {
+                avg_degree: 0.0,
+                isolation_count: 0,
+            },
+        };
+
+        let events = vec![
+            snapshot(TelemetryEvent::CellReplicated {
+                cell_id: "a".to_string(),
+                child_id: "a-1".to_string(),
+            }),
+            snapshot(TelemetryEvent::CellReplicated {
+                cell_id: "a".to_string(),
+                child_id: "a-2".to_string(),
+            }),
+            summary(0, 0.8, 4, None),
+            snapshot(TelemetryEvent::AnomalyDetected {
+                cell_id: "a-1".to_string(),
+                topic: "activator".to_string(),
+                confidence: 0.9,
+            }),
+            snapshot(TelemetryEvent::PeerQuarantined {
+                cell_id: "a-1".to_string(),
+                target_id: "a-2".to_string(),
+            }),
+            snapshot(TelemetryEvent::CellDied {
+                cell_id: "a-2".to_string(),
+            }),
+            summary(1, 0.3, 3, None),
+        ];
+
+        assert_eq!(
+            RunSummary::new(&final_state, &StepMetrics::from_telemetry(&events), &events),
+            RunSummary {
+                final_population: 3,
+                final_lineages: lineages,
+                replications: 2,
+                deaths: 1,
+                anomalies: 1,
+                quarantines: 1,
+                peak_threat: 0.8,
+                peak_population: 4,
+                steps: 2,
+            }
+        );
+        let empty = AppMetrics {
+            cell_count: 0,
+            lineage_counts: BTreeMap::new(),
+            ..final_state
+        };
+        assert_eq!(RunSummary::new(&empty, &[], &[]), RunSummary::default());
+    }
+}
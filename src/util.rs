@@ -0,0 +1,66 @@
+//! Small filesystem helpers shared by the persistence code paths.
+
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Write `path` via a sibling `.tmp` file that is renamed into place only
+/// once `write` succeeds, so readers never observe a half-written file.
+///
+/// On failure the temporary file is removed and any existing file at `path`
+/// is left untouched.
+pub fn write_atomic<P, F, E>(path: P, write: F) -> Result<(), E>
+where
+    P: AsRef<Path>,
+    F: FnOnce(&mut BufWriter<File>) -> Result<(), E>,
+    E: From<io::Error>,
+{
+    let path = path.as_ref();
+    let tmp_path = temp_path_for(path);
+    let written = (|| {
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        write(&mut writer)?;
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        Ok(())
+    })();
+    if let Err(err) = written {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err);
+    }
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+fn temp_path_for(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn failed_write_leaves_original_file_untouched() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("artifact.jsonl");
+        fs::write(&path, "original\n").unwrap();
+
+        let result: io::Result<()> = write_atomic(&path, |writer| {
+            writer.write_all(b"partial")?;
+            Err(io::Error::other("serializer failed"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "original\n");
+        assert!(!temp_path_for(&path).exists());
+
+        write_atomic(&path, |writer| writer.write_all(b"replaced\n")).expect("atomic write");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "replaced\n");
+        assert!(!temp_path_for(&path).exists());
+    }
+}
@@ -0,0 +1,43 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn max_wall_time_stops_between_generations_and_saves_state() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let scenario_path = dir.path().join("scenario.yaml");
+    let state_path = dir.path().join("state.json");
+    fs::write(
+        &scenario_path,
+        "scenario_name: budget\ninitial_cell_count: 3\nsimulation_steps: 4\n",
+    )
+    .expect("write scenario");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_adversarial_loop"))
+        .arg("--state")
+        .arg(&state_path)
+        .arg("--seed")
+        .arg(format!("seed-a={}", scenario_path.display()))
+        .arg("--generations")
+        .arg("25")
+        .arg("--artifact-dir")
+        .arg(dir.path().join("runs"))
+        .arg("--max-wall-time")
+        .arg("0")
+        .output()
+        .expect("run adversarial_loop");
+    // Progress is logged to stderr; the evaluation table goes to stdout.
+    let log = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "adversarial_loop failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(log.matches("Starting generation").count(), 1);
+    assert!(log.contains("Wall-time budget"), "{log}");
+
+    let state: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&state_path).expect("state saved"))
+            .expect("state is valid JSON");
+    assert_eq!(state["archive"].as_array().map(Vec::len), Some(1));
+}
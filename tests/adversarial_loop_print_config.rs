@@ -0,0 +1,29 @@
+use morphogenetic_security::adversarial::EvolutionConfig;
+use std::process::Command;
+
+#[test]
+fn print_config_emits_effective_config_without_saving_state() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let state_path = dir.path().join("state.json");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_adversarial_loop"))
+        .arg("--state")
+        .arg(&state_path)
+        .args(["--batch-size", "7", "--print-config"])
+        .output()
+        .expect("run adversarial_loop");
+    assert!(
+        output.status.success(),
+        "print-config failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let printed: EvolutionConfig =
+        serde_json::from_slice(&output.stdout).expect("stdout is config JSON");
+    let expected = EvolutionConfig {
+        batch_size: 7,
+        ..EvolutionConfig::default_smoke_test()
+    };
+    assert_eq!(printed, expected);
+    assert!(!state_path.exists(), "print-config must not write state");
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn adversarial_loop(state_path: &Path, runs: &Path, extra: &[String]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_adversarial_loop"))
+        .arg("--state")
+        .arg(state_path)
+        .arg("--artifact-dir")
+        .arg(runs)
+        .args(extra)
+        .output()
+        .expect("run adversarial_loop")
+}
+
+#[test]
+fn replaying_an_archived_candidate_writes_a_fresh_outcome() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let scenario_path = dir.path().join("scenario.yaml");
+    let state_path = dir.path().join("state.json");
+    let runs = dir.path().join("runs");
+    fs::write(
+        &scenario_path,
+        "scenario_name: replay\ninitial_cell_count: 3\nsimulation_steps: 4\n",
+    )
+    .expect("write scenario");
+
+    let seeded = adversarial_loop(
+        &state_path,
+        &runs,
+        &[
+            "--seed".to_string(),
+            format!("seed-a={}", scenario_path.display()),
+        ],
+    );
+    assert!(
+        seeded.status.success(),
+        "seed run failed: {}",
+        String::from_utf8_lossy(&seeded.stderr)
+    );
+    let state_before = fs::read_to_string(&state_path).expect("state saved");
+
+    let replayed = adversarial_loop(
+        &state_path,
+        &runs,
+        &["--replay".to_string(), "seed-a".to_string()],
+    );
+    assert!(
+        replayed.status.success(),
+        "replay failed: {}",
+        String::from_utf8_lossy(&replayed.stderr)
+    );
+
+    let replay_dir = runs.join("replay/gen000/seed-a");
+    let outcome: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(replay_dir.join("outcome.json")).expect("outcome written"),
+    )
+    .expect("outcome is valid JSON");
+    assert_eq!(outcome["candidate"]["id"], "seed-a");
+    assert_eq!(outcome["statistics"]["step_count"], 4);
+    assert!(replay_dir.join("telemetry.jsonl").exists());
+    assert_eq!(
+        fs::read_to_string(&state_path).expect("state still present"),
+        state_before,
+        "replay must not modify harness state"
+    );
+
+    let missing = adversarial_loop(
+        &state_path,
+        &runs,
+        &["--replay".to_string(), "no-such-candidate".to_string()],
+    );
+    assert!(!missing.status.success());
+    let stderr = String::from_utf8_lossy(&missing.stderr);
+    assert!(
+        stderr.contains("`no-such-candidate` is not in the harness archive"),
+        "unexpected error: {stderr}"
+    );
+}
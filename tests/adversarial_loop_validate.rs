@@ -0,0 +1,67 @@
+use morphogenetic_security::adversarial::{
+    AdversarialHarness, AttackCandidate, AttackOutcome, EvolutionConfig, RunStatistics,
+};
+use std::fs;
+use std::process::Command;
+
+fn outcome(id: &str, generation: u32, fitness_score: f32) -> AttackOutcome {
+    AttackOutcome {
+        candidate: AttackCandidate {
+            id: id.to_string(),
+            scenario_ref: "scenario.yaml".to_string(),
+            stimulus_ref: None,
+            generation,
+            parent_id: None,
+            origin_scenario: None,
+            cumulative_factors: Default::default(),
+            mutation: None,
+        },
+        fitness_score,
+        breach_observed: false,
+        notes: None,
+        statistics: RunStatistics::default(),
+    }
+}
+
+#[test]
+fn validating_a_well_formed_state_reports_its_archive() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let state_path = dir.path().join("state.json");
+    let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+    harness.record_outcome(outcome("seed", 0, 0.25));
+    harness.record_outcome(outcome("child", 3, 0.75));
+    harness.save_state(&state_path).expect("save state");
+    let before = fs::read_to_string(&state_path).expect("state saved");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_adversarial_loop"))
+        .arg("--state")
+        .arg(&state_path)
+        .arg("--validate-state")
+        .output()
+        .expect("run adversarial_loop");
+    assert!(
+        output.status.success(),
+        "validation failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for expected in [
+        "Archive size: 2",
+        "Backlog length: 0",
+        "Generation: 3",
+        "Best fitness: 0.750",
+        "Worst fitness: 0.250",
+        "Dropped on load: 0",
+    ] {
+        assert!(
+            stdout.contains(expected),
+            "missing `{expected}` in:\n{stdout}"
+        );
+    }
+    assert_eq!(
+        fs::read_to_string(&state_path).expect("state still present"),
+        before,
+        "validation must not modify harness state"
+    );
+}
@@ -0,0 +1,43 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn warmup_steps_are_simulated_but_excluded_from_metrics() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let scenario_path = dir.path().join("scenario.yaml");
+    let state_path = dir.path().join("state.json");
+    let runs = dir.path().join("runs");
+    fs::write(
+        &scenario_path,
+        "scenario_name: warmup\ninitial_cell_count: 3\nsimulation_steps: 6\nwarmup_steps: 3\n",
+    )
+    .expect("write scenario");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_adversarial_loop"))
+        .arg("--state")
+        .arg(&state_path)
+        .arg("--seed")
+        .arg(format!("seed-a={}", scenario_path.display()))
+        .arg("--artifact-dir")
+        .arg(&runs)
+        .output()
+        .expect("run adversarial_loop");
+    assert!(
+        output.status.success(),
+        "adversarial_loop failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut reader = csv::Reader::from_path(runs.join("gen000/seed-a/step_metrics.csv"))
+        .expect("step metrics written");
+    let steps: Vec<u32> = reader
+        .records()
+        .map(|record| record.expect("csv row")[0].parse().expect("step index"))
+        .collect();
+    assert_eq!(steps, vec![3, 4, 5]);
+
+    let state: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&state_path).expect("state saved"))
+            .expect("state is valid JSON");
+    assert_eq!(state["archive"][0]["statistics"]["step_count"], 3);
+}
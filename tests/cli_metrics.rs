@@ -0,0 +1,34 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn metrics_flag_writes_one_csv_row_per_step() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let config_path = dir.path().join("scenario.yaml");
+    let metrics_path = dir.path().join("step_metrics.csv");
+    fs::write(
+        &config_path,
+        "scenario_name: cli-metrics\ninitial_cell_count: 3\nsimulation_steps: 7\n",
+    )
+    .expect("write scenario");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_morphogenetic-security"))
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--metrics")
+        .arg(&metrics_path)
+        .output()
+        .expect("run binary");
+    assert!(
+        output.status.success(),
+        "binary failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut reader = csv::Reader::from_path(&metrics_path).expect("open metrics csv");
+    let steps: Vec<u32> = reader
+        .records()
+        .map(|record| record.expect("csv row")[0].parse().expect("step column"))
+        .collect();
+    assert_eq!(steps, (0..7).collect::<Vec<u32>>());
+}
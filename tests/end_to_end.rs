@@ -0,0 +1,100 @@
+use morphogenetic_security::adversarial::{AdversarialHarness, AttackCandidate, EvolutionConfig};
+use morphogenetic_security::config;
+use morphogenetic_security::signaling::Signal;
+use morphogenetic_security::stimulus::StimulusSchedule;
+use morphogenetic_security::telemetry::StreamingPipeline;
+use morphogenetic_security::{MorphogeneticApp, StepMetrics};
+use std::fs;
+
+const SCENARIO: &str = "\
+scenario_name: end-to-end
+initial_cell_count: 4
+simulation_steps: 6
+threat_profile:
+  background_threat: 0.4
+  spike_threshold: 0.7
+spikes:
+  - step: 2
+    intensity: 0.5
+";
+
+const STIMULUS: &str = r#"{"step":1,"topic":"activator","value":0.6,"duration":2}
+{"step":3,"topic":"inhibitor","value":0.3}
+"#;
+
+#[test]
+fn scenario_and_stimulus_flow_through_to_harness_analysis() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let scenario_path = dir.path().join("scenario.yaml");
+    let stimulus_path = dir.path().join("stimulus.jsonl");
+    fs::write(&scenario_path, SCENARIO).expect("write scenario");
+    fs::write(&stimulus_path, STIMULUS).expect("write stimulus");
+
+    let scenario = config::load_from_path(&scenario_path).expect("scenario parses");
+    let mut schedule = StimulusSchedule::load(&stimulus_path).expect("stimulus parses");
+    let mut app = MorphogeneticApp::new(
+        scenario.build_population(),
+        StreamingPipeline::default(),
+        scenario.topology.clone(),
+    );
+
+    let mut steps: Vec<StepMetrics> = Vec::new();
+    let mut active = Vec::new();
+    for step in 0..scenario.simulation_steps {
+        for signal in scenario.scheduled_signals(step) {
+            app.telemetry_mut()
+                .record_stimulus(&signal.topic, signal.value);
+            app.inject_signal(signal);
+        }
+        active.extend(schedule.take_for_step(step));
+        for command in &active {
+            app.telemetry_mut()
+                .record_stimulus(&command.topic, command.value);
+            app.inject_signal(Signal {
+                source: command.source.clone(),
+                target: command.target.clone(),
+                ..Signal::new(command.topic.clone(), command.value)
+            });
+        }
+        active.retain(|command| step < command.step + command.duration - 1);
+
+        app.step(step, scenario.threat_level_for_step(step));
+        steps.extend(app.telemetry_mut().take_step_metrics());
+    }
+
+    assert_eq!(steps.len(), scenario.simulation_steps as usize);
+    assert!(steps.iter().all(|row| row.cell_count > 0));
+    assert!(
+        steps
+            .iter()
+            .any(|row| row.stimulus_by_topic.contains_key("activator"))
+    );
+
+    let mut harness = AdversarialHarness::new(EvolutionConfig::default_smoke_test());
+    let candidate = AttackCandidate {
+        id: "end-to-end".to_string(),
+        scenario_ref: scenario_path.display().to_string(),
+        stimulus_ref: Some(stimulus_path.display().to_string()),
+        generation: 0,
+        parent_id: None,
+        mutation: None,
+        origin_scenario: None,
+        cumulative_factors: Default::default(),
+    };
+    let (outcome, _follow_up, analysis) = harness
+        .evaluate_steps(candidate, steps)
+        .expect("evaluation succeeds");
+
+    assert_eq!(analysis.statistics.step_count, 6);
+    assert!(analysis.statistics.total_stimulus > 0.0);
+    assert!(
+        outcome.fitness_score > 0.0 && outcome.fitness_score <= 1.0,
+        "implausible fitness {}",
+        outcome.fitness_score
+    );
+    assert!(
+        analysis.recommended_mutation.is_some(),
+        "no mutation recommended ({})",
+        analysis.recommendation_reason
+    );
+}
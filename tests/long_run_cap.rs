@@ -0,0 +1,38 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn scenario_over_step_cap_needs_allow_long_run() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let config_path = dir.path().join("scenario.yaml");
+    fs::write(
+        &config_path,
+        "scenario_name: long-run\nsimulation_steps: 5\nmax_simulation_steps: 3\n",
+    )
+    .expect("write scenario");
+
+    let run = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_morphogenetic-security"))
+            .arg("--config")
+            .arg(&config_path)
+            .args(extra)
+            .output()
+            .expect("run binary")
+    };
+
+    let rejected = run(&[]);
+    assert!(!rejected.status.success());
+    let stderr = String::from_utf8_lossy(&rejected.stderr);
+    assert!(
+        stderr.contains("exceeds max_simulation_steps"),
+        "unexpected stderr: {stderr}"
+    );
+
+    let allowed = run(&["--allow-long-run"]);
+    assert!(
+        allowed.status.success(),
+        "override failed: {}",
+        String::from_utf8_lossy(&allowed.stderr)
+    );
+    assert!(String::from_utf8_lossy(&allowed.stdout).contains("executed 5 step(s)"));
+}
@@ -0,0 +1,48 @@
+//! Lives in its own test binary: the PKI registry is process-global, so
+//! clearing it would race with attestation checks in other tests.
+
+use morphogenetic_security::immune::{Pki, TPM, pki_clear, pki_len};
+
+#[test]
+fn registry_grows_per_tpm_and_clears() {
+    let before = pki_len();
+    let tpms: Vec<TPM> = (0..5)
+        .map(|idx| TPM::new(format!("pki-registry-test-{idx}")))
+        .collect();
+    assert_eq!(pki_len(), before + tpms.len());
+
+    // Re-keying an existing id replaces its entry instead of adding one.
+    let _rekeyed = TPM::new("pki-registry-test-0".to_string());
+    assert_eq!(pki_len(), before + tpms.len());
+
+    let attestation = tpms[1].attest(0, "payload").expect("fresh TPM attests");
+    assert!(Pki::global().verify(&attestation, 0, "payload"));
+
+    pki_clear();
+    assert_eq!(pki_len(), 0);
+    assert!(!Pki::global().verify(&attestation, 0, "payload"));
+}
+
+#[test]
+fn independent_registries_do_not_share_keys() {
+    let first = Pki::new();
+    let second = Pki::new();
+    let alpha = TPM::generate("alpha".to_string());
+    let beta = TPM::generate("alpha".to_string());
+    first.register(&alpha);
+    second.register(&beta);
+    assert_eq!((first.len(), second.len()), (1, 1));
+
+    let from_alpha = alpha.attest(3, "payload").expect("alpha attests");
+    let from_beta = beta.attest(3, "payload").expect("beta attests");
+    assert!(first.verify(&from_alpha, 3, "payload"));
+    assert!(!second.verify(&from_alpha, 3, "payload"));
+    assert!(second.verify(&from_beta, 3, "payload"));
+    assert!(!first.verify(&from_beta, 3, "payload"));
+
+    // Neither the unpublished keys nor clearing one registry touch the other.
+    assert!(!Pki::global().verify(&from_alpha, 3, "payload"));
+    first.clear();
+    assert!(first.is_empty());
+    assert!(second.verify(&from_beta, 3, "payload"));
+}
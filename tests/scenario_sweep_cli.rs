@@ -0,0 +1,52 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn sweep_writes_one_comparison_row_per_scenario() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let scenarios = dir.path().join("scenarios");
+    let output_dir = dir.path().join("sweep");
+    fs::create_dir_all(&scenarios).expect("scenario dir");
+    fs::write(
+        scenarios.join("calm.yaml"),
+        "scenario_name: calm\ninitial_cell_count: 3\nsimulation_steps: 4\n",
+    )
+    .expect("write calm scenario");
+    fs::write(
+        scenarios.join("spiky.yaml"),
+        "scenario_name: spiky\ninitial_cell_count: 3\nsimulation_steps: 4\nspikes:\n  - step: 1\n    intensity: 0.9\n",
+    )
+    .expect("write spiky scenario");
+    fs::write(scenarios.join("notes.txt"), "not a scenario").expect("write notes");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_scenario_sweep"))
+        .arg(&scenarios)
+        .arg("--jobs")
+        .arg("2")
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .output()
+        .expect("run scenario_sweep");
+    assert!(
+        output.status.success(),
+        "scenario_sweep failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut reader =
+        csv::Reader::from_path(output_dir.join("comparison.csv")).expect("comparison written");
+    let rows: Vec<csv::StringRecord> = reader.records().map(|row| row.expect("row")).collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(&rows[0][0], "calm");
+    assert_eq!(&rows[1][0], "spiky");
+
+    for name in ["calm", "spiky"] {
+        let outcome: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(output_dir.join(format!("{name}_outcome.json")))
+                .expect("outcome written"),
+        )
+        .expect("outcome is valid JSON");
+        assert_eq!(outcome["outcome"]["candidate_id"], format!("sweep-{name}"));
+        assert_eq!(outcome["statistics"]["step_count"], 4);
+    }
+}
@@ -4,19 +4,15 @@ use morphogenetic_security::signaling::{Signal, SignalBus};
 #[test]
 fn test_security_cell_tick_does_not_short_circuit_on_consensus_spam() {
     let mut cell = SecurityCell::new("test_cell");
-    let signal = Signal {
-        topic: "consensus:activator".to_string(),
-        value: 1.0,
-        source: Some("malicious_peer".to_string()),
-        target: None,
-        attestation: None, // Unauthenticated!
-    };
+    // Unauthenticated!
+    let signal = Signal::new("consensus:activator", 1.0).with_source("malicious_peer");
 
     let env = CellEnvironment {
         step: 10,
         local_threat_score: 0.1,
-        neighbor_signals: vec![signal],
+        neighbor_signals: vec![signal].into(),
         detected_neighbors: vec!["malicious_peer".to_string()],
+        ..Default::default()
     };
 
     let action = cell.tick(&env);
@@ -34,40 +30,24 @@ fn test_signal_bus_purge_removes_all_signals_from_source() {
     let mut bus = SignalBus::default();
 
     // Targeted signal to victim
-    bus.publish(Signal {
-        topic: "ping".to_string(),
-        value: 1.0,
-        source: Some("spammer".to_string()),
-        target: Some("victim".to_string()),
-        attestation: None,
-    });
+    bus.publish(
+        Signal::new("ping", 1.0)
+            .with_source("spammer")
+            .with_target("victim"),
+    );
 
     // Broadcast signal
-    bus.publish(Signal {
-        topic: "broadcast_spam".to_string(),
-        value: 1.0,
-        source: Some("spammer".to_string()),
-        target: None,
-        attestation: None,
-    });
+    bus.publish(Signal::new("broadcast_spam", 1.0).with_source("spammer"));
 
     // Targeted signal to someone else
-    bus.publish(Signal {
-        topic: "ping".to_string(),
-        value: 1.0,
-        source: Some("spammer".to_string()),
-        target: Some("third_party".to_string()),
-        attestation: None,
-    });
+    bus.publish(
+        Signal::new("ping", 1.0)
+            .with_source("spammer")
+            .with_target("third_party"),
+    );
 
     // Another signal (safe)
-    bus.publish(Signal {
-        topic: "safe".to_string(),
-        value: 1.0,
-        source: Some("good_guy".to_string()),
-        target: None,
-        attestation: None,
-    });
+    bus.publish(Signal::new("safe", 1.0).with_source("good_guy"));
 
     // Purge ALL signals from spammer
     bus.purge_from("spammer", "victim");
@@ -0,0 +1,50 @@
+use morphogenetic_security::cellular::CellGenome;
+use std::collections::HashSet;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn sensitivity_reports_one_ranked_row_per_gene() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let scenario_path = dir.path().join("scenario.yaml");
+    let output_path = dir.path().join("sensitivity.csv");
+    fs::write(
+        &scenario_path,
+        "scenario_name: tiny\ninitial_cell_count: 3\nsimulation_steps: 4\n",
+    )
+    .expect("write scenario");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_sensitivity"))
+        .arg(&scenario_path)
+        .arg("--output")
+        .arg(&output_path)
+        .output()
+        .expect("run sensitivity");
+    assert!(
+        output.status.success(),
+        "sensitivity failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut reader = csv::Reader::from_path(&output_path).expect("csv written");
+    assert_eq!(
+        reader.headers().expect("header"),
+        vec![
+            "rank",
+            "gene",
+            "fitness_minus",
+            "fitness_plus",
+            "sensitivity"
+        ]
+    );
+    let rows: Vec<csv::StringRecord> = reader.records().map(|row| row.expect("row")).collect();
+    assert_eq!(rows.len(), CellGenome::GENE_NAMES.len());
+
+    let genes: HashSet<&str> = rows.iter().map(|row| &row[1]).collect();
+    assert_eq!(genes.len(), rows.len());
+    let magnitudes: Vec<f32> = rows
+        .iter()
+        .map(|row| row[4].parse::<f32>().expect("sensitivity").abs())
+        .collect();
+    assert!(magnitudes.windows(2).all(|pair| pair[0] >= pair[1]));
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn known_telemetry_converts_to_expected_first_row() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let telemetry_path = dir.path().join("run.jsonl");
+    let stimulus_path = dir.path().join("stimulus.jsonl");
+    let output_path = dir.path().join("metrics.csv");
+    fs::write(
+        &telemetry_path,
+        concat!(
+            r#"{"timestamp_ms":1,"event":{"CellReplicated":{"cell_id":"a","child_id":"a-1"}}}"#,
+            "\n",
+            r#"{"timestamp_ms":2,"event":{"SignalEmitted":{"cell_id":"a","topic":"activator","value":0.5}}}"#,
+            "\n",
+            r#"{"timestamp_ms":3,"event":{"SignalEmitted":{"cell_id":"b","topic":"activator","value":0.2}}}"#,
+            "\n",
+            r#"{"timestamp_ms":4,"event":{"StepSummary":{"step":0,"threat_score":0.25,"cell_count":3,"population_stats":null}}}"#,
+            "\n",
+            r#"{"timestamp_ms":5,"event":{"StepSummary":{"step":1,"threat_score":0.5,"cell_count":3,"population_stats":null}}}"#,
+            "\n",
+        ),
+    )
+    .expect("write telemetry");
+    fs::write(
+        &stimulus_path,
+        "{\"step\":0,\"topic\":\"activator\",\"value\":0.4}\n",
+    )
+    .expect("write stimulus");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_telemetry_to_metrics"))
+        .arg(&telemetry_path)
+        .arg("--stimulus")
+        .arg(&stimulus_path)
+        .arg("--output")
+        .arg(&output_path)
+        .output()
+        .expect("run telemetry_to_metrics");
+    assert!(
+        output.status.success(),
+        "telemetry_to_metrics failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut reader = csv::Reader::from_path(&output_path).expect("csv written");
+    let headers = reader.headers().expect("header").clone();
+    let rows: Vec<csv::StringRecord> = reader.records().map(|row| row.expect("row")).collect();
+    assert_eq!(rows.len(), 2);
+
+    let column = |name: &str| {
+        let index = headers.iter().position(|h| h == name).expect(name);
+        rows[0][index].to_string()
+    };
+    assert_eq!(column("step"), "0");
+    assert_eq!(column("threat_score").parse::<f32>().unwrap(), 0.25);
+    assert_eq!(column("cell_count"), "3");
+    assert_eq!(column("replications"), "1");
+    assert_eq!(column("signals_total"), "2");
+    assert_eq!(column("top_signal_topic"), "activator");
+    assert!((column("stimulus_total").parse::<f32>().unwrap() - 0.4).abs() < 1e-6);
+}